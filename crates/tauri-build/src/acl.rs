@@ -11,9 +11,11 @@ use std::{
 use anyhow::{Context, Result};
 use tauri_utils::{
   acl::{
-    capability::Capability, manifest::Manifest, schema::CAPABILITIES_SCHEMA_FOLDER_PATH,
-    ACL_MANIFESTS_FILE_NAME, APP_ACL_KEY, CAPABILITIES_FILE_NAME,
+    capability::Capability, manifest::Manifest, resolved::Resolved,
+    schema::CAPABILITIES_SCHEMA_FOLDER_PATH, ACL_MANIFESTS_FILE_NAME, APP_ACL_KEY,
+    CAPABILITIES_FILE_NAME,
   },
+  config::Config,
   platform::Target,
   write_if_changed,
 };
@@ -148,7 +150,8 @@ fn read_plugins_manifests() -> Result<BTreeMap<String, Manifest>> {
 
   for (plugin_name, permission_files) in permission_map {
     let global_scope_schema = global_scope_map.remove(&plugin_name);
-    let manifest = Manifest::new(permission_files, global_scope_schema);
+    let manifest = Manifest::new(permission_files, global_scope_schema)
+      .with_context(|| format!("failed to load the ACL manifest of plugin `{plugin_name}`"))?;
     manifests.insert(plugin_name, manifest);
   }
 
@@ -232,7 +235,7 @@ permissions = [{default_permissions}]
       )?);
     }
 
-    let manifest = tauri_utils::acl::manifest::Manifest::new(permission_files, None);
+    let manifest = tauri_utils::acl::manifest::Manifest::new(permission_files, None)?;
     acl_manifests.insert(name.into(), manifest);
   }
 
@@ -304,10 +307,7 @@ fn app_manifest_permissions(
     )?);
   }
 
-  Ok(tauri_utils::acl::manifest::Manifest::new(
-    permission_files,
-    None,
-  ))
+  tauri_utils::acl::manifest::Manifest::new(permission_files, None).map_err(Into::into)
 }
 
 fn validate_capabilities(
@@ -373,7 +373,12 @@ fn validate_capabilities(
   Ok(())
 }
 
-pub fn build(out_dir: &Path, target: Target, attributes: &Attributes) -> super::Result<()> {
+pub fn build(
+  out_dir: &Path,
+  target: Target,
+  attributes: &Attributes,
+  config: &Config,
+) -> super::Result<()> {
   let mut acl_manifests = read_plugins_manifests()?;
 
   let app_manifest = app_manifest_permissions(
@@ -395,15 +400,45 @@ pub fn build(out_dir: &Path, target: Target, attributes: &Attributes) -> super::
 
   tauri_utils::acl::schema::generate_capability_schema(&acl_manifests, target)?;
 
-  let capabilities = if let Some(pattern) = attributes.capabilities_path_pattern {
+  let all_capabilities = if let Some(pattern) = attributes.capabilities_path_pattern {
     tauri_utils::acl::build::parse_capabilities(pattern)?
   } else {
     println!("cargo:rerun-if-changed=capabilities");
     tauri_utils::acl::build::parse_capabilities("./capabilities/**/*")?
   };
+  // the capabilities are saved unfiltered, the app context filters them along with the capabilities of the configuration
+  tauri_utils::acl::build::save_enabled_features(out_dir)?;
+  let mut capabilities = all_capabilities.clone();
+  tauri_utils::acl::build::filter_capabilities_by_features(
+    &mut capabilities,
+    &tauri_utils::acl::build::enabled_features(),
+  );
   validate_capabilities(&acl_manifests, &capabilities)?;
+  for warning in Resolved::lint(&acl_manifests, &capabilities, target)? {
+    println!("cargo:warning={warning}");
+  }
+  // restricting capabilities to some platforms is common, so only report it in verbose builds
+  if std::env::var("TAURI_CLI_VERBOSITY").is_ok_and(|v| v.parse::<u8>().unwrap_or_default() > 0) {
+    for skipped in Resolved::skipped_capabilities(&capabilities, target) {
+      println!("cargo:warning={skipped}");
+    }
+  }
+  // child webviews are created at runtime, so only the windows of the configuration are known here
+  let labels = config
+    .app
+    .windows
+    .iter()
+    .map(|w| (w.label.clone(), vec![w.label.clone()]))
+    .collect::<BTreeMap<_, _>>();
+  if !labels.is_empty() {
+    for capability in capabilities.values() {
+      for warning in capability.lint_targets(&labels) {
+        println!("cargo:warning={warning}");
+      }
+    }
+  }
 
-  let capabilities_path = save_capabilities(&capabilities)?;
+  let capabilities_path = save_capabilities(&all_capabilities)?;
   fs::copy(capabilities_path, out_dir.join(CAPABILITIES_FILE_NAME))?;
 
   tauri_utils::plugin::save_global_api_scripts_paths(out_dir);
@@ -0,0 +1,108 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use tauri_utils::{
+  acl::{
+    resolved::{AllowlistDiff, Resolved},
+    Profile,
+  },
+  platform::Target,
+};
+
+use crate::{
+  acl::{read_generated_acl, read_json},
+  helpers::app_paths::tauri_dir,
+  Result,
+};
+
+#[derive(Debug, Parser)]
+#[clap(
+  about = "Compare the commands allowed by your capabilities with an expected allowlist",
+  long_about = "Compare the commands allowed by your capabilities with an expected allowlist. Exits with an error if they differ.\n\nThe allowlist is a JSON array of command names, such as `plugin:fs|read` for plugin commands or the command name for app commands. Reads the ACL generated by the last build of the app."
+)]
+pub struct Options {
+  /// Path to the JSON file containing the expected allowlist.
+  allowlist: PathBuf,
+  /// Target triple to resolve the ACL for. Defaults to the current platform.
+  #[clap(short, long)]
+  target: Option<String>,
+  /// Resolve the ACL of release builds instead of debug builds.
+  #[clap(long)]
+  release: bool,
+  /// Print the differences as JSON.
+  #[clap(long)]
+  json: bool,
+}
+
+pub fn command(options: Options) -> Result<()> {
+  crate::helpers::app_paths::resolve();
+
+  let (acl, capabilities) = read_generated_acl(&tauri_dir())?;
+  let allowlist = read_json::<Vec<String>>(&options.allowlist)?;
+
+  let target = options
+    .target
+    .as_deref()
+    .map(Target::from_triple)
+    .unwrap_or_else(Target::current);
+  let profile = if options.release {
+    Profile::Release
+  } else {
+    Profile::Debug
+  };
+  let resolved = Resolved::resolve_for_profile(&acl, capabilities, target, profile)?;
+  let diff = resolved.diff_allowlist(allowlist.iter().map(String::as_str));
+
+  if options.json {
+    println!("{}", serde_json::to_string_pretty(&diff)?);
+  } else {
+    for line in diff_lines(&diff) {
+      println!("{line}");
+    }
+  }
+
+  if !diff.is_empty() {
+    anyhow::bail!(
+      "the allowed commands differ from {}: {} unexpected, {} missing",
+      options.allowlist.display(),
+      diff.unexpected.len(),
+      diff.missing.len()
+    );
+  }
+
+  log::info!(action = "Checked"; "allowed commands match {}", options.allowlist.display());
+  Ok(())
+}
+
+/// Formats the diff as `+ command` for the unexpected commands and `- command` for the missing ones.
+fn diff_lines(diff: &AllowlistDiff) -> Vec<String> {
+  diff
+    .unexpected
+    .iter()
+    .map(|command| format!("+ {command}"))
+    .chain(diff.missing.iter().map(|command| format!("- {command}")))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::diff_lines;
+  use tauri_utils::acl::resolved::AllowlistDiff;
+
+  #[test]
+  fn formats_diff() {
+    let diff = AllowlistDiff {
+      unexpected: ["plugin:fs|write".to_string()].into(),
+      missing: ["plugin:fs|remove".to_string()].into(),
+    };
+    assert_eq!(
+      diff_lines(&diff),
+      vec!["+ plugin:fs|write", "- plugin:fs|remove"]
+    );
+    assert!(diff_lines(&AllowlistDiff::default()).is_empty());
+  }
+}
@@ -103,6 +103,9 @@ pub fn command(options: Options) -> Result<()> {
       })
       .collect(),
     platforms: None,
+    enabled_on_features: Vec::new(),
+    include_required_permissions: false,
+    variables: Default::default(),
   };
 
   let path = match options.out {
@@ -2,8 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use serde::Serialize;
-use std::fmt::Display;
+use anyhow::Context;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::BTreeMap, fmt::Display, fs::read_to_string, path::Path};
+use tauri_utils::acl::{
+  capability::Capability, manifest::Manifest, schema::CAPABILITIES_SCHEMA_FOLDER_PATH,
+  ACL_MANIFESTS_FILE_NAME, CAPABILITIES_FILE_NAME,
+};
 
 pub mod capability;
 pub mod permission;
@@ -39,3 +44,32 @@ impl FileFormat {
     Ok(contents)
   }
 }
+
+/// Reads the plugin manifests and the capabilities saved by the last build of the app.
+pub fn read_generated_acl(
+  tauri_dir: &Path,
+) -> crate::Result<(BTreeMap<String, Manifest>, BTreeMap<String, Capability>)> {
+  let schemas_dir = tauri_dir.join(CAPABILITIES_SCHEMA_FOLDER_PATH);
+  let acl_manifests_path = schemas_dir.join(ACL_MANIFESTS_FILE_NAME);
+  let capabilities_path = schemas_dir.join(CAPABILITIES_FILE_NAME);
+  for path in [&acl_manifests_path, &capabilities_path] {
+    if !path.exists() {
+      anyhow::bail!(
+        "{} not found, build your app to generate it",
+        path.display()
+      );
+    }
+  }
+
+  Ok((
+    read_json(&acl_manifests_path)?,
+    read_json(&capabilities_path)?,
+  ))
+}
+
+/// Reads and deserializes the given JSON file.
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> crate::Result<T> {
+  let contents =
+    read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+  serde_json::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+}
@@ -0,0 +1,59 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use tauri_utils::acl::manifest::{Manifest, PermissionFile};
+
+use crate::Result;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Check permission files for mistakes")]
+pub struct Options {
+  /// Directory containing the permission files. Defaults to the `permissions` directory of the current directory.
+  path: Option<PathBuf>,
+  /// Also require a version on every permission, as needed to publish the plugin.
+  #[clap(long)]
+  strict: bool,
+}
+
+pub fn command(options: Options) -> Result<()> {
+  let path = options
+    .path
+    .unwrap_or_else(|| std::env::current_dir().unwrap().join("permissions"));
+  if !path.is_dir() {
+    anyhow::bail!("permissions directory {} not found", path.display());
+  }
+
+  let permission_files = PermissionFile::load_dir(&path)?;
+  if permission_files.is_empty() {
+    log::warn!("no permission files found in {}", path.display());
+    return Ok(());
+  }
+
+  let manifest = Manifest::new(permission_files, None)?;
+  let lints = if options.strict {
+    manifest.lint_strict()
+  } else {
+    manifest.lint()
+  };
+  for warning in &lints.warnings {
+    log::warn!("{warning}");
+  }
+  for error in &lints.errors {
+    log::error!("{error}");
+  }
+
+  if !lints.errors.is_empty() {
+    anyhow::bail!(
+      "found {} error(s) in the permissions of {}",
+      lints.errors.len(),
+      path.display()
+    );
+  }
+
+  log::info!(action = "Checked"; "permissions of {}", path.display());
+  Ok(())
+}
@@ -99,6 +99,13 @@ pub fn command(options: Options) -> Result<()> {
           .map(|f| permission.identifier.contains(f))
           .unwrap_or(true)
         {
+          for warning in permission.lint() {
+            if key == APP_ACL_KEY {
+              log::warn!("{warning}");
+            } else {
+              log::warn!("{key}: {warning}");
+            }
+          }
           permissions.push(format!(
             "{prefix}{}{}{}{}",
             permission.identifier.cyan(),
@@ -7,9 +7,11 @@ use clap::{Parser, Subcommand};
 use crate::Result;
 
 pub mod add;
+mod lint;
 mod ls;
 mod new;
 pub mod rm;
+mod types;
 
 #[derive(Debug, Parser)]
 #[clap(about = "Manage or create permissions for your app or plugin")]
@@ -27,6 +29,8 @@ enum Commands {
   Rm(rm::Options),
   #[clap(alias = "list")]
   Ls(ls::Options),
+  Lint(lint::Options),
+  Types(types::Options),
 }
 
 pub fn command(cli: Cli) -> Result<()> {
@@ -35,5 +39,7 @@ pub fn command(cli: Cli) -> Result<()> {
     Commands::Add(options) => add::command(options),
     Commands::Rm(options) => rm::command(options),
     Commands::Ls(options) => ls::command(options),
+    Commands::Lint(options) => lint::command(options),
+    Commands::Types(options) => types::command(options),
   }
 }
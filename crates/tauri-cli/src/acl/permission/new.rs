@@ -61,10 +61,21 @@ pub fn command(options: Options) -> Result<()> {
     version: None,
     identifier,
     description,
+    description_file: None,
     commands: Commands { allow, deny },
     scope: Default::default(),
     platforms: Default::default(),
+    profiles: Default::default(),
+    execution_context: Default::default(),
+    max_calls_per_minute: None,
+    requires: Vec::new(),
+    inherits: None,
+    category: None,
   };
+  permission.validate_identifier()?;
+  for warning in permission.lint() {
+    log::warn!("{warning}");
+  }
 
   let path = match options.out {
     Some(o) => o.canonicalize()?,
@@ -105,6 +116,8 @@ pub fn command(options: Options) -> Result<()> {
       default: None,
       set: Vec::new(),
       permission: vec![permission],
+      import: Vec::new(),
+      scope_fragments: Default::default(),
     })?,
   )?;
 
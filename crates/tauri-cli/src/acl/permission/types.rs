@@ -0,0 +1,64 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{collections::BTreeMap, fs::read_to_string, path::PathBuf};
+
+use clap::Parser;
+use tauri_utils::acl::{
+  manifest::Manifest,
+  schema::{generate_typescript_definitions, TYPESCRIPT_DEFINITIONS_FILE_NAME},
+};
+
+use crate::{helpers::app_paths::tauri_dir, Result};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Generate TypeScript definitions for the permissions and scope of a plugin")]
+pub struct Options {
+  /// Name of the plugin, as used in permission identifiers.
+  plugin: String,
+  /// Path of the generated file, e.g. the `permissions.d.ts` file of the plugin's TypeScript API package.
+  /// Defaults to `permissions.d.ts` in the current directory.
+  #[clap(short, long)]
+  out: Option<PathBuf>,
+}
+
+pub fn command(options: Options) -> Result<()> {
+  crate::helpers::app_paths::resolve();
+
+  let acl_manifests_path = tauri_dir()
+    .join("gen")
+    .join("schemas")
+    .join("acl-manifests.json");
+  if !acl_manifests_path.exists() {
+    anyhow::bail!("permission file not found, please build your application once first");
+  }
+
+  let acl_manifests_json = read_to_string(&acl_manifests_path)?;
+  let acl = serde_json::from_str::<BTreeMap<String, Manifest>>(&acl_manifests_json)?;
+  let Some(manifest) = acl.get(&options.plugin) else {
+    anyhow::bail!(
+      "plugin {} not found, available plugins: {}",
+      options.plugin,
+      acl.keys().cloned().collect::<Vec<_>>().join(", ")
+    );
+  };
+
+  let path = options.out.unwrap_or_else(|| {
+    std::env::current_dir()
+      .unwrap()
+      .join(TYPESCRIPT_DEFINITIONS_FILE_NAME)
+  });
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)?;
+  }
+
+  std::fs::write(
+    &path,
+    generate_typescript_definitions(&options.plugin, manifest),
+  )?;
+
+  log::info!(action = "Created"; "TypeScript definitions at {}", dunce::simplified(&path).display());
+
+  Ok(())
+}
@@ -0,0 +1,157 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{collections::BTreeSet, path::Path};
+
+use tauri_utils::{
+  acl::{resolved::Resolved, ExecutionContext, Profile},
+  platform::Target,
+};
+
+use super::{SectionItem, Status};
+
+pub fn items(tauri_dir: Option<&Path>, target: Target) -> Vec<SectionItem> {
+  let Some(tauri_dir) = tauri_dir else {
+    return Vec::new();
+  };
+
+  let (acl, capabilities) = match crate::acl::read_generated_acl(tauri_dir) {
+    Ok(generated) => generated,
+    Err(_) => {
+      return vec![SectionItem::new()
+        .description("capabilities: not resolved yet, build the app to generate the ACL")]
+    }
+  };
+
+  if capabilities.is_empty() {
+    return vec![SectionItem::new().description("capabilities: none")];
+  }
+
+  let capability_count = capabilities.len();
+  let skipped = Resolved::skipped_capabilities(&capabilities, target);
+  // the ACL of the shipped app
+  let mut items = match Resolved::resolve_for_profile(&acl, capabilities, target, Profile::Release)
+  {
+    Ok(resolved) => summary(capability_count, &resolved)
+      .into_iter()
+      .map(|description| SectionItem::new().description(description))
+      .collect(),
+    Err(e) => {
+      let mut item = SectionItem::new().description(format!("capabilities: {e}"));
+      item.status = Status::Error;
+      vec![item]
+    }
+  };
+  if !skipped.is_empty() {
+    items.push(
+      SectionItem::new().description(format!("skipped capabilities:\n{}", skipped.join("\n"))),
+    );
+  }
+  items
+}
+
+/// Describes the capabilities count, the allowed and denied commands count
+/// and the remote origins allowed to call commands.
+fn summary(capability_count: usize, resolved: &Resolved) -> Vec<String> {
+  let remote_origins = resolved
+    .allowed_commands
+    .values()
+    .flatten()
+    .filter_map(|command| match &command.context {
+      ExecutionContext::Remote { url } => Some(url.as_str().to_string()),
+      ExecutionContext::Local => None,
+    })
+    .collect::<BTreeSet<_>>();
+
+  let mut summary = vec![
+    format!("capabilities: {capability_count}"),
+    format!("allowed commands: {}", resolved.allowed_commands.len()),
+    format!("denied commands: {}", resolved.denied_commands.len()),
+  ];
+  if !remote_origins.is_empty() {
+    summary.push(format!(
+      "remote origins: {}",
+      remote_origins.into_iter().collect::<Vec<_>>().join(", ")
+    ));
+  }
+  summary
+}
+
+#[cfg(test)]
+mod tests {
+  use tauri_utils::{
+    acl::{
+      capability::{Capability, CapabilityRemote, PermissionEntry},
+      manifest::Manifest,
+      resolved::Resolved,
+      Commands, Identifier, Permission,
+    },
+    platform::Target,
+  };
+
+  use super::summary;
+
+  #[test]
+  fn summarizes_resolved_acl() {
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [(
+          "allow-read-deny-write".to_string(),
+          Permission {
+            identifier: "allow-read-deny-write".to_string(),
+            commands: Commands {
+              allow: vec!["read".into(), "exists".into()],
+              deny: vec!["write".into()],
+            },
+            ..Default::default()
+          },
+        )]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capability = |identifier: &str, remote: Option<&str>| {
+      (
+        identifier.to_string(),
+        Capability {
+          identifier: identifier.to_string(),
+          description: String::new(),
+          remote: remote.map(|url| CapabilityRemote {
+            urls: vec![url.to_string()],
+            ..Default::default()
+          }),
+          local: remote.is_none(),
+          windows: vec!["main".into()],
+          webviews: Vec::new(),
+          permissions: vec![PermissionEntry::PermissionRef(
+            Identifier::try_from("fs:allow-read-deny-write".to_string()).unwrap(),
+          )],
+          platforms: None,
+          enabled_on_features: Vec::new(),
+          include_required_permissions: false,
+          variables: Default::default(),
+        },
+      )
+    };
+    let capabilities = [
+      capability("main", None),
+      capability("docs", Some("https://tauri.app")),
+    ]
+    .into();
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::current()).unwrap();
+    assert_eq!(
+      summary(2, &resolved),
+      vec![
+        "capabilities: 2",
+        "allowed commands: 2",
+        "denied commands: 1",
+        "remote origins: https://tauri.app",
+      ]
+    );
+  }
+}
@@ -11,7 +11,9 @@ use colored::{ColoredString, Colorize};
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use serde::Deserialize;
 use std::fmt::{self, Display, Formatter};
+use tauri_utils::platform::Target;
 
+mod acl;
 mod app;
 mod env_nodejs;
 mod env_rust;
@@ -256,10 +258,16 @@ pub struct Options {
   /// Interactive mode to apply automatic fixes.
   #[clap(long)]
   pub interactive: bool,
+  /// Target triple to resolve the ACL for, listing the capabilities skipped on it. Defaults to the current platform.
+  #[clap(long)]
+  pub acl_target: Option<String>,
 }
 
 pub fn command(options: Options) -> Result<()> {
-  let Options { interactive } = options;
+  let Options {
+    interactive,
+    acl_target,
+  } = options;
 
   let frontend_dir = resolve_frontend_dir();
   let tauri_dir = resolve_tauri_dir();
@@ -316,10 +324,25 @@ pub fn command(options: Options) -> Result<()> {
     .items
     .extend(app::items(frontend_dir.as_ref(), tauri_dir.as_deref()));
 
+  let mut acl = Section {
+    label: "ACL",
+    interactive,
+    items: acl::items(
+      tauri_dir.as_deref(),
+      acl_target
+        .as_deref()
+        .map(Target::from_triple)
+        .unwrap_or_else(Target::current),
+    ),
+  };
+
   environment.display();
   packages.display();
   plugins.display();
   app.display();
+  if !acl.items.is_empty() {
+    acl.display();
+  }
 
   // iOS
   #[cfg(target_os = "macos")]
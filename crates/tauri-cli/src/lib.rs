@@ -141,8 +141,7 @@ enum Commands {
   Android(mobile::android::Cli),
   #[cfg(target_os = "macos")]
   Ios(mobile::ios::Cli),
-  /// Migrate from v1 to v2
-  Migrate,
+  Migrate(migrate::Options),
   Info(info::Options),
   Add(add::Options),
   Remove(remove::Options),
@@ -277,7 +276,7 @@ where
     Commands::Android(c) => mobile::android::command(c, cli.verbose)?,
     #[cfg(target_os = "macos")]
     Commands::Ios(c) => mobile::ios::command(c, cli.verbose)?,
-    Commands::Migrate => migrate::command()?,
+    Commands::Migrate(options) => migrate::command(options)?,
     Commands::Inspect(cli) => inspect::command(cli)?,
   }
 
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use crate::Result;
+use crate::{migrate::Changes, Result};
 
 use serde_json::{Map, Value};
 use tauri_utils::acl::{
@@ -16,16 +16,26 @@ use std::{
   path::Path,
 };
 
-pub fn migrate(tauri_dir: &Path) -> Result<MigratedConfig> {
+pub fn migrate(tauri_dir: &Path, changes: &mut Changes) -> Result<MigratedConfig> {
   if let Ok((mut config, config_path)) =
     tauri_utils_v1::config::parse::parse_value(tauri_dir.join("tauri.conf.json"))
   {
     let migrated = migrate_config(&mut config)?;
-    if config_path.extension().map_or(false, |ext| ext == "toml") {
-      fs::write(&config_path, toml::to_string_pretty(&config)?)?;
-    } else {
-      fs::write(&config_path, serde_json::to_string_pretty(&config)?)?;
+    for (from, to) in &migrated.renamed {
+      log::info!("Migrated `{from}` to `{to}`");
+    }
+    for warning in &migrated.warnings {
+      log::warn!("{warning}");
     }
+    let config_contents = if config_path.extension().map_or(false, |ext| ext == "toml") {
+      toml::to_string_pretty(&config)?
+    } else {
+      serde_json::to_string_pretty(&config)?
+    };
+    changes.apply(
+      format!("Rewrite {} to the v2 format", config_path.display()),
+      || fs::write(&config_path, config_contents).map_err(Into::into),
+    )?;
 
     let mut permissions: Vec<PermissionEntry> = vec!["core:default"]
       .into_iter()
@@ -34,19 +44,29 @@ pub fn migrate(tauri_dir: &Path) -> Result<MigratedConfig> {
     permissions.extend(migrated.permissions.clone());
 
     let capabilities_path = config_path.parent().unwrap().join("capabilities");
-    fs::create_dir_all(&capabilities_path)?;
-    fs::write(
-      capabilities_path.join("migrated.json"),
-      serde_json::to_string_pretty(&Capability {
-        identifier: "migrated".to_string(),
-        description: "permissions that were migrated from v1".into(),
-        local: true,
-        remote: None,
-        windows: vec!["main".into()],
-        webviews: vec![],
-        permissions,
-        platforms: None,
-      })?,
+    let capability = serde_json::to_string_pretty(&Capability {
+      identifier: "migrated".to_string(),
+      description: "permissions that were migrated from v1".into(),
+      local: true,
+      remote: None,
+      windows: vec!["main".into()],
+      webviews: vec![],
+      permissions,
+      platforms: None,
+      enabled_on_features: vec![],
+      include_required_permissions: false,
+      variables: Default::default(),
+    })?;
+    changes.apply(
+      format!(
+        "Create {} with the permissions migrated from the allowlist",
+        capabilities_path.join("migrated.json").display()
+      ),
+      || {
+        fs::create_dir_all(&capabilities_path)?;
+        fs::write(capabilities_path.join("migrated.json"), capability)?;
+        Ok(())
+      },
     )?;
 
     return Ok(migrated);
@@ -59,17 +79,26 @@ pub fn migrate(tauri_dir: &Path) -> Result<MigratedConfig> {
 pub struct MigratedConfig {
   pub permissions: Vec<PermissionEntry>,
   pub plugins: HashSet<String>,
+  /// Settings moved to their v2 equivalent, as `(v1 path, v2 path)`.
+  pub renamed: Vec<(String, String)>,
+  /// Settings that could not be migrated automatically and need to be reviewed by the user.
+  pub warnings: Vec<String>,
 }
 
 fn migrate_config(config: &mut Value) -> Result<MigratedConfig> {
   let mut migrated = MigratedConfig {
     permissions: Vec::new(),
     plugins: HashSet::new(),
+    renamed: Vec::new(),
+    warnings: Vec::new(),
   };
 
   if let Some(config) = config.as_object_mut() {
+    if let Some((from, to)) = process_schema(config) {
+      log::info!("Migrated `$schema` from `{from}` to `{to}`");
+    }
     process_package_metadata(config);
-    process_build(config);
+    process_build(config, &mut migrated);
 
     let mut plugins = config
       .entry("plugins")
@@ -114,7 +143,7 @@ fn migrate_config(config: &mut Value) -> Result<MigratedConfig> {
         .get_mut("security")
         .and_then(|c| c.as_object_mut())
       {
-        process_security(security)?;
+        process_security(security, &mut migrated)?;
       }
 
       // tauri > pattern
@@ -168,6 +197,28 @@ fn migrate_config(config: &mut Value) -> Result<MigratedConfig> {
   Ok(migrated)
 }
 
+/// Points `$schema` at the v2 schema, returning the previous and new values if it was changed.
+fn process_schema(config: &mut Map<String, Value>) -> Option<(String, String)> {
+  const V1_REMOTE_SCHEMA_PREFIX: &str = "https://schema.tauri.app/config/1";
+  const V2_REMOTE_SCHEMA: &str = "https://schema.tauri.app/config/2";
+  // v1 of `@tauri-apps/cli` shipped the config schema as `schema.json`
+  const V1_LOCAL_SCHEMA_SUFFIX: &str = "@tauri-apps/cli/schema.json";
+  const V2_LOCAL_SCHEMA_SUFFIX: &str = "@tauri-apps/cli/config.schema.json";
+
+  let schema = config.get("$schema").and_then(|s| s.as_str())?;
+  let migrated = if schema.starts_with(V1_REMOTE_SCHEMA_PREFIX) {
+    V2_REMOTE_SCHEMA.to_string()
+  } else if let Some(base) = schema.strip_suffix(V1_LOCAL_SCHEMA_SUFFIX) {
+    format!("{base}{V2_LOCAL_SCHEMA_SUFFIX}")
+  } else {
+    return None;
+  };
+
+  let original = schema.to_string();
+  config.insert("$schema".into(), migrated.clone().into());
+  Some((original, migrated))
+}
+
 fn process_package_metadata(config: &mut Map<String, Value>) {
   if let Some(mut package_config) = config.remove("package") {
     if let Some(package_config) = package_config.as_object_mut() {
@@ -202,27 +253,52 @@ fn process_package_metadata(config: &mut Map<String, Value>) {
   }
 }
 
-fn process_build(config: &mut Map<String, Value>) {
+fn process_build(config: &mut Map<String, Value>, migrated: &mut MigratedConfig) {
   if let Some(build_config) = config.get_mut("build").and_then(|b| b.as_object_mut()) {
-    if let Some((dist_dir, key)) = build_config
+    let frontend_dist_key = if build_config.contains_key("dist-dir") {
+      "frontend-dist"
+    } else {
+      "frontendDist"
+    };
+    if let Some((dist_dir, from)) = build_config
       .remove("distDir")
-      .map(|v| (v, "frontendDist"))
-      .or_else(|| {
-        build_config
-          .remove("dist-dir")
-          .map(|v| (v, "frontend-dist"))
-      })
+      .map(|v| (v, "distDir"))
+      .or_else(|| build_config.remove("dist-dir").map(|v| (v, "dist-dir")))
     {
-      build_config.insert(key.into(), dist_dir);
+      build_config.insert(frontend_dist_key.into(), dist_dir);
+      migrated.renamed.push((
+        format!("build > {from}"),
+        format!("build > {frontend_dist_key}"),
+      ));
     }
-    if let Some((dev_path, key)) = build_config
+    if let Some((dev_path, from, key)) = build_config
       .remove("devPath")
-      .map(|v| (v, "devUrl"))
-      .or_else(|| build_config.remove("dev-path").map(|v| (v, "dev-url")))
+      .map(|v| (v, "devPath", "devUrl"))
+      .or_else(|| {
+        build_config
+          .remove("dev-path")
+          .map(|v| (v, "dev-path", "dev-url"))
+      })
     {
       let is_url = url::Url::parse(dev_path.as_str().unwrap_or_default()).is_ok();
       if is_url {
         build_config.insert(key.into(), dev_path);
+        migrated
+          .renamed
+          .push((format!("build > {from}"), format!("build > {key}")));
+      } else if let Some(frontend_dist) = build_config.get(frontend_dist_key) {
+        // v2 has no setting for a local development path, `tauri dev` serves the frontend dist
+        if *frontend_dist != dev_path {
+          migrated.warnings.push(format!(
+            "`build > {from}` is a local path ({dev_path}), which is not supported in v2. `tauri dev` will serve `build > {frontend_dist_key}` ({frontend_dist}) instead; set `build > {key}` if you use a development server."
+          ));
+        }
+      } else {
+        build_config.insert(frontend_dist_key.into(), dev_path);
+        migrated.renamed.push((
+          format!("build > {from}"),
+          format!("build > {frontend_dist_key}"),
+        ));
       }
     }
     if let Some((with_global_tauri, key)) = build_config
@@ -369,8 +445,11 @@ fn process_bundle(config: &mut Map<String, Value>, migrated: &MigratedConfig) {
   }
 }
 
-fn process_security(security: &mut Map<String, Value>) -> Result<()> {
-  // migrate CSP: add `ipc:` to `connect-src`
+fn process_security(
+  security: &mut Map<String, Value>,
+  migrated: &mut MigratedConfig,
+) -> Result<()> {
+  // migrate CSP: add `ipc:` to `connect-src`, keeping the policy string or directive map representation
   if let Some(csp_value) = security.remove("csp") {
     let csp = if csp_value.is_null() {
       csp_value
@@ -378,11 +457,32 @@ fn process_security(security: &mut Map<String, Value>) -> Result<()> {
       let mut csp: tauri_utils_v1::config::Csp = serde_json::from_value(csp_value)?;
       match &mut csp {
         tauri_utils_v1::config::Csp::Policy(csp) => {
-          if csp.contains("connect-src") {
-            *csp = csp.replace("connect-src", "connect-src ipc: http://ipc.localhost");
-          } else {
-            *csp = format!("{csp}; connect-src ipc: http://ipc.localhost");
+          let mut directives = parse_csp_policy(csp);
+          match directives
+            .iter_mut()
+            .find(|(name, _)| name == "connect-src")
+          {
+            Some((_, sources)) => {
+              if !sources.iter().any(|s| s == "ipc:") {
+                sources.extend(["ipc:".to_string(), "http://ipc.localhost".to_string()]);
+              }
+            }
+            None => directives.push((
+              "connect-src".into(),
+              vec!["ipc:".into(), "http://ipc.localhost".into()],
+            )),
           }
+          warn_csp_unsafe_inline(&directives, migrated);
+          *csp = directives
+            .into_iter()
+            .map(|(name, sources)| {
+              std::iter::once(name)
+                .chain(sources)
+                .collect::<Vec<_>>()
+                .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
         }
         tauri_utils_v1::config::Csp::DirectiveMap(csp) => {
           if let Some(connect_src) = csp.get_mut("connect-src") {
@@ -397,6 +497,29 @@ fn process_security(security: &mut Map<String, Value>) -> Result<()> {
               ]),
             );
           }
+          let mut directives = csp
+            .iter()
+            .map(|(name, sources)| {
+              let sources = match sources {
+                tauri_utils_v1::config::CspDirectiveSources::Inline(source) => {
+                  vec![source.as_str()]
+                }
+                tauri_utils_v1::config::CspDirectiveSources::List(list) => {
+                  list.iter().map(String::as_str).collect()
+                }
+              };
+              (
+                name.clone(),
+                sources
+                  .into_iter()
+                  .flat_map(str::split_whitespace)
+                  .map(ToString::to_string)
+                  .collect(),
+              )
+            })
+            .collect::<Vec<_>>();
+          directives.sort();
+          warn_csp_unsafe_inline(&directives, migrated);
         }
       }
       serde_json::to_value(csp)?
@@ -419,6 +542,35 @@ fn process_security(security: &mut Map<String, Value>) -> Result<()> {
   Ok(())
 }
 
+/// Splits a CSP policy string into its directives and their sources, keeping their order.
+fn parse_csp_policy(policy: &str) -> Vec<(String, Vec<String>)> {
+  policy
+    .split(';')
+    .filter_map(|directive| {
+      let mut tokens = directive.split_whitespace();
+      tokens
+        .next()
+        .map(|name| (name.to_string(), tokens.map(ToString::to_string).collect()))
+    })
+    .collect()
+}
+
+/// Warns about `'unsafe-inline'` sources in the directives Tauri v2 adds nonces and hashes to,
+/// since the webview ignores `'unsafe-inline'` when a nonce or hash is present.
+fn warn_csp_unsafe_inline(directives: &[(String, Vec<String>)], migrated: &mut MigratedConfig) {
+  for (name, sources) in directives {
+    if matches!(name.as_str(), "script-src" | "style-src")
+      && sources.iter().any(|s| s == "'unsafe-inline'")
+    {
+      migrated.warnings.push(format!(
+        "`tauri > security > csp > {name}` allows `'unsafe-inline'`, which is ignored in v2 because Tauri adds nonces and hashes to `{name}` for your bundled assets. \
+        Move inline {kind} to files, or add `{name}` to `app > security > dangerousDisableAssetCspModification` to keep them working",
+        kind = if name == "script-src" { "scripts" } else { "styles" }
+      ));
+    }
+  }
+}
+
 fn process_allowlist(
   tauri_config: &mut Map<String, Value>,
   allowlist: Value,
@@ -489,6 +641,7 @@ fn allowlist_to_permissions(
       .collect::<Vec<_>>();
     permissions.push(PermissionEntry::ExtendedPermission {
       identifier: "fs:scope".to_string().try_into().unwrap(),
+      version: None,
       scope: Scopes {
         allow: if fs_allowed.is_empty() {
           None
@@ -500,6 +653,7 @@ fn allowlist_to_permissions(
         } else {
           Some(fs_denied)
         },
+        include: Vec::new(),
       },
     });
   }
@@ -557,9 +711,11 @@ fn allowlist_to_permissions(
 
     permissions.push(PermissionEntry::ExtendedPermission {
       identifier: "shell:allow-execute".to_string().try_into().unwrap(),
+      version: None,
       scope: Scopes {
         allow: Some(allowed),
         deny: None,
+        include: Vec::new(),
       },
     });
   }
@@ -600,9 +756,11 @@ fn allowlist_to_permissions(
 
     permissions.push(PermissionEntry::ExtendedPermission {
       identifier: "http:default".to_string().try_into().unwrap(),
+      version: None,
       scope: Scopes {
         allow: Some(allowed),
         deny: None,
+        include: Vec::new(),
       },
     });
   }
@@ -644,6 +802,9 @@ fn process_cli(plugins: &mut Map<String, Value>, cli: Value) -> Result<()> {
   Ok(())
 }
 
+/// Keys of the v1 updater config that have the same meaning in the v2 updater plugin config.
+const UPDATER_PLUGIN_KEYS: &[&str] = &["endpoints", "pubkey", "windows"];
+
 fn process_updater(
   tauri_config: &mut Map<String, Value>,
   plugins: &mut Map<String, Value>,
@@ -651,18 +812,54 @@ fn process_updater(
 ) -> Result<()> {
   if let Some(mut updater) = tauri_config.remove("updater") {
     if let Some(updater) = updater.as_object_mut() {
-      updater.remove("dialog");
+      // the dialog is enabled by default on v1
+      let dialog = updater
+        .remove("dialog")
+        .and_then(|d| d.as_bool())
+        .unwrap_or(true);
+      let active = updater
+        .remove("active")
+        .and_then(|a| a.as_bool())
+        .unwrap_or_default();
 
       // we only migrate the updater config if it's active
       // since we now assume it's always active if the config object is set
       // we also migrate if pubkey is set so we do not lose that information on the migration
       // in this case, the user need to deal with the updater being inactive on their own
-      if updater
-        .remove("active")
-        .and_then(|a| a.as_bool())
-        .unwrap_or_default()
-        || updater.get("pubkey").is_some()
-      {
+      if active || updater.get("pubkey").is_some() {
+        if !active {
+          migrated.warnings.push(
+            "`tauri > updater > active` is false but the updater plugin is always active when configured, only register it when you want to check for updates".into(),
+          );
+        }
+        if dialog {
+          migrated.warnings.push(
+            "`tauri > updater > dialog` has no v2 equivalent, use the dialog plugin to ask the user before installing an update".into(),
+          );
+        }
+
+        let unknown_keys = updater
+          .keys()
+          .filter(|key| !UPDATER_PLUGIN_KEYS.contains(&key.as_str()))
+          .cloned()
+          .collect::<Vec<_>>();
+        for key in unknown_keys {
+          updater.remove(&key);
+          migrated
+            .warnings
+            .push(format!("`tauri > updater > {key}` could not be migrated"));
+        }
+
+        if let Some(endpoints) = updater.get("endpoints").and_then(|e| e.as_array()) {
+          for endpoint in endpoints.iter().filter_map(|e| e.as_str()) {
+            if endpoint.starts_with("http://") {
+              migrated.warnings.push(format!(
+                "updater endpoint `{endpoint}` does not use HTTPS, which is rejected by the updater plugin unless `plugins > updater > dangerousInsecureTransportProtocol` is set"
+              ));
+            }
+          }
+        }
+
         plugins.insert("updater".into(), serde_json::to_value(updater)?);
         migrated.plugins.insert("updater".to_string());
       }
@@ -706,15 +903,6 @@ mod test {
     let mut migrated = original.clone();
     super::migrate_config(&mut migrated).expect("failed to migrate config");
 
-    if original.get("$schema").is_some() {
-      if let Some(map) = migrated.as_object_mut() {
-        map.insert(
-          "$schema".to_string(),
-          serde_json::Value::String("https://schema.tauri.app/config/2".to_string()),
-        );
-      }
-    }
-
     if original
       .get("tauri")
       .and_then(|v| v.get("bundle"))
@@ -832,7 +1020,10 @@ mod test {
     let migrated = migrate(&original);
 
     // $schema
-    assert_eq!(migrated["$schema"], "https://schema.tauri.app/config/2");
+    assert_eq!(
+      migrated["$schema"],
+      "../node_modules/@tauri-apps/cli/config.schema.json"
+    );
 
     // plugins > updater
     assert_eq!(
@@ -966,6 +1157,64 @@ mod test {
     );
   }
 
+  #[test]
+  fn migrate_updater_fixture() {
+    let original: serde_json::Value =
+      serde_json::from_str(include_str!("./fixtures/updater.tauri.conf.json")).unwrap();
+
+    let migrated = migrate(&original);
+    assert_eq!(
+      migrated["plugins"]["updater"],
+      serde_json::json!({
+        "endpoints": [
+          "https://releases.myapp.com/{{target}}/{{arch}}/{{current_version}}",
+          "http://localhost:3000/{{target}}/{{current_version}}"
+        ],
+        "pubkey": original["tauri"]["updater"]["pubkey"],
+        "windows": {
+          "installMode": "passive",
+          "installerArgs": ["/NS"]
+        }
+      })
+    );
+    assert_eq!(migrated["app"].get("updater"), None);
+    assert_eq!(migrated["bundle"]["createUpdaterArtifacts"], "v1Compatible");
+    assert_eq!(
+      migrated["bundle"]["targets"].as_array(),
+      Some(&vec!["nsis".into()])
+    );
+
+    let mut config = original.clone();
+    let report = super::migrate_config(&mut config).unwrap();
+    assert!(report.plugins.contains("updater"));
+    assert_eq!(report.warnings.len(), 2);
+    assert!(report.warnings[0].contains("`tauri > updater > dialog`"));
+    assert!(report.warnings[1].contains("http://localhost:3000"));
+  }
+
+  #[test]
+  fn reports_unmigrated_updater_fields() {
+    let mut config = serde_json::json!({
+      "tauri": {
+        "updater": {
+          "active": false,
+          "dialog": false,
+          "pubkey": "somekey",
+          "unknownField": true
+        }
+      }
+    });
+
+    let report = super::migrate_config(&mut config).unwrap();
+    assert_eq!(
+      config["plugins"]["updater"],
+      serde_json::json!({ "pubkey": "somekey" })
+    );
+    assert_eq!(report.warnings.len(), 2);
+    assert!(report.warnings[0].contains("`tauri > updater > active`"));
+    assert!(report.warnings[1].contains("`tauri > updater > unknownField`"));
+  }
+
   #[test]
   fn migrate_dangerous_use_http_scheme() {
     let original = serde_json::json!({
@@ -1008,6 +1257,36 @@ mod test {
     migrate(&original);
   }
 
+  #[test]
+  fn migrate_schema() {
+    let original = serde_json::json!({
+      "$schema": "https://schema.tauri.app/config/1"
+    });
+    let migrated = migrate(&original);
+    assert_eq!(migrated["$schema"], "https://schema.tauri.app/config/2");
+
+    let original = serde_json::json!({
+      "$schema": "https://schema.tauri.app/config/1.5.0"
+    });
+    let migrated = migrate(&original);
+    assert_eq!(migrated["$schema"], "https://schema.tauri.app/config/2");
+
+    let original = serde_json::json!({
+      "$schema": "../../node_modules/@tauri-apps/cli/schema.json"
+    });
+    let migrated = migrate(&original);
+    assert_eq!(
+      migrated["$schema"],
+      "../../node_modules/@tauri-apps/cli/config.schema.json"
+    );
+
+    // unknown references are left untouched
+    let original =
+      serde_json::from_str(include_str!("./fixtures/api-example.tauri.conf.json")).unwrap();
+    let migrated = migrate(&original);
+    assert_eq!(migrated["$schema"], original["$schema"]);
+  }
+
   #[test]
   fn migrate_updater_target() {
     let original = serde_json::json!({});
@@ -1186,6 +1465,52 @@ mod test {
     );
   }
 
+  #[test]
+  fn migrate_csp_fixture() {
+    let original: serde_json::Value =
+      serde_json::from_str(include_str!("./fixtures/csp.tauri.conf.json")).unwrap();
+
+    let migrated = migrate(&original);
+    assert_eq!(
+      migrated["app"]["security"]["csp"],
+      "default-src 'self' asset: https://asset.localhost; \
+      script-src 'self' 'unsafe-inline' https://cdn.jsdelivr.net; \
+      style-src 'self' 'unsafe-inline' https://fonts.googleapis.com; \
+      img-src 'self' data: blob: https:; \
+      connect-src 'self' https://api.myapp.com wss://api.myapp.com ipc: http://ipc.localhost; \
+      frame-ancestors 'none'"
+    );
+
+    let mut config = original.clone();
+    let report = super::migrate_config(&mut config).unwrap();
+    assert_eq!(report.warnings.len(), 2);
+    assert!(report.warnings[0].contains("`tauri > security > csp > script-src`"));
+    assert!(report.warnings[1].contains("`tauri > security > csp > style-src`"));
+    assert!(report.warnings[1].contains("dangerousDisableAssetCspModification"));
+  }
+
+  #[test]
+  fn migrate_csp_object_unsafe_inline() {
+    let mut config = serde_json::json!({
+      "tauri": {
+        "security": {
+          "csp": {
+            "default-src": "'self'",
+            "style-src": ["'self'", "'unsafe-inline'"]
+          }
+        }
+      }
+    });
+
+    let report = super::migrate_config(&mut config).unwrap();
+    assert_eq!(
+      config["app"]["security"]["csp"]["style-src"],
+      serde_json::json!(["'self'", "'unsafe-inline'"])
+    );
+    assert_eq!(report.warnings.len(), 1);
+    assert!(report.warnings[0].contains("`tauri > security > csp > style-src`"));
+  }
+
   #[test]
   fn migrate_invalid_url_dev_path() {
     let original = serde_json::json!({
@@ -1199,9 +1524,74 @@ mod test {
 
     assert!(migrated["build"].get("devUrl").is_none());
     assert_eq!(
-      migrated["build"]["distDir"],
-      original["build"]["frontendDist"]
+      migrated["build"]["frontendDist"],
+      original["build"]["distDir"]
+    );
+  }
+
+  #[test]
+  fn migrate_dev_url_fixture() {
+    let mut config: serde_json::Value =
+      serde_json::from_str(include_str!("./fixtures/dev-url.tauri.conf.json")).unwrap();
+
+    let report = super::migrate_config(&mut config).unwrap();
+    assert_eq!(
+      config["build"],
+      serde_json::json!({
+        "frontendDist": "../dist",
+        "devUrl": "http://localhost:1420",
+        "beforeDevCommand": "npm run dev",
+        "beforeBuildCommand": "npm run build"
+      })
+    );
+    assert_eq!(
+      report.renamed,
+      [
+        (
+          "build > distDir".to_string(),
+          "build > frontendDist".to_string()
+        ),
+        ("build > devPath".to_string(), "build > devUrl".to_string()),
+      ]
+    );
+    assert!(report.warnings.is_empty());
+  }
+
+  #[test]
+  fn migrate_dev_path_fixture() {
+    let mut config: serde_json::Value =
+      serde_json::from_str(include_str!("./fixtures/dev-path.tauri.conf.json")).unwrap();
+
+    let report = super::migrate_config(&mut config).unwrap();
+    assert_eq!(
+      config["build"],
+      serde_json::json!({ "frontendDist": "../dist" })
+    );
+    assert_eq!(
+      report.renamed,
+      [(
+        "build > distDir".to_string(),
+        "build > frontendDist".to_string()
+      )]
+    );
+    assert_eq!(report.warnings.len(), 1);
+    assert!(report.warnings[0].contains("`build > devPath` is a local path (\"../src\")"));
+
+    // without a dist dir the local dev path is the frontend
+    let mut config = serde_json::json!({ "build": { "dev-path": "../src" } });
+    let report = super::migrate_config(&mut config).unwrap();
+    assert_eq!(
+      config["build"],
+      serde_json::json!({ "frontend-dist": "../src" })
+    );
+    assert_eq!(
+      report.renamed,
+      [(
+        "build > dev-path".to_string(),
+        "build > frontend-dist".to_string()
+      )]
     );
+    assert!(report.warnings.is_empty());
   }
 
   #[test]
@@ -0,0 +1,27 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use tauri::Manager;
+
+#[tauri::command]
+fn notify(app: tauri::AppHandle) {
+  app.emit_all("notification", "hello").unwrap();
+}
+
+fn main() {
+  tauri::Builder::default()
+    .system_tray(tauri::SystemTray::new())
+    .on_window_event(|event| println!("{:?}", event.event()))
+    .setup(|app| {
+      // keep the window reference: app.get_window("main")
+      let window = app.get_window("main").unwrap();
+      app.listen_global("ready", move |_| {
+        window.show().unwrap();
+      });
+      Ok(())
+    })
+    .invoke_handler(tauri::generate_handler![notify])
+    .run(tauri::generate_context!())
+    .expect("error while running tauri application");
+}
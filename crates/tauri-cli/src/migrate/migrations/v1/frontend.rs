@@ -4,6 +4,7 @@
 
 use crate::{
   helpers::{app_paths::walk_builder, npm::PackageManager},
+  migrate::Changes,
   Result,
 };
 use anyhow::Context;
@@ -68,7 +69,7 @@ const MODULES_MAP: phf::Map<&str, &str> = phf::phf_map! {
 const JS_EXTENSIONS: &[&str] = &["js", "mjs", "jsx", "ts", "mts", "tsx", "svelte", "vue"];
 
 /// Returns a list of migrated plugins
-pub fn migrate(frontend_dir: &Path) -> Result<Vec<String>> {
+pub fn migrate(frontend_dir: &Path, changes: &mut Changes) -> Result<Vec<String>> {
   let mut new_npm_packages = Vec::new();
   let mut new_plugins = Vec::new();
   let mut npm_packages_to_remove = Vec::new();
@@ -102,15 +103,30 @@ pub fn migrate(frontend_dir: &Path) -> Result<Vec<String>> {
       let ext = path.extension().unwrap_or_default();
       if JS_EXTENSIONS.iter().any(|e| e == &ext) {
         let js_contents = std::fs::read_to_string(path)?;
+        let mut file_plugins = Vec::new();
+        let mut file_packages_to_remove = Vec::new();
         let new_contents = migrate_imports(
           path,
           &js_contents,
-          &mut new_plugins,
-          &mut npm_packages_to_remove,
+          &mut file_plugins,
+          &mut file_packages_to_remove,
         )?;
         if new_contents != js_contents {
-          fs::write(path, new_contents)
-            .with_context(|| format!("Error writing {}", path.display()))?;
+          let applied = changes.apply(
+            format!(
+              "Migrate the `@tauri-apps/api` imports in {}",
+              path.display()
+            ),
+            || {
+              fs::write(path, new_contents)
+                .with_context(|| format!("Error writing {}", path.display()))
+            },
+          )?;
+          // the plugins and packages are only needed by the rewritten imports
+          if applied {
+            new_plugins.extend(file_plugins);
+            npm_packages_to_remove.extend(file_packages_to_remove);
+          }
         }
       }
     }
@@ -119,15 +135,28 @@ pub fn migrate(frontend_dir: &Path) -> Result<Vec<String>> {
   if !npm_packages_to_remove.is_empty() {
     npm_packages_to_remove.sort();
     npm_packages_to_remove.dedup();
-    pm.remove(&npm_packages_to_remove, frontend_dir)
-      .context("Error removing npm packages")?;
+    changes.apply(
+      format!(
+        "Remove the npm packages {}",
+        npm_packages_to_remove.join(", ")
+      ),
+      || {
+        pm.remove(&npm_packages_to_remove, frontend_dir)
+          .context("Error removing npm packages")
+      },
+    )?;
   }
 
   if !new_npm_packages.is_empty() {
     new_npm_packages.sort();
     new_npm_packages.dedup();
-    pm.install(&new_npm_packages, frontend_dir)
-      .context("Error installing new npm packages")?;
+    changes.apply(
+      format!("Install the npm packages {}", new_npm_packages.join(", ")),
+      || {
+        pm.install(&new_npm_packages, frontend_dir)
+          .context("Error installing new npm packages")
+      },
+    )?;
   }
 
   Ok(new_plugins)
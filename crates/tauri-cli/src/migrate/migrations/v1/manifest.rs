@@ -4,6 +4,7 @@
 
 use crate::{
   interface::rust::manifest::{read_manifest, serialize_manifest},
+  migrate::Changes,
   Result,
 };
 
@@ -15,13 +16,18 @@ use std::path::Path;
 
 const CRATE_TYPES: [&str; 3] = ["lib", "staticlib", "cdylib"];
 
-pub fn migrate(tauri_dir: &Path) -> Result<()> {
+pub fn migrate(tauri_dir: &Path, changes: &mut Changes) -> Result<()> {
   let manifest_path = tauri_dir.join("Cargo.toml");
   let (mut manifest, _) = read_manifest(&manifest_path)?;
   migrate_manifest(&mut manifest)?;
 
-  std::fs::write(&manifest_path, serialize_manifest(&manifest))
-    .context("failed to rewrite Cargo manifest")?;
+  changes.apply(
+    format!("Update the dependencies in {}", manifest_path.display()),
+    || {
+      std::fs::write(&manifest_path, serialize_manifest(&manifest))
+        .context("failed to rewrite Cargo manifest")
+    },
+  )?;
 
   Ok(())
 }
@@ -4,6 +4,7 @@
 
 use crate::{
   helpers::app_paths::{frontend_dir, tauri_dir},
+  migrate::Changes,
   Result,
 };
 
@@ -12,27 +13,35 @@ use anyhow::Context;
 mod config;
 mod frontend;
 mod manifest;
+mod rust;
 
-pub fn run() -> Result<()> {
+pub fn run(changes: &mut Changes) -> Result<()> {
   let tauri_dir = tauri_dir();
   let frontend_dir = frontend_dir();
 
-  let mut migrated = config::migrate(tauri_dir).context("Could not migrate config")?;
-  manifest::migrate(tauri_dir).context("Could not migrate manifest")?;
-  let plugins = frontend::migrate(frontend_dir)?;
+  let mut migrated = config::migrate(tauri_dir, changes).context("Could not migrate config")?;
+  manifest::migrate(tauri_dir, changes).context("Could not migrate manifest")?;
+  rust::migrate(tauri_dir, changes).context("Could not migrate Rust sources")?;
+  let plugins = frontend::migrate(frontend_dir, changes)?;
 
   migrated.plugins.extend(plugins);
 
   // Add plugins
   for plugin in migrated.plugins {
-    crate::add::run(crate::add::Options {
-      plugin: plugin.clone(),
-      branch: None,
-      tag: None,
-      rev: None,
-      no_fmt: false,
-    })
-    .with_context(|| format!("Could not migrate plugin '{plugin}'"))?;
+    changes
+      .apply(
+        format!("Add the `{plugin}` plugin (`tauri add {plugin}`)"),
+        || {
+          crate::add::run(crate::add::Options {
+            plugin: plugin.clone(),
+            branch: None,
+            tag: None,
+            rev: None,
+            no_fmt: false,
+          })
+        },
+      )
+      .with_context(|| format!("Could not migrate plugin '{plugin}'"))?;
   }
 
   Ok(())
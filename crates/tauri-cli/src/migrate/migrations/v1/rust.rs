@@ -0,0 +1,299 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{helpers::app_paths::walk_builder, migrate::Changes, Result};
+
+use anyhow::Context;
+
+use std::{fs, path::Path};
+
+// (from, to)
+const RENAMED_METHODS: phf::Map<&str, &str> = phf::phf_map! {
+  "get_window" => "get_webview_window",
+  "emit_all" => "emit",
+  "listen_global" => "listen_any",
+  "once_global" => "once_any",
+};
+// (method, how to migrate it)
+const CHANGED_METHODS: phf::Map<&str, &str> = phf::phf_map! {
+  "system_tray" => "the system tray was replaced with `tauri::tray::TrayIconBuilder`, create the tray icon in `setup`",
+  "on_system_tray_event" => "the system tray was replaced with `tauri::tray::TrayIconBuilder`, use its `on_tray_icon_event` and `on_menu_event` instead",
+  "on_window_event" => "the handler now receives the window and the event as separate arguments",
+  "on_menu_event" => "the handler now receives the app handle and the menu event",
+  "register_uri_scheme_protocol" => "the handler now receives a `UriSchemeContext` instead of the app handle",
+  "path_resolver" => "use `path()` instead, resources are resolved with `BaseDirectory::Resource`",
+  "invoke_system" => "custom invoke systems are no longer supported",
+};
+// traits that must be in scope for the renamed methods
+const RENAMED_METHOD_TRAITS: phf::Map<&str, &str> = phf::phf_map! {
+  "emit" => "Emitter",
+  "listen_any" => "Listener",
+  "once_any" => "Listener",
+};
+
+pub fn migrate(tauri_dir: &Path, changes: &mut Changes) -> Result<()> {
+  let src_dir = tauri_dir.join("src");
+  if !src_dir.exists() {
+    return Ok(());
+  }
+
+  for entry in walk_builder(&src_dir).build().flatten() {
+    let path = entry.path();
+    if !entry.file_type().map(|t| t.is_file()).unwrap_or_default()
+      || path.extension().map_or(true, |ext| ext != "rs")
+    {
+      continue;
+    }
+
+    let contents = fs::read_to_string(path)?;
+    let (new_contents, notes) = migrate_source(&contents);
+    if new_contents != contents {
+      changes.apply(
+        format!("Migrate the renamed Tauri methods in {}", path.display()),
+        || {
+          fs::write(path, new_contents).with_context(|| format!("Error writing {}", path.display()))
+        },
+      )?;
+    }
+    for note in notes {
+      log::warn!("{}: {note}", path.display());
+    }
+  }
+
+  Ok(())
+}
+
+/// Rewrites the calls to renamed v1 methods in the given Rust source.
+///
+/// Returns the migrated source and notes about the calls that must be migrated manually.
+fn migrate_source(source: &str) -> (String, Vec<String>) {
+  let tokens = tokenize(source);
+  let mut replacements = Vec::new();
+  let mut notes = Vec::new();
+  let mut required_traits = Vec::new();
+
+  for window in tokens.windows(3) {
+    let [Token::Punct(_, '.'), Token::Ident(start, name), next] = window else {
+      continue;
+    };
+    // only method calls, optionally with a turbofish
+    if !matches!(next, Token::Punct(_, '(' | ':')) {
+      continue;
+    }
+    if let Some(new_name) = RENAMED_METHODS.get(*name) {
+      replacements.push((*start, name.len(), *new_name));
+      if let Some(trait_) = RENAMED_METHOD_TRAITS.get(*new_name) {
+        if !required_traits.contains(trait_) {
+          required_traits.push(*trait_);
+        }
+      }
+    } else if let Some(message) = CHANGED_METHODS.get(*name) {
+      let line = source[..*start].matches('\n').count() + 1;
+      notes.push(format!(
+        "line {line}: `{name}` must be migrated manually, {message}"
+      ));
+    }
+  }
+
+  for trait_ in required_traits {
+    if !tokens
+      .iter()
+      .any(|token| matches!(token, Token::Ident(_, name) if *name == trait_))
+    {
+      notes.push(format!(
+        "`tauri::{trait_}` must be imported to use the migrated methods"
+      ));
+    }
+  }
+
+  let mut migrated = source.to_string();
+  for (start, len, new_name) in replacements.into_iter().rev() {
+    migrated.replace_range(start..start + len, new_name);
+  }
+
+  (migrated, notes)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+  /// An identifier and its byte offset.
+  Ident(usize, &'a str),
+  /// A punctuation character and its byte offset.
+  Punct(usize, char),
+}
+
+/// Splits Rust source into identifiers and punctuation, skipping comments and literals
+/// so their contents are never rewritten.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+  let bytes = source.as_bytes();
+  let mut tokens = Vec::new();
+  let mut i = 0;
+
+  while i < bytes.len() {
+    let c = bytes[i];
+    match c {
+      b'/' if bytes.get(i + 1) == Some(&b'/') => {
+        i = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+      }
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        let mut depth = 0;
+        while i < bytes.len() {
+          if bytes[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+          } else if bytes[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+              break;
+            }
+          } else {
+            i += 1;
+          }
+        }
+      }
+      b'"' => i = skip_string(bytes, i + 1),
+      b'\'' => {
+        if bytes.get(i + 1) == Some(&b'\\') {
+          // escaped char literal
+          i += 3;
+          while i < bytes.len() && bytes[i] != b'\'' {
+            i += 1;
+          }
+          i += 1;
+        } else {
+          let next_len = source[i + 1..].chars().next().map_or(0, char::len_utf8);
+          if bytes.get(i + 1 + next_len) == Some(&b'\'') {
+            // char literal
+            i += next_len + 2;
+          } else {
+            // lifetime or label
+            i += 1;
+            while i < bytes.len() && is_ident_char(bytes[i]) {
+              i += 1;
+            }
+          }
+        }
+      }
+      c if c.is_ascii_alphabetic() || c == b'_' => {
+        let start = i;
+        while i < bytes.len() && is_ident_char(bytes[i]) {
+          i += 1;
+        }
+        let ident = &source[start..i];
+        match (ident, bytes.get(i)) {
+          ("r" | "br", Some(b'"' | b'#')) => i = skip_raw_string(bytes, i),
+          ("b", Some(b'"')) => i = skip_string(bytes, i + 1),
+          ("b", Some(b'\'')) => {
+            i += 1;
+            if bytes.get(i) == Some(&b'\\') {
+              i += 1;
+            }
+            i += 1;
+            while i < bytes.len() && bytes[i] != b'\'' {
+              i += 1;
+            }
+            i += 1;
+          }
+          _ => tokens.push(Token::Ident(start, ident)),
+        }
+      }
+      c if c.is_ascii_digit() => {
+        // numbers, including suffixes such as `1.0f64` but not tuple field accesses such as `.0.get_window()`
+        while i < bytes.len()
+          && (is_ident_char(bytes[i])
+            || (bytes[i] == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)))
+        {
+          i += 1;
+        }
+      }
+      c if c.is_ascii_whitespace() => i += 1,
+      _ => {
+        let c = source[i..].chars().next().unwrap();
+        tokens.push(Token::Punct(i, c));
+        i += c.len_utf8();
+      }
+    }
+  }
+
+  tokens
+}
+
+fn is_ident_char(c: u8) -> bool {
+  c.is_ascii_alphanumeric() || c == b'_'
+}
+
+/// Skips a string literal, `i` being the index after the opening quote.
+fn skip_string(bytes: &[u8], mut i: usize) -> usize {
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 2,
+      b'"' => return i + 1,
+      _ => i += 1,
+    }
+  }
+  i
+}
+
+/// Skips a raw string literal, `i` being the index after the `r` prefix.
+fn skip_raw_string(bytes: &[u8], mut i: usize) -> usize {
+  let hashes = bytes[i..].iter().take_while(|c| **c == b'#').count();
+  i += hashes;
+  if bytes.get(i) != Some(&b'"') {
+    // raw identifier such as `r#type`
+    return i;
+  }
+  let mut closing = vec![b'"'];
+  closing.extend(std::iter::repeat(b'#').take(hashes));
+  i += 1;
+  while i < bytes.len() {
+    if bytes[i..].starts_with(&closing) {
+      return i + closing.len();
+    }
+    i += 1;
+  }
+  i
+}
+
+#[cfg(test)]
+mod tests {
+  use super::migrate_source;
+
+  #[test]
+  fn migrates_renamed_methods() {
+    let (migrated, notes) = migrate_source(include_str!("./fixtures/main.v1.rs"));
+    assert_eq!(migrated, include_str!("./fixtures/main.v2.rs"));
+    assert_eq!(
+      notes,
+      vec![
+        "line 14: `system_tray` must be migrated manually, the system tray was replaced with `tauri::tray::TrayIconBuilder`, create the tray icon in `setup`",
+        "line 15: `on_window_event` must be migrated manually, the handler now receives the window and the event as separate arguments",
+        "`tauri::Emitter` must be imported to use the migrated methods",
+        "`tauri::Listener` must be imported to use the migrated methods",
+      ]
+    );
+  }
+
+  #[test]
+  fn skips_comments_and_literals() {
+    let source = r##"
+// app.get_window("main")
+/* app.emit_all("event", ()) /* nested */ app.get_window("main") */
+let s = "app.get_window(\"main\")";
+let r = r#"app.emit_all("event", ())"#;
+let c = '.';
+fn get_window<'a>(app: &'a App) {}
+let w = app.get_window ("main");
+"##;
+    let (migrated, notes) = migrate_source(source);
+    assert_eq!(
+      migrated,
+      source.replace(
+        "app.get_window (\"main\")",
+        "app.get_webview_window (\"main\")"
+      )
+    );
+    assert!(notes.is_empty());
+  }
+}
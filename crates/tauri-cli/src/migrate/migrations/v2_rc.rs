@@ -8,6 +8,7 @@ use crate::{
     npm::PackageManager,
   },
   interface::rust::manifest::{read_manifest, serialize_manifest},
+  migrate::Changes,
   Result,
 };
 
@@ -16,7 +17,7 @@ use std::{fs::read_to_string, path::Path};
 use anyhow::Context;
 use toml_edit::{DocumentMut, Item, Table, TableLike, Value};
 
-pub fn run() -> Result<()> {
+pub fn run(changes: &mut Changes) -> Result<()> {
   let frontend_dir = frontend_dir();
   let tauri_dir = tauri_dir();
 
@@ -24,17 +25,22 @@ pub fn run() -> Result<()> {
   let (mut manifest, _) = read_manifest(&manifest_path)?;
   migrate_manifest(&mut manifest)?;
 
-  migrate_permissions(tauri_dir)?;
+  migrate_permissions(tauri_dir, changes)?;
 
-  migrate_npm_dependencies(frontend_dir)?;
+  migrate_npm_dependencies(frontend_dir, changes)?;
 
-  std::fs::write(&manifest_path, serialize_manifest(&manifest))
-    .context("failed to rewrite Cargo manifest")?;
+  changes.apply(
+    format!("Update the dependencies in {}", manifest_path.display()),
+    || {
+      std::fs::write(&manifest_path, serialize_manifest(&manifest))
+        .context("failed to rewrite Cargo manifest")
+    },
+  )?;
 
   Ok(())
 }
 
-fn migrate_npm_dependencies(frontend_dir: &Path) -> Result<()> {
+fn migrate_npm_dependencies(frontend_dir: &Path, changes: &mut Changes) -> Result<()> {
   let pm = PackageManager::from_project(frontend_dir);
 
   let mut install_deps = Vec::new();
@@ -77,13 +83,16 @@ fn migrate_npm_dependencies(frontend_dir: &Path) -> Result<()> {
   }
 
   if !install_deps.is_empty() {
-    pm.install(&install_deps, frontend_dir)?;
+    changes.apply(
+      format!("Install the npm packages {}", install_deps.join(", ")),
+      || pm.install(&install_deps, frontend_dir),
+    )?;
   }
 
   Ok(())
 }
 
-fn migrate_permissions(tauri_dir: &Path) -> Result<()> {
+fn migrate_permissions(tauri_dir: &Path, changes: &mut Changes) -> Result<()> {
   let core_plugins = [
     "app",
     "event",
@@ -100,11 +109,20 @@ fn migrate_permissions(tauri_dir: &Path) -> Result<()> {
     let entry = entry?;
     let path = entry.path();
     if path.extension().map_or(false, |ext| ext == "json") {
-      let mut capability = read_to_string(path).context("failed to read capability")?;
+      let original = read_to_string(path).context("failed to read capability")?;
+      let mut capability = original.clone();
       for plugin in core_plugins {
         capability = capability.replace(&format!("\"{plugin}:"), &format!("\"core:{plugin}:"));
       }
-      std::fs::write(path, capability).context("failed to rewrite capability")?;
+      if capability != original {
+        changes.apply(
+          format!(
+            "Prefix the core permissions in {} with `core:`",
+            path.display()
+          ),
+          || std::fs::write(path, capability).context("failed to rewrite capability"),
+        )?;
+      }
     }
   }
   Ok(())
@@ -14,10 +14,56 @@ use crate::{
 use std::{fs::read_to_string, str::FromStr};
 
 use anyhow::Context;
+use clap::Parser;
 
 mod migrations;
 
-pub fn command() -> Result<()> {
+#[derive(Debug, Parser)]
+#[clap(about = "Migrate from v1 to v2")]
+pub struct Options {
+  /// Ask for confirmation before applying each change.
+  ///
+  /// Declined changes are listed at the end so they can be applied manually later.
+  #[clap(short, long)]
+  pub interactive: bool,
+}
+
+/// Applies the individual changes of a migration, asking for confirmation first in interactive mode.
+pub struct Changes {
+  interactive: bool,
+  skipped: Vec<String>,
+}
+
+impl Changes {
+  pub fn new(interactive: bool) -> Self {
+    Self {
+      interactive,
+      skipped: Vec::new(),
+    }
+  }
+
+  /// Runs `change` unless the user declines it, returning whether it was applied.
+  pub fn apply<F: FnOnce() -> Result<()>>(
+    &mut self,
+    description: impl Into<String>,
+    change: F,
+  ) -> Result<bool> {
+    let description = description.into();
+    if self.interactive && !crate::helpers::prompts::confirm(&description, Some(true))? {
+      self.skipped.push(description);
+      return Ok(false);
+    }
+    change()?;
+    Ok(true)
+  }
+
+  /// The descriptions of the changes the user declined.
+  pub fn skipped(&self) -> &[String] {
+    &self.skipped
+  }
+}
+
+pub fn command(options: Options) -> Result<()> {
   crate::helpers::app_paths::resolve();
 
   let tauri_dir = tauri_dir();
@@ -43,15 +89,46 @@ pub fn command() -> Result<()> {
     .context("failed to get tauri version")?;
   let tauri_version = semver::Version::from_str(&tauri_version)?;
 
+  let mut changes = Changes::new(options.interactive);
+
   if tauri_version.major == 1 {
-    migrations::v1::run().context("failed to migrate from v1")?;
+    migrations::v1::run(&mut changes).context("failed to migrate from v1")?;
   } else if tauri_version.major == 2 {
     if let Some((pre, _number)) = tauri_version.pre.as_str().split_once('.') {
       if pre == "beta" {
-        migrations::v2_rc::run().context("failed to migrate from v2 beta to rc")?;
+        migrations::v2_rc::run(&mut changes).context("failed to migrate from v2 beta to rc")?;
       }
     }
   }
 
+  if !changes.skipped().is_empty() {
+    log::warn!("Skipped the following changes, apply them manually to finish the migration:");
+    for change in changes.skipped() {
+      log::warn!("  - {change}");
+    }
+  }
+
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::Changes;
+
+  #[test]
+  fn applies_all_changes_when_not_interactive() {
+    let mut changes = Changes::new(false);
+    let mut applied = Vec::new();
+    for change in ["Rewrite tauri.conf.json", "Add the `dialog` plugin"] {
+      let result = changes
+        .apply(change, || {
+          applied.push(change);
+          Ok(())
+        })
+        .unwrap();
+      assert!(result);
+    }
+    assert_eq!(applied.len(), 2);
+    assert!(changes.skipped().is_empty());
+  }
+}
@@ -33,9 +33,11 @@ use cargo_mobile2::{
 use rand::distributions::{Alphanumeric, DistString};
 
 use std::{
+  collections::BTreeMap,
   env::{set_current_dir, var, var_os},
   fs,
   path::PathBuf,
+  process::Command,
 };
 
 #[derive(Debug, Clone, Parser)]
@@ -57,6 +59,18 @@ pub struct Options {
     value_parser(clap::builder::PossibleValuesParser::new(Target::name_list()))
   )]
   pub targets: Vec<String>,
+  /// Build the Rust library for these architectures and combine them into an XCFramework.
+  ///
+  /// Use this to distribute a library supporting both devices and simulators.
+  /// The simulator architectures are merged into a single fat binary.
+  #[clap(
+    long,
+    value_delimiter = ',',
+    num_args(1..),
+    conflicts_with = "targets",
+    value_parser(clap::builder::PossibleValuesParser::new(Target::name_list()))
+  )]
+  pub archs: Option<Vec<String>>,
   /// List of cargo features to activate
   #[clap(short, long, action = ArgAction::Append, num_args(0..))]
   pub features: Option<Vec<String>>,
@@ -77,6 +91,19 @@ pub struct Options {
   /// Use this to create a package ready for the App Store (app-store-connect option) or TestFlight (release-testing option).
   #[clap(long, value_enum)]
   pub export_method: Option<ExportMethod>,
+  /// Generate debug symbols (dSYM) for crash symbolication.
+  ///
+  /// The dSYM bundle is copied next to the generated artifacts.
+  /// It is generated by the release configuration of the Xcode project,
+  /// which uses the `dwarf-with-dsym` debug information format by default,
+  /// so this cannot be used with `--debug`.
+  #[clap(long, alias = "with-symbols", conflicts_with = "debug")]
+  pub dsym: bool,
+  /// Rebuild from scratch instead of reusing the artifacts of previous builds.
+  ///
+  /// By default the Rust library and the Xcode project are built incrementally.
+  #[clap(long)]
+  pub clean: bool,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -125,9 +152,14 @@ impl From<Options> for BuildOptions {
   }
 }
 
-pub fn command(options: Options, noise_level: NoiseLevel) -> Result<()> {
+pub fn command(mut options: Options, noise_level: NoiseLevel) -> Result<()> {
   crate::helpers::app_paths::resolve();
 
+  if let Some(archs) = &options.archs {
+    validate_archs(archs)?;
+    options.targets = archs.clone();
+  }
+
   let mut build_options: BuildOptions = options.clone().into();
   build_options.target = Some(
     Target::all()
@@ -212,6 +244,9 @@ pub fn command(options: Options, noise_level: NoiseLevel) -> Result<()> {
     &project_config,
     options.debug,
   )?;
+  if options.dsym && matches!(options.export_method, Some(ExportMethod::AppStoreConnect)) {
+    export_options_plist.insert("uploadSymbols".to_string(), true.into());
+  }
   if pbxproj.has_changes() {
     pbxproj.save()?;
   }
@@ -279,12 +314,19 @@ fn run_build(
   })?;
   let _lock = flock::open_rw(out_dir.join("lock").with_extension("ios"), "iOS")?;
 
+  let mut vars = std::collections::HashMap::new();
+  if options.dsym {
+    // the Rust library must keep its DWARF info so dsymutil can extract it
+    vars.insert("CARGO_PROFILE_RELEASE_DEBUG".into(), "true".into());
+    vars.insert("CARGO_PROFILE_RELEASE_STRIP".into(), "none".into());
+  }
+
   let cli_options = CliOptions {
     dev: false,
     features: build_options.features.clone(),
     args: build_options.args.clone(),
     noise_level,
-    vars: Default::default(),
+    vars,
     config: build_options.config.clone(),
     target_device: None,
   };
@@ -294,6 +336,13 @@ fn run_build(
   )?;
 
   let mut out_files = Vec::new();
+  let mut dsym_files = Vec::new();
+
+  if options.clean {
+    log::info!(action = "Cleaning"; "previous iOS build artifacts");
+  } else {
+    log::info!("Building incrementally, reusing the artifacts of previous iOS builds. Use `--clean` to rebuild from scratch.");
+  }
 
   call_for_targets_with_fallback(
     options.targets.iter(),
@@ -315,6 +364,10 @@ fn run_build(
           .skip_codesign();
       }
 
+      if options.clean {
+        clean_target(config, target, profile)?;
+      }
+
       target.build(config, env, noise_level, profile, build_config)?;
 
       let mut archive_config = ArchiveConfig::new();
@@ -403,16 +456,171 @@ fn run_build(
         }
       }
 
+      if options.dsym {
+        let dsym_path = config
+          .archive_dir()
+          .join(format!("{}.xcarchive", config.scheme()))
+          .join("dSYMs")
+          .join(format!("{}.app.dSYM", config.app().stylized_name()));
+
+        if dsym_path.exists() {
+          fs::create_dir_all(&out_dir)?;
+          let path = out_dir.join(dsym_path.file_name().unwrap());
+          if path.exists() {
+            fs::remove_dir_all(&path)?;
+          }
+          fs::rename(&dsym_path, &path)?;
+          dsym_files.push(path);
+        } else {
+          log::warn!("debug symbols were not found at {}", dsym_path.display());
+        }
+      }
+
       Ok(())
     },
   )
   .map_err(|e: TargetInvalid| anyhow::anyhow!(e.to_string()))??;
 
   log_finished(out_files, "iOS Bundle");
+  log_finished(dsym_files, "iOS dSYM");
+
+  if let Some(archs) = &options.archs {
+    let xcframework = create_xcframework(&interface, config, &validate_archs(archs)?, profile)?;
+    log_finished(vec![xcframework], "iOS XCFramework");
+  }
 
   Ok(handle)
 }
 
+/// Removes the Cargo output of the target and the Xcode artifacts so the next build starts from scratch.
+fn clean_target(config: &AppleConfig, target: &Target, profile: Profile) -> Result<()> {
+  let mut cargo = Command::new("cargo");
+  cargo.args(["clean", "--target", target.triple]);
+  if matches!(profile, Profile::Release) {
+    cargo.arg("--release");
+  }
+  let status = cargo.status().context("failed to run `cargo clean`")?;
+  if !status.success() {
+    anyhow::bail!("Failed to clean the {} build", target.triple);
+  }
+
+  for path in [
+    config.project_dir().join("Externals").join(target.arch),
+    config
+      .archive_dir()
+      .join(format!("{}.xcarchive", config.scheme())),
+  ] {
+    if path.exists() {
+      fs::remove_dir_all(&path).with_context(|| format!("failed to remove {}", path.display()))?;
+    }
+  }
+
+  Ok(())
+}
+
+/// Resolves the `--archs` values, which must not contain the same target twice.
+fn validate_archs(archs: &[String]) -> Result<Vec<&'static Target>> {
+  let mut targets = Vec::new();
+  for arch in archs {
+    let target = Target::all().get(arch.as_str()).ok_or_else(|| {
+      anyhow::anyhow!(
+        "unknown iOS architecture `{arch}`, expected one of {}",
+        Target::name_list().join(", ")
+      )
+    })?;
+    if targets.iter().any(|t: &&Target| t.triple == target.triple) {
+      anyhow::bail!("the `{arch}` architecture was specified more than once");
+    }
+    targets.push(target);
+  }
+  Ok(targets)
+}
+
+/// Groups the targets by SDK, as an XCFramework has a single library for each platform.
+fn xcframework_slices<'a, 't>(
+  targets: &[&'a Target<'t>],
+) -> BTreeMap<&'t str, Vec<&'a Target<'t>>> {
+  let mut slices = BTreeMap::<_, Vec<_>>::new();
+  for target in targets {
+    slices.entry(target.sdk).or_default().push(*target);
+  }
+  slices
+}
+
+/// Combines the Rust libraries built for the targets into an XCFramework in the export directory.
+fn create_xcframework(
+  interface: &AppInterface,
+  config: &AppleConfig,
+  targets: &[&Target],
+  profile: Profile,
+) -> Result<PathBuf> {
+  let lib_file_name = format!("lib{}.a", config.app().lib_name());
+  let mut libraries = Vec::new();
+
+  for (sdk, targets) in xcframework_slices(targets) {
+    let mut sdk_libraries = Vec::new();
+    for target in targets {
+      let out_dir = interface.app_settings().out_dir(&InterfaceOptions {
+        debug: matches!(profile, Profile::Debug),
+        target: Some(target.triple.into()),
+        ..Default::default()
+      })?;
+      let lib_path = out_dir.join(&lib_file_name);
+      if !lib_path.exists() {
+        anyhow::bail!(
+          "Library for {} not found at {}",
+          target.triple,
+          lib_path.display()
+        );
+      }
+      sdk_libraries.push(lib_path);
+    }
+
+    if sdk_libraries.len() == 1 {
+      libraries.extend(sdk_libraries);
+    } else {
+      let fat_lib_dir = config.export_dir().join("xcframework").join(sdk);
+      fs::create_dir_all(&fat_lib_dir)?;
+      let fat_lib_path = fat_lib_dir.join(&lib_file_name);
+      let status = Command::new("lipo")
+        .arg("-create")
+        .args(&sdk_libraries)
+        .arg("-output")
+        .arg(&fat_lib_path)
+        .status()
+        .context("failed to run `lipo`")?;
+      if !status.success() {
+        anyhow::bail!("Failed to merge the {sdk} libraries into a fat binary");
+      }
+      libraries.push(fat_lib_path);
+    }
+  }
+
+  let xcframework_path = config
+    .export_dir()
+    .join(format!("{}.xcframework", config.app().lib_name()));
+  if xcframework_path.exists() {
+    fs::remove_dir_all(&xcframework_path)
+      .with_context(|| format!("failed to remove {}", xcframework_path.display()))?;
+  }
+
+  let mut xcodebuild = Command::new("xcodebuild");
+  xcodebuild.arg("-create-xcframework");
+  for library in &libraries {
+    xcodebuild.arg("-library").arg(library);
+  }
+  let status = xcodebuild
+    .arg("-output")
+    .arg(&xcframework_path)
+    .status()
+    .context("failed to run `xcodebuild -create-xcframework`")?;
+  if !status.success() {
+    anyhow::bail!("Failed to create the XCFramework");
+  }
+
+  Ok(xcframework_path)
+}
+
 fn auth_credentials_from_env() -> Result<Option<cargo_mobile2::apple::AuthCredentials>> {
   match (
     var("APPLE_API_KEY"),
@@ -432,3 +640,35 @@ fn auth_credentials_from_env() -> Result<Option<cargo_mobile2::apple::AuthCreden
     ),
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{validate_archs, xcframework_slices};
+
+  #[test]
+  fn groups_archs_by_sdk() {
+    let targets = validate_archs(&[
+      "aarch64".to_string(),
+      "aarch64-sim".to_string(),
+      "x86_64".to_string(),
+    ])
+    .unwrap();
+    let slices = xcframework_slices(&targets)
+      .into_iter()
+      .map(|(sdk, targets)| (sdk, targets.iter().map(|t| t.triple).collect::<Vec<_>>()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      slices,
+      vec![
+        ("iphoneos", vec!["aarch64-apple-ios"]),
+        (
+          "iphonesimulator",
+          vec!["aarch64-apple-ios-sim", "x86_64-apple-ios"]
+        ),
+      ]
+    );
+
+    assert!(validate_archs(&["aarch64".to_string(), "aarch64".to_string()]).is_err());
+    assert!(validate_archs(&["armv7".to_string()]).is_err());
+  }
+}
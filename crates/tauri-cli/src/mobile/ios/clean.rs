@@ -0,0 +1,188 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+  helpers::{
+    app_paths::{resolve as resolve_app_paths, tauri_dir},
+    prompts,
+  },
+  Result,
+};
+
+use anyhow::Context;
+use clap::Parser;
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+/// Name of the folder, next to the generated project, where the project is moved to.
+const BACKUP_DIR_NAME: &str = "apple.backup";
+
+/// File names and extensions of the generated project that are usually edited by hand.
+const CUSTOMIZABLE_FILES: &[&str] = &[
+  "Info.plist",
+  "ExportOptions.plist",
+  "LaunchScreen.storyboard",
+  "Podfile",
+  "project.yml",
+  ".entitlements",
+];
+
+#[derive(Debug, Parser)]
+#[clap(
+  about = "Delete the generated Xcode project so the next `ios init` or `ios dev` regenerates it"
+)]
+pub struct Options {
+  /// Skip the confirmation prompt
+  #[clap(short, long)]
+  yes: bool,
+  /// Delete the project instead of moving it to `gen/apple.backup`
+  #[clap(long)]
+  no_backup: bool,
+}
+
+pub fn command(options: Options) -> Result<()> {
+  resolve_app_paths();
+
+  let gen_dir = tauri_dir().join("gen");
+  let project_dir = gen_dir.join("apple");
+  if !project_dir.exists() {
+    log::info!("No iOS project found at {}", project_dir.display());
+    return Ok(());
+  }
+
+  let customized = customizable_files(&project_dir);
+  if !customized.is_empty() {
+    log::warn!(
+      "The iOS project contains files that are usually customized, make sure to re-apply your changes after regenerating it:\n{}",
+      customized
+        .iter()
+        .map(|path| format!("- {}", path.display()))
+        .collect::<Vec<_>>()
+        .join("\n")
+    );
+  }
+
+  let prompt = if options.no_backup {
+    format!("Delete {}?", project_dir.display())
+  } else {
+    format!(
+      "Move {} to {}?",
+      project_dir.display(),
+      gen_dir.join(BACKUP_DIR_NAME).display()
+    )
+  };
+  if !options.yes && !prompts::confirm(&prompt, Some(false))? {
+    return Ok(());
+  }
+
+  if options.no_backup {
+    fs::remove_dir_all(&project_dir)
+      .with_context(|| format!("failed to delete {}", project_dir.display()))?;
+    log::info!("Deleted {}", project_dir.display());
+  } else {
+    let backup_dir = backup_project(&project_dir)?;
+    log::info!(
+      "Moved {} to {}",
+      project_dir.display(),
+      backup_dir.display()
+    );
+  }
+
+  log::info!("Run `tauri ios init` to regenerate the iOS project");
+
+  Ok(())
+}
+
+/// Moves the project to the backup folder next to it, replacing the previous backup.
+fn backup_project(project_dir: &Path) -> Result<PathBuf> {
+  let backup_dir = project_dir.with_file_name(BACKUP_DIR_NAME);
+  if backup_dir.exists() {
+    fs::remove_dir_all(&backup_dir)
+      .with_context(|| format!("failed to delete {}", backup_dir.display()))?;
+  }
+  fs::rename(project_dir, &backup_dir).with_context(|| {
+    format!(
+      "failed to move {} to {}",
+      project_dir.display(),
+      backup_dir.display()
+    )
+  })?;
+  Ok(backup_dir)
+}
+
+/// The files of the project matching [`CUSTOMIZABLE_FILES`], relative to the project folder.
+fn customizable_files(project_dir: &Path) -> Vec<PathBuf> {
+  let mut files = walkdir::WalkDir::new(project_dir)
+    .into_iter()
+    .filter_entry(|entry| {
+      // skip build outputs and installed pods
+      !matches!(
+        entry.file_name().to_str(),
+        Some("build" | "Pods" | "Externals")
+      )
+    })
+    .flatten()
+    .filter(|entry| {
+      entry.file_type().is_file()
+        && entry.file_name().to_str().is_some_and(|name| {
+          CUSTOMIZABLE_FILES
+            .iter()
+            .any(|file| name == *file || (file.starts_with('.') && name.ends_with(file)))
+        })
+    })
+    .filter_map(|entry| {
+      entry
+        .path()
+        .strip_prefix(project_dir)
+        .ok()
+        .map(Path::to_path_buf)
+    })
+    .collect::<Vec<_>>();
+  files.sort();
+  files
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{fs, path::PathBuf};
+
+  use super::{backup_project, customizable_files};
+
+  #[test]
+  fn backs_up_project() {
+    let gen_dir = tempfile::tempdir().unwrap();
+    let project_dir = gen_dir.path().join("apple");
+    for file in [
+      "project.yml",
+      "app_iOS/Info.plist",
+      "app_iOS/app_iOS.entitlements",
+      "app.xcodeproj/project.pbxproj",
+      "build/app_iOS/Info.plist",
+    ] {
+      let path = project_dir.join(file);
+      fs::create_dir_all(path.parent().unwrap()).unwrap();
+      fs::write(path, "").unwrap();
+    }
+
+    assert_eq!(
+      customizable_files(&project_dir),
+      vec![
+        PathBuf::from("app_iOS/Info.plist"),
+        PathBuf::from("app_iOS/app_iOS.entitlements"),
+        PathBuf::from("project.yml"),
+      ]
+    );
+
+    // a previous backup is replaced
+    fs::create_dir_all(gen_dir.path().join("apple.backup/stale")).unwrap();
+    let backup_dir = backup_project(&project_dir).unwrap();
+    assert_eq!(backup_dir, gen_dir.path().join("apple.backup"));
+    assert!(!project_dir.exists());
+    assert!(backup_dir.join("app_iOS/Info.plist").exists());
+    assert!(!backup_dir.join("stale").exists());
+  }
+}
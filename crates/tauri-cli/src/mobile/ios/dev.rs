@@ -20,6 +20,8 @@ use crate::{
   ConfigValue, Result,
 };
 use clap::{ArgAction, Parser};
+use log::{Level, LevelFilter};
+use regex::Regex;
 
 use anyhow::Context;
 use cargo_mobile2::{
@@ -31,7 +33,11 @@ use cargo_mobile2::{
   opts::{NoiseLevel, Profile},
 };
 
-use std::env::set_current_dir;
+use std::{
+  env::set_current_dir,
+  io::{BufRead, BufReader, Write},
+  os::fd::AsRawFd,
+};
 
 const PHYSICAL_IPHONE_DEV_WARNING: &str = "To develop on physical phones you need the `--host` option (not required for Simulators). See the documentation for more information: https://v2.tauri.app/develop/#development-server";
 
@@ -94,6 +100,88 @@ pub struct Options {
   /// Specify port for the built-in dev server for static files. Defaults to 1430.
   #[clap(long, env = "TAURI_CLI_PORT")]
   pub port: Option<u16>,
+  /// Only print device console lines matching the given regular expression.
+  ///
+  /// Note that `println!` and other stdout/stderr output from the app is forwarded to the logger
+  /// at the `INFO` level by the mobile entry point (`tauri::log_stdout`), so it is filtered like any other log line.
+  #[clap(long)]
+  pub log_filter: Option<Regex>,
+  /// Only print device console lines logged with the given level or a more severe one.
+  ///
+  /// Lines without a recognizable level (`ERROR`, `WARN`, `INFO`, `DEBUG` or `TRACE`) are always printed.
+  #[clap(long)]
+  pub log_level: Option<LevelFilter>,
+}
+
+/// Filters the device console output before it is printed.
+#[derive(Debug, Clone)]
+struct LogFilter {
+  pattern: Option<Regex>,
+  level: Option<LevelFilter>,
+}
+
+impl LogFilter {
+  fn from_options(options: &Options) -> Option<Self> {
+    if options.log_filter.is_none() && options.log_level.is_none() {
+      return None;
+    }
+    Some(Self {
+      pattern: options.log_filter.clone(),
+      level: options.log_level,
+    })
+  }
+
+  fn matches(&self, line: &str) -> bool {
+    if let (Some(max_level), Some(level)) = (self.level, line_level(line)) {
+      if level > max_level {
+        return false;
+      }
+    }
+    self
+      .pattern
+      .as_ref()
+      .map_or(true, |pattern| pattern.is_match(line))
+  }
+
+  /// Routes the CLI stdout through this filter.
+  ///
+  /// The device process inherits our stdout, so this is the only place where its output can be intercepted.
+  /// The CLI logs are written to stderr and are not affected.
+  fn apply_to_stdout(self) -> Result<()> {
+    let (reader, writer) = os_pipe::pipe()?;
+    let mut stdout = os_pipe::dup_stdout()?;
+    if unsafe { libc::dup2(writer.as_raw_fd(), libc::STDOUT_FILENO) } == -1 {
+      return Err(std::io::Error::last_os_error()).context("failed to redirect stdout");
+    }
+    drop(writer);
+
+    std::thread::spawn(move || {
+      for line in BufReader::new(reader)
+        .lines()
+        .map_while(std::io::Result::ok)
+      {
+        if self.matches(&line) {
+          let _ = writeln!(stdout, "{line}");
+        }
+      }
+    });
+
+    Ok(())
+  }
+}
+
+/// Finds the first log level token in the given console line.
+fn line_level(line: &str) -> Option<Level> {
+  line
+    .split(|c: char| !c.is_ascii_alphabetic())
+    .find_map(|token| match token {
+      "ERROR" => Some(Level::Error),
+      "WARN" => Some(Level::Warn),
+      "INFO" => Some(Level::Info),
+      "DEBUG" => Some(Level::Debug),
+      "TRACE" => Some(Level::Trace),
+      _ => None,
+    })
 }
 
 impl From<Options> for DevOptions {
@@ -253,6 +341,12 @@ fn run_dev(
 
   let set_host = options.host.0.is_some();
 
+  if !options.open && device.is_some() {
+    if let Some(filter) = LogFilter::from_options(&options) {
+      filter.apply_to_stdout()?;
+    }
+  }
+
   let open = options.open;
   interface.mobile_dev(
     MobileOptions {
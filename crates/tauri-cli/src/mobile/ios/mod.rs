@@ -44,6 +44,7 @@ use std::{
 };
 
 mod build;
+mod clean;
 mod dev;
 pub(crate) mod project;
 mod xcode_script;
@@ -83,6 +84,7 @@ enum Commands {
   Init(InitOptions),
   Dev(dev::Options),
   Build(build::Options),
+  Clean(clean::Options),
   #[clap(hide(true))]
   XcodeScript(xcode_script::Options),
 }
@@ -101,6 +103,7 @@ pub fn command(cli: Cli, verbosity: u8) -> Result<()> {
     }
     Commands::Dev(options) => dev::command(options, noise_level)?,
     Commands::Build(options) => build::command(options, noise_level)?,
+    Commands::Clean(options) => clean::command(options)?,
     Commands::XcodeScript(options) => xcode_script::command(options)?,
   }
 
@@ -205,6 +205,14 @@ impl Default for CliOptions {
   }
 }
 
+/// Whether the address can be reached by other devices on the network, such as a physical phone.
+fn is_network_address(ipaddr: &IpAddr) -> bool {
+  match ipaddr {
+    IpAddr::V4(i) => !(i.is_loopback() || i.is_link_local() || i.is_unspecified()),
+    IpAddr::V6(i) => i.to_string().ends_with("::2"),
+  }
+}
+
 fn local_ip_address(force: bool) -> &'static IpAddr {
   static LOCAL_IP: OnceLock<IpAddr> = OnceLock::new();
   LOCAL_IP.get_or_init(|| {
@@ -213,14 +221,16 @@ fn local_ip_address(force: bool) -> &'static IpAddr {
         .expect("failed to list networks")
         .into_iter()
         .map(|(_, ipaddr)| ipaddr)
-        .filter(|ipaddr| match ipaddr {
-          IpAddr::V4(i) => i != &Ipv4Addr::LOCALHOST,
-          IpAddr::V6(i) => i.to_string().ends_with("::2"),
-
-        })
+        .filter(is_network_address)
         .collect();
       match addresses.len() {
-        0 => panic!("No external IP detected."),
+        0 => {
+          log::warn!(
+            "No network address detected, falling back to localhost which is only reachable from emulators and simulators. \
+            To run on a physical device, connect this machine and the device to the same network or set the address with `--host <address>`."
+          );
+          IpAddr::V4(Ipv4Addr::LOCALHOST)
+        }
         1 => {
           let ipaddr = addresses.first().unwrap();
           *ipaddr
@@ -242,7 +252,10 @@ fn local_ip_address(force: bool) -> &'static IpAddr {
     let ip = if force {
       prompt_for_ip()
     } else {
-      local_ip_address::local_ip().unwrap_or_else(|_| prompt_for_ip())
+      local_ip_address::local_ip()
+        .ok()
+        .filter(is_network_address)
+        .unwrap_or_else(prompt_for_ip)
     };
     log::info!("Using {ip} to access the development server.");
     ip
@@ -568,3 +581,21 @@ fn log_finished(outputs: Vec<PathBuf>, kind: &str) {
     log::info!(action = "Finished"; "{} {}{} at:\n{}", outputs.len(), kind, if outputs.len() == 1 { "" } else { "s" }, printable_paths);
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::net::{IpAddr, Ipv4Addr};
+
+  use super::is_network_address;
+
+  #[test]
+  fn filters_unreachable_addresses() {
+    let lan = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10));
+    let link_local = IpAddr::V4(Ipv4Addr::new(169, 254, 3, 7));
+
+    assert!(is_network_address(&lan));
+    assert!(!is_network_address(&link_local));
+    assert!(!is_network_address(&IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    assert!(!is_network_address(&IpAddr::V4(Ipv4Addr::UNSPECIFIED)));
+  }
+}
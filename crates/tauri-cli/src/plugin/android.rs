@@ -131,6 +131,7 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {{
       println!("You must add the following to the Cargo.toml file:\n{cargo_toml_addition}",);
       println!("You must add the following code to the build.rs file:\n\n{build_file}",);
       println!("Your plugin's init function under src/lib.rs must initialize the Android plugin:\n{init_fn}");
+      println!("Declare the Android permissions your plugin needs in android/src/main/AndroidManifest.xml, they are merged into the manifest of the apps using it.");
     }
   }
 
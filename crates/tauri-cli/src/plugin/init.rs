@@ -43,6 +43,15 @@ pub struct Options {
   /// Author name
   #[clap(short, long)]
   pub(crate) author: Option<String>,
+  /// Description of the plugin, used in the crate and package metadata
+  #[clap(long)]
+  pub(crate) description: Option<String>,
+  /// URL of the plugin homepage
+  #[clap(long, value_parser = super::parse_http_url)]
+  pub(crate) homepage: Option<String>,
+  /// URL of the plugin source repository
+  #[clap(long, value_parser = super::parse_http_url)]
+  pub(crate) repository: Option<String>,
   /// Whether to initialize an Android project for the plugin.
   #[clap(long)]
   pub(crate) android: bool,
@@ -52,6 +61,12 @@ pub struct Options {
   /// Whether to initialize Android and iOS projects for the plugin.
   #[clap(long)]
   pub(crate) mobile: bool,
+  /// Only scaffold the plugin registration and permissions on the Rust side,
+  /// leaving the commands to the native Android and iOS projects.
+  ///
+  /// Requires `--android`, `--ios` or `--mobile` and the TypeScript API.
+  #[clap(long)]
+  pub(crate) native_only: bool,
   /// Type of framework to use for the iOS project.
   #[clap(long)]
   #[clap(default_value_t = PluginIosFramework::default())]
@@ -78,10 +93,56 @@ impl Options {
       });
     }
   }
+
+  pub(crate) fn validate(&self) -> Result<()> {
+    if self.native_only {
+      if !(self.android || self.ios || self.mobile) {
+        anyhow::bail!(
+          "`--native-only` requires a native project, use it with `--android`, `--ios` or `--mobile`"
+        );
+      }
+      if self.no_api {
+        anyhow::bail!(
+          "`--native-only` cannot be used with `--no-api` since the native commands are only reachable from the TypeScript API"
+        );
+      }
+    }
+    if let Some(tauri_path) = &self.tauri_path {
+      validate_tauri_path(tauri_path)?;
+    }
+    Ok(())
+  }
+}
+
+/// Checks that `--tauri-path` points at a Tauri repository,
+/// since the plugin references the crates of the repository with path dependencies.
+fn validate_tauri_path(tauri_path: &Path) -> Result<()> {
+  let missing = ["tauri", "tauri-build", "tauri-plugin"]
+    .into_iter()
+    .filter(|name| {
+      !tauri_path
+        .join("crates")
+        .join(name)
+        .join("Cargo.toml")
+        .is_file()
+    })
+    .collect::<Vec<_>>();
+
+  if !missing.is_empty() {
+    anyhow::bail!(
+      "`--tauri-path` {} does not point at a Tauri repository: could not find the following crates in its `crates` directory: {}. \
+      The path must be relative to the current directory and point at a clone of https://github.com/tauri-apps/tauri",
+      tauri_path.display(),
+      missing.join(", ")
+    );
+  }
+
+  Ok(())
 }
 
 pub fn command(mut options: Options) -> Result<()> {
   options.load();
+  options.validate()?;
 
   let plugin_name = match options.plugin_name {
     None => super::infer_plugin_name(&options.directory)?,
@@ -139,6 +200,23 @@ pub fn command(mut options: Options) -> Result<()> {
     data.insert("tauri_build_dep", to_json(tauri_build_dep));
     data.insert("tauri_plugin_dep", to_json(tauri_plugin_dep));
     data.insert("author", to_json(options.author));
+    // quoted so the values can be used as both TOML and JSON strings
+    data.insert(
+      "description",
+      to_json(serde_json::to_string(
+        options.description.as_deref().unwrap_or_default(),
+      )?),
+    );
+    if let Some(homepage) = &options.homepage {
+      data.insert("homepage", to_json(serde_json::to_string(homepage)?));
+    }
+    if let Some(repository) = &options.repository {
+      data.insert("repository", to_json(serde_json::to_string(repository)?));
+    }
+
+    if options.native_only {
+      data.insert("native_only", to_json(true));
+    }
 
     if options.tauri {
       data.insert(
@@ -230,6 +308,9 @@ pub fn command(mut options: Options) -> Result<()> {
             {
               return Ok(None);
             }
+            "src" if options.native_only && path.file_name() != Some(OsStr::new("lib.rs")) => {
+              return Ok(None);
+            }
             _ => (),
           }
         }
@@ -250,10 +331,17 @@ pub fn command(mut options: Options) -> Result<()> {
   std::fs::create_dir(&permissions_dir)
     .with_context(|| "failed to create `permissions` directory")?;
 
-  let default_permissions = r#"[default]
-description = "Default permissions for the plugin"
+  let default_permissions_description = match &options.description {
+    Some(description) => format!("Default permissions for the plugin: {description}"),
+    None => "Default permissions for the plugin".into(),
+  };
+  let default_permissions = format!(
+    r#"[default]
+description = {}
 permissions = ["allow-ping"]
-"#;
+"#,
+    toml::Value::String(default_permissions_description)
+  );
   std::fs::write(permissions_dir.join("default.toml"), default_permissions)
     .with_context(|| "failed to write `permissions/default.toml`")?;
 
@@ -317,3 +405,62 @@ pub fn generate_android_out_file(
     Ok(None)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{generate_android_out_file, plugin_name_data, validate_tauri_path, TEMPLATE_DIR};
+  use crate::helpers::template;
+  use handlebars::{to_json, Handlebars};
+  use std::{collections::BTreeMap, ffi::OsStr, path::Component};
+
+  #[test]
+  fn validates_tauri_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = validate_tauri_path(dir.path()).unwrap_err();
+    assert!(err.to_string().contains("tauri, tauri-build, tauri-plugin"));
+
+    for name in ["tauri", "tauri-build", "tauri-plugin"] {
+      let crate_dir = dir.path().join("crates").join(name);
+      std::fs::create_dir_all(&crate_dir).unwrap();
+      std::fs::write(crate_dir.join("Cargo.toml"), "").unwrap();
+    }
+    validate_tauri_path(dir.path()).unwrap();
+
+    std::fs::remove_file(dir.path().join("crates/tauri-plugin/Cargo.toml")).unwrap();
+    let err = validate_tauri_path(dir.path()).unwrap_err();
+    assert!(err.to_string().contains("directory: tauri-plugin."));
+  }
+
+  #[test]
+  fn scaffolds_android_manifest_permissions() {
+    let dir = tempfile::tempdir().unwrap();
+    let handlebars = Handlebars::new();
+    let mut data = BTreeMap::new();
+    plugin_name_data(&mut data, "camera");
+    data.insert("android_package_id", to_json("com.plugin.camera"));
+
+    let mut created_dirs = Vec::new();
+    template::render_with_generator(&handlebars, &data, &TEMPLATE_DIR, dir.path(), &mut |path| {
+      match path.components().next() {
+        Some(Component::Normal(root)) if root == OsStr::new("android") => {
+          generate_android_out_file(&path, dir.path(), "com/plugin/camera", &mut created_dirs)
+        }
+        _ => Ok(None),
+      }
+    })
+    .unwrap();
+
+    let manifest =
+      std::fs::read_to_string(dir.path().join("android/src/main/AndroidManifest.xml")).unwrap();
+    assert!(
+      manifest.contains(r#"<!-- <uses-permission android:name="android.permission.CAMERA" /> -->"#)
+    );
+
+    let plugin =
+      std::fs::read_to_string(dir.path().join("android/src/main/java/ExamplePlugin.kt")).unwrap();
+    assert!(plugin.starts_with("package com.plugin.camera"));
+    assert!(
+      plugin.contains("Permission(strings = [Manifest.permission.CAMERA], alias = \"camera\")")
+    );
+  }
+}
@@ -63,6 +63,18 @@ pub fn command(cli: Cli) -> Result<()> {
   Ok(())
 }
 
+/// Validates a homepage or repository URL given on the command line.
+fn parse_http_url(url: &str) -> std::result::Result<String, String> {
+  match url::Url::parse(url) {
+    Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(url.to_string()),
+    Ok(parsed) => Err(format!(
+      "unsupported URL scheme `{}`, expected `http` or `https`",
+      parsed.scheme()
+    )),
+    Err(e) => Err(e.to_string()),
+  }
+}
+
 fn infer_plugin_name<P: AsRef<Path>>(directory: P) -> Result<String> {
   let dir = directory.as_ref();
   let cargo_toml_path = dir.join("Cargo.toml");
@@ -4,8 +4,13 @@
 
 use super::PluginIosFramework;
 use crate::Result;
+use anyhow::Context;
 use clap::Parser;
-use std::path::PathBuf;
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+use toml_edit::{Array, DocumentMut, Item, Value};
 
 #[derive(Debug, Parser)]
 #[clap(about = "Initializes a new Tauri plugin project")]
@@ -24,6 +29,15 @@ pub struct Options {
   /// Author name
   #[clap(short, long)]
   author: Option<String>,
+  /// Description of the plugin, used in the crate and package metadata
+  #[clap(long)]
+  description: Option<String>,
+  /// URL of the plugin homepage
+  #[clap(long, value_parser = super::parse_http_url)]
+  homepage: Option<String>,
+  /// URL of the plugin source repository
+  #[clap(long, value_parser = super::parse_http_url)]
+  repository: Option<String>,
   /// Whether to initialize an Android project for the plugin.
   #[clap(long)]
   android: bool,
@@ -33,6 +47,12 @@ pub struct Options {
   /// Whether to initialize Android and iOS projects for the plugin.
   #[clap(long)]
   mobile: bool,
+  /// Only scaffold the plugin registration and permissions on the Rust side,
+  /// leaving the commands to the native Android and iOS projects.
+  ///
+  /// Requires `--android`, `--ios` or `--mobile` and the TypeScript API.
+  #[clap(long)]
+  native_only: bool,
   /// Type of framework to use for the iOS project.
   #[clap(long)]
   #[clap(default_value_t = PluginIosFramework::default())]
@@ -40,6 +60,12 @@ pub struct Options {
   /// Generate github workflows
   #[clap(long)]
   github_workflows: bool,
+  /// Add the plugin to the Cargo workspace of a parent directory.
+  ///
+  /// Fails if no workspace is found. Without this flag the plugin is still added
+  /// to a workspace detected in a parent directory.
+  #[clap(long)]
+  workspace: bool,
 
   /// Initializes a Tauri core plugin (internal usage)
   #[clap(long, hide(true))]
@@ -57,9 +83,13 @@ impl From<Options> for super::init::Options {
       no_example: o.no_example,
       directory: o.directory.unwrap(),
       author: o.author,
+      description: o.description,
+      homepage: o.homepage,
+      repository: o.repository,
       android: o.android,
       ios: o.ios,
       mobile: o.mobile,
+      native_only: o.native_only,
       ios_framework: o.ios_framework,
       github_workflows: o.github_workflows,
 
@@ -71,13 +101,225 @@ impl From<Options> for super::init::Options {
 
 pub fn command(mut options: Options) -> Result<()> {
   let cwd = std::env::current_dir()?;
-  if let Some(dir) = &options.directory {
-    std::fs::create_dir_all(cwd.join(dir))?;
-  } else {
-    let target = cwd.join(format!("tauri-plugin-{}", options.plugin_name));
-    std::fs::create_dir_all(&target)?;
-    options.directory.replace(target.display().to_string());
+  let target = match &options.directory {
+    Some(dir) => cwd.join(dir),
+    None => {
+      let target = cwd.join(format!("tauri-plugin-{}", options.plugin_name));
+      options.directory.replace(target.display().to_string());
+      target
+    }
+  };
+
+  let require_workspace = options.workspace;
+  let options: super::init::Options = options.into();
+  // validate before creating the plugin directory
+  options.validate()?;
+  let workspace_manifest = find_workspace_manifest(target.parent().unwrap_or(&cwd));
+  if require_workspace && workspace_manifest.is_none() {
+    anyhow::bail!(
+      "no Cargo workspace found in the parent directories of {}",
+      target.display()
+    );
+  }
+  fs::create_dir_all(&target)?;
+
+  super::init::command(options)?;
+
+  if let Some(manifest_path) = workspace_manifest {
+    add_to_workspace(&manifest_path, &target)?;
   }
 
-  super::init::command(options.into())
+  Ok(())
+}
+
+/// Finds the closest `Cargo.toml` defining a `[workspace]`, starting at `dir`.
+fn find_workspace_manifest(dir: &Path) -> Option<PathBuf> {
+  dir
+    .ancestors()
+    .map(|dir| dir.join("Cargo.toml"))
+    .find(|path| {
+      fs::read_to_string(path)
+        .ok()
+        .and_then(|manifest| manifest.parse::<DocumentMut>().ok())
+        .is_some_and(|manifest| manifest.contains_key("workspace"))
+    })
+}
+
+/// Adds the plugin and its example app to the members of the workspace at `manifest_path`.
+fn add_to_workspace(manifest_path: &Path, plugin_dir: &Path) -> Result<()> {
+  let workspace_dir = manifest_path.parent().unwrap();
+  let mut members = vec![plugin_dir.to_path_buf()];
+  // the example app is nested in the workspace too, so cargo requires it to be a member
+  if let Ok(examples) = fs::read_dir(plugin_dir.join("examples")) {
+    members.extend(
+      examples
+        .flatten()
+        .map(|example| example.path().join("src-tauri"))
+        .filter(|app_dir| app_dir.join("Cargo.toml").exists()),
+    );
+  }
+
+  let contents = fs::read_to_string(manifest_path)
+    .with_context(|| format!("failed to read {}", manifest_path.display()))?;
+  let mut manifest = contents
+    .parse::<DocumentMut>()
+    .with_context(|| format!("failed to parse {}", manifest_path.display()))?;
+
+  let mut changed = false;
+  for member in &members {
+    let Ok(relative) = member.strip_prefix(workspace_dir) else {
+      continue;
+    };
+    let member = relative
+      .components()
+      .map(|c| c.as_os_str().to_string_lossy())
+      .collect::<Vec<_>>()
+      .join("/");
+    if add_workspace_member(&mut manifest, &member) {
+      log::info!(
+        "Added {member} to the workspace members of {}",
+        manifest_path.display()
+      );
+      changed = true;
+    }
+  }
+
+  if changed {
+    fs::write(manifest_path, manifest.to_string())
+      .with_context(|| format!("failed to write {}", manifest_path.display()))?;
+  }
+
+  Ok(())
+}
+
+/// Adds `member` to the `workspace.members` array, creating it if needed.
+///
+/// Returns `false` if the workspace already lists the member.
+fn add_workspace_member(manifest: &mut DocumentMut, member: &str) -> bool {
+  let Some(workspace) = manifest
+    .get_mut("workspace")
+    .and_then(Item::as_table_like_mut)
+  else {
+    return false;
+  };
+
+  let members = workspace
+    .entry("members")
+    .or_insert_with(|| Item::Value(Value::Array(Array::new())));
+  let Some(members) = members.as_array_mut() else {
+    return false;
+  };
+
+  let normalize = |m: &str| m.trim_start_matches("./").trim_end_matches('/').to_string();
+  if members
+    .iter()
+    .filter_map(Value::as_str)
+    .any(|m| normalize(m) == member)
+  {
+    return false;
+  }
+
+  // keep multiline arrays multiline
+  let multiline = members.iter().next().is_some_and(|m| {
+    m.decor()
+      .prefix()
+      .and_then(|p| p.as_str())
+      .is_some_and(|p| p.contains('\n'))
+  });
+  members.push(member);
+  if multiline {
+    if let Some(last) = members.iter_mut().last() {
+      last.decor_mut().set_prefix("\n  ");
+    }
+    members.set_trailing("\n");
+    members.set_trailing_comma(true);
+  }
+
+  true
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{add_to_workspace, add_workspace_member, find_workspace_manifest};
+  use std::fs;
+  use toml_edit::DocumentMut;
+
+  const WORKSPACE: &str = r#"# the workspace
+[workspace]
+resolver = "2"
+members = [
+  # core crates
+  "crates/core",
+  "./plugins/existing",
+]
+
+[workspace.dependencies]
+serde = "1"
+"#;
+
+  #[test]
+  fn adds_workspace_member() {
+    let mut manifest = WORKSPACE.parse::<DocumentMut>().unwrap();
+
+    assert!(!add_workspace_member(&mut manifest, "plugins/existing"));
+    assert_eq!(manifest.to_string(), WORKSPACE);
+
+    assert!(add_workspace_member(
+      &mut manifest,
+      "plugins/tauri-plugin-test"
+    ));
+    assert_eq!(
+      manifest.to_string(),
+      WORKSPACE.replace(
+        "  \"./plugins/existing\",\n",
+        "  \"./plugins/existing\",\n  \"plugins/tauri-plugin-test\",\n"
+      )
+    );
+
+    let mut manifest = "[workspace]\n".parse::<DocumentMut>().unwrap();
+    assert!(add_workspace_member(&mut manifest, "tauri-plugin-test"));
+    assert_eq!(
+      manifest.to_string(),
+      "[workspace]\nmembers = [\"tauri-plugin-test\"]\n"
+    );
+
+    let mut manifest = "[package]\nname = \"app\"\n"
+      .parse::<DocumentMut>()
+      .unwrap();
+    assert!(!add_workspace_member(&mut manifest, "tauri-plugin-test"));
+  }
+
+  #[test]
+  fn adds_plugin_to_workspace_fixture() {
+    let workspace_dir = tempfile::tempdir().unwrap();
+    let manifest_path = workspace_dir.path().join("Cargo.toml");
+    fs::write(&manifest_path, WORKSPACE).unwrap();
+
+    let plugin_dir = workspace_dir.path().join("plugins/tauri-plugin-test");
+    let app_dir = plugin_dir.join("examples/tauri-app/src-tauri");
+    fs::create_dir_all(&app_dir).unwrap();
+    fs::write(plugin_dir.join("Cargo.toml"), "[package]\n").unwrap();
+    fs::write(app_dir.join("Cargo.toml"), "[package]\n").unwrap();
+
+    assert_eq!(
+      find_workspace_manifest(plugin_dir.parent().unwrap()),
+      Some(manifest_path.clone())
+    );
+    // the plugin manifest has no workspace section
+    assert_eq!(
+      find_workspace_manifest(&plugin_dir),
+      Some(manifest_path.clone())
+    );
+
+    add_to_workspace(&manifest_path, &plugin_dir).unwrap();
+    let expected = WORKSPACE.replace(
+      "  \"./plugins/existing\",\n",
+      "  \"./plugins/existing\",\n  \"plugins/tauri-plugin-test\",\n  \"plugins/tauri-plugin-test/examples/tauri-app/src-tauri\",\n",
+    );
+    assert_eq!(fs::read_to_string(&manifest_path).unwrap(), expected);
+
+    // running it again is a no-op
+    add_to_workspace(&manifest_path, &plugin_dir).unwrap();
+    assert_eq!(fs::read_to_string(&manifest_path).unwrap(), expected);
+  }
 }
@@ -13,6 +13,15 @@ class PingArgs {
   var value: String? = null
 }
 
+// Runtime permissions declared here can be checked and requested from the frontend
+// with `checkPermissions` and `requestPermissions` using their alias, for instance
+// (importing `android.Manifest` and `app.tauri.annotation.Permission`):
+// @TauriPlugin(
+//     permissions = [
+//         Permission(strings = [Manifest.permission.CAMERA], alias = "camera")
+//     ]
+// )
+// The permissions must also be declared in the plugin's AndroidManifest.xml.
 @TauriPlugin
 class ExamplePlugin(private val activity: Activity): Plugin(activity) {
     private val implementation = Example()
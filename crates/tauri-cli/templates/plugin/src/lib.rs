@@ -1,6 +1,32 @@
 {{#if license_header}}
 {{ license_header }}
 {{/if}}
+{{#if native_only}}
+use tauri::{
+  plugin::{Builder, TauriPlugin},
+  Runtime,
+};
+
+#[cfg(target_os = "ios")]
+tauri::ios_plugin_binding!(init_plugin_{{ plugin_name_snake_case }});
+
+/// Initializes the plugin.
+///
+/// The commands are implemented by the Kotlin and Swift plugin classes and called directly from the TypeScript API.
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+  Builder::new("{{ plugin_name }}")
+    .setup(|_app, _api| {
+{{#if android_package_id}}
+      #[cfg(target_os = "android")]
+      _api.register_android_plugin("{{ android_package_id }}", "ExamplePlugin")?;
+{{/if}}
+      #[cfg(target_os = "ios")]
+      _api.register_ios_plugin(init_plugin_{{ plugin_name_snake_case }})?;
+      Ok(())
+    })
+    .build()
+}
+{{else}}
 use tauri::{
   plugin::{Builder, TauriPlugin},
   Manager, Runtime,
@@ -49,3 +75,4 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
     })
     .build()
 }
+{{/if}}
@@ -402,12 +402,14 @@ pub fn context_codegen(data: ContextData) -> EmbeddedAssetsResult<TokenStream> {
     for capability_entry in &config.app.security.capabilities {
       match capability_entry {
         CapabilityEntry::Inlined(capability) => {
-          capabilities.insert(capability.identifier.clone(), capability.clone());
+          capabilities.insert(capability.identifier.clone(), *capability.clone());
         }
         CapabilityEntry::Reference(id) => {
-          let capability = capabilities_from_files
-            .remove(id)
-            .unwrap_or_else(|| panic!("capability with identifier {id} not found"));
+          let capability = capabilities_from_files.remove(id).ok_or_else(|| {
+            tauri_utils::acl::Error::UnknownCapability {
+              identifier: id.clone(),
+            }
+          })?;
           capabilities.insert(id.clone(), capability);
         }
       }
@@ -428,7 +430,7 @@ pub fn context_codegen(data: ContextData) -> EmbeddedAssetsResult<TokenStream> {
         .unwrap_or_else(|e| panic!("failed to read capability {}: {e}", path.display()));
       match capability {
         CapabilityFile::Capability(c) => {
-          capabilities.insert(c.identifier.clone(), c);
+          capabilities.insert(c.identifier.clone(), *c);
         }
         CapabilityFile::List(capabilities_list)
         | CapabilityFile::NamedList {
@@ -444,6 +446,10 @@ pub fn context_codegen(data: ContextData) -> EmbeddedAssetsResult<TokenStream> {
     }
   }
 
+  if let Some(enabled_features) = tauri_utils::acl::build::read_enabled_features(&out_dir)? {
+    tauri_utils::acl::build::filter_capabilities_by_features(&mut capabilities, &enabled_features);
+  }
+
   let resolved = Resolved::resolve(&acl, capabilities, target).expect("failed to resolve ACL");
   let runtime_authority = quote!(#root::ipc::RuntimeAuthority::new(#acl_tokens, #resolved));
 
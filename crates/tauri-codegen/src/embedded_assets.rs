@@ -61,6 +61,9 @@ pub enum EmbeddedAssetsError {
 
   #[error("version error: {0}")]
   Version(#[from] semver::Error),
+
+  #[error(transparent)]
+  Acl(#[from] tauri_utils::acl::Error),
 }
 
 pub type EmbeddedAssetsResult<T> = Result<T, EmbeddedAssetsError>;
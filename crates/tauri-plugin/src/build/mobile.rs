@@ -16,6 +16,10 @@ use super::{build_var, cfg_alias};
 
 #[cfg(target_os = "macos")]
 pub fn update_entitlements<F: FnOnce(&mut plist::Dictionary)>(f: F) -> Result<()> {
+  // the project path is set by the CLI, so incremental builds must rerun when it changes
+  println!("cargo:rerun-if-env-changed=TAURI_IOS_PROJECT_PATH");
+  println!("cargo:rerun-if-env-changed=TAURI_IOS_APP_NAME");
+
   if let (Some(project_path), Ok(app_name)) = (
     var_os("TAURI_IOS_PROJECT_PATH").map(PathBuf::from),
     std::env::var("TAURI_IOS_APP_NAME"),
@@ -18,10 +18,11 @@ use raw_window_handle::{DisplayHandle, HasDisplayHandle, HasWindowHandle};
 use tauri_runtime::{
   dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Position, Size},
   monitor::Monitor,
-  webview::{DetachedWebview, DownloadEvent, PendingWebview, WebviewIpcHandler},
+  webview::{DetachedWebview, DownloadEvent, FindOptions, PendingWebview, WebviewIpcHandler},
   window::{
-    CursorIcon, DetachedWindow, DetachedWindowWebview, DragDropEvent, PendingWindow, RawWindow,
-    WebviewEvent, WindowBuilder, WindowBuilderBase, WindowEvent, WindowId, WindowSizeConstraints,
+    CursorIcon, DetachedWindow, DetachedWindowWebview, DragDropEvent, FileDropEvent, PendingWindow,
+    RawWindow, WebviewEvent, WindowBuilder, WindowBuilderBase, WindowEvent, WindowId,
+    WindowSizeConstraints,
   },
   DeviceEventFilter, Error, EventLoopProxy, ExitRequestedEventAction, Icon, ProgressBarState,
   ProgressBarStatus, Result, RunEvent, Runtime, RuntimeHandle, RuntimeInitArgs, UserAttentionType,
@@ -37,9 +38,15 @@ use tao::platform::unix::{WindowBuilderExtUnix, WindowExtUnix};
 #[cfg(windows)]
 use tao::platform::windows::{WindowBuilderExtWindows, WindowExtWindows};
 #[cfg(windows)]
-use webview2_com::FocusChangedEventHandler;
+use webview2_com::{
+  FaviconChangedEventHandler, FocusChangedEventHandler,
+  Microsoft::Web::WebView2::Win32::ICoreWebView2_15,
+};
 #[cfg(windows)]
-use windows::Win32::{Foundation::HWND, System::WinRT::EventRegistrationToken};
+use windows::{
+  core::Interface,
+  Win32::{Foundation::HWND, System::WinRT::EventRegistrationToken},
+};
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 use wry::WebViewBuilderExtDarwin;
 #[cfg(windows)]
@@ -63,7 +70,6 @@ use tao::{
     UserAttentionType as TaoUserAttentionType,
   },
 };
-#[cfg(target_os = "macos")]
 use tauri_utils::TitleBarStyle;
 use tauri_utils::{
   config::{Color, WindowConfig},
@@ -732,6 +738,17 @@ pub struct WindowBuilderWrapper {
   center: bool,
   #[cfg(target_os = "macos")]
   tabbing_identifier: Option<String>,
+  #[cfg(any(
+    windows,
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  app_id: Option<String>,
+  #[cfg(all(desktop, not(target_os = "macos")))]
+  title_bar_hidden: bool,
 }
 
 impl std::fmt::Debug for WindowBuilderWrapper {
@@ -742,6 +759,17 @@ impl std::fmt::Debug for WindowBuilderWrapper {
     {
       s.field("tabbing_identifier", &self.tabbing_identifier);
     }
+    #[cfg(any(
+      windows,
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd"
+    ))]
+    {
+      s.field("app_id", &self.app_id);
+    }
     s.finish()
   }
 }
@@ -833,6 +861,11 @@ impl WindowBuilder for WindowBuilderWrapper {
         .minimizable(config.minimizable)
         .shadow(config.shadow);
 
+      #[cfg(not(target_os = "macos"))]
+      {
+        window = window.title_bar_style(config.title_bar_style);
+      }
+
       let mut constraints = WindowSizeConstraints::default();
 
       if let Some(min_width) = config.min_width {
@@ -1057,6 +1090,11 @@ impl WindowBuilder for WindowBuilderWrapper {
         self.inner = self.inner.with_titlebar_transparent(true);
         self.inner = self.inner.with_fullsize_content_view(true);
       }
+      TitleBarStyle::Hidden => {
+        self.inner = self.inner.with_titlebar_transparent(true);
+        self.inner = self.inner.with_fullsize_content_view(true);
+        self.inner = self.inner.with_title_hidden(true);
+      }
       unknown => {
         #[cfg(feature = "tracing")]
         tracing::warn!("unknown title bar style applied: {unknown}");
@@ -1068,6 +1106,27 @@ impl WindowBuilder for WindowBuilderWrapper {
     self
   }
 
+  #[cfg(not(target_os = "macos"))]
+  fn title_bar_style(mut self, style: TitleBarStyle) -> Self {
+    // the system title bar can only be removed with the rest of the decorations
+    if style == TitleBarStyle::Hidden {
+      self.inner = self.inner.with_decorations(false);
+    }
+    #[cfg(desktop)]
+    {
+      self.title_bar_hidden = style == TitleBarStyle::Hidden;
+    }
+    self
+  }
+
+  #[cfg(target_os = "macos")]
+  fn traffic_light_position(mut self, x: f64, y: f64) -> Self {
+    self.inner = self
+      .inner
+      .with_traffic_light_inset(TaoLogicalPosition::new(x, y));
+    self
+  }
+
   #[cfg(target_os = "macos")]
   fn hidden_title(mut self, hidden: bool) -> Self {
     self.inner = self.inner.with_title_hidden(hidden);
@@ -1138,6 +1197,31 @@ impl WindowBuilder for WindowBuilderWrapper {
   fn window_classname<S: Into<String>>(self, _window_classname: S) -> Self {
     self
   }
+
+  #[cfg(any(
+    windows,
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  fn app_id<S: Into<String>>(mut self, app_id: S) -> Self {
+    self.app_id.replace(app_id.into());
+    self
+  }
+
+  #[cfg(not(any(
+    windows,
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  )))]
+  fn app_id<S: Into<String>>(self, _app_id: S) -> Self {
+    self
+  }
 }
 
 #[cfg(any(
@@ -1302,6 +1386,7 @@ pub enum WebviewMessage {
   WebviewEvent(WebviewEvent),
   SynthesizedWindowEvent(SynthesizedWindowEvent),
   Navigate(Url),
+  LoadHtml(String),
   Print,
   Close,
   Show,
@@ -1309,17 +1394,54 @@ pub enum WebviewMessage {
   SetPosition(Position),
   SetSize(Size),
   SetBounds(tauri_runtime::Rect),
+  SetSizeConstraints(Option<LogicalSize<f64>>, Option<LogicalSize<f64>>),
   SetFocus,
   Reparent(WindowId, Sender<Result<()>>),
   SetAutoResize(bool),
   SetZoom(f64),
   SetBackgroundColor(Option<Color>),
   ClearAllBrowsingData,
+  #[cfg(not(target_os = "android"))]
+  SetUserAgent(String),
+  #[cfg(not(target_os = "android"))]
+  SetAcceptLanguage(String),
+  #[cfg(not(target_os = "android"))]
+  SetSpellcheck(bool),
+  #[cfg(windows)]
+  SetAutofill(bool),
+  #[cfg(windows)]
+  SetSuspended(bool),
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  Find(String, FindOptions),
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  FindNext,
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  StopFind,
   // Getters
   Url(Sender<Result<String>>),
   Bounds(Sender<Result<tauri_runtime::Rect>>),
   Position(Sender<Result<PhysicalPosition<i32>>>),
   Size(Sender<Result<PhysicalSize<u32>>>),
+  #[cfg(windows)]
+  Favicon(Sender<Result<Option<Url>>>),
   WithWebview(Box<dyn FnOnce(Webview) + Send>),
   // Devtools
   #[cfg(any(debug_assertions, feature = "devtools"))]
@@ -1452,6 +1574,16 @@ impl<T: UserEvent> WebviewDispatch<T> for WryWebviewDispatcher<T> {
     webview_getter!(self, WebviewMessage::Size)?
   }
 
+  #[cfg(windows)]
+  fn favicon(&self) -> Result<Option<Url>> {
+    webview_getter!(self, WebviewMessage::Favicon)?
+  }
+
+  #[cfg(not(windows))]
+  fn favicon(&self) -> Result<Option<Url>> {
+    Err(Error::Unsupported)
+  }
+
   // Setters
 
   fn navigate(&self, url: Url) -> Result<()> {
@@ -1465,6 +1597,17 @@ impl<T: UserEvent> WebviewDispatch<T> for WryWebviewDispatcher<T> {
     )
   }
 
+  fn load_html(&self, html: String) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::LoadHtml(html),
+      ),
+    )
+  }
+
   fn print(&self) -> Result<()> {
     send_user_message(
       &self.context,
@@ -1520,6 +1663,21 @@ impl<T: UserEvent> WebviewDispatch<T> for WryWebviewDispatcher<T> {
     )
   }
 
+  fn set_size_constraints(
+    &self,
+    min: Option<LogicalSize<f64>>,
+    max: Option<LogicalSize<f64>>,
+  ) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::SetSizeConstraints(min, max),
+      ),
+    )
+  }
+
   fn set_focus(&self) -> Result<()> {
     send_user_message(
       &self.context,
@@ -1641,6 +1799,181 @@ impl<T: UserEvent> WebviewDispatch<T> for WryWebviewDispatcher<T> {
       ),
     )
   }
+
+  #[cfg(not(target_os = "android"))]
+  fn set_user_agent(&self, user_agent: &str) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::SetUserAgent(user_agent.into()),
+      ),
+    )
+  }
+
+  #[cfg(target_os = "android")]
+  fn set_user_agent(&self, _user_agent: &str) -> Result<()> {
+    Err(Error::Unsupported)
+  }
+
+  #[cfg(not(target_os = "android"))]
+  fn set_accept_language(&self, language: &str) -> Result<()> {
+    tauri_runtime::webview::validate_language_tag(language)?;
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::SetAcceptLanguage(language.into()),
+      ),
+    )
+  }
+
+  // the Android webview language follows the app locale
+  #[cfg(target_os = "android")]
+  fn set_accept_language(&self, _language: &str) -> Result<()> {
+    Err(Error::Unsupported)
+  }
+
+  #[cfg(not(target_os = "android"))]
+  fn set_spellcheck(&self, enabled: bool) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::SetSpellcheck(enabled),
+      ),
+    )
+  }
+
+  // scripts cannot be added to the Android webview after it has been created
+  #[cfg(target_os = "android")]
+  fn set_spellcheck(&self, _enabled: bool) -> Result<()> {
+    Err(Error::Unsupported)
+  }
+
+  #[cfg(windows)]
+  fn set_autofill(&self, enabled: bool) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::SetAutofill(enabled),
+      ),
+    )
+  }
+
+  #[cfg(not(windows))]
+  fn set_autofill(&self, _enabled: bool) -> Result<()> {
+    Err(Error::Unsupported)
+  }
+
+  #[cfg(windows)]
+  fn set_suspended(&self, suspended: bool) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::SetSuspended(suspended),
+      ),
+    )
+  }
+
+  #[cfg(not(windows))]
+  fn set_suspended(&self, _suspended: bool) -> Result<()> {
+    Err(Error::Unsupported)
+  }
+
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  fn find(&self, query: &str, options: FindOptions) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::Find(query.into(), options),
+      ),
+    )
+  }
+
+  #[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  )))]
+  fn find(&self, _query: &str, _options: FindOptions) -> Result<()> {
+    Err(Error::Unsupported)
+  }
+
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  fn find_next(&self) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::FindNext,
+      ),
+    )
+  }
+
+  #[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  )))]
+  fn find_next(&self) -> Result<()> {
+    Err(Error::Unsupported)
+  }
+
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  fn stop_find(&self) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        *self.window_id.lock().unwrap(),
+        self.webview_id,
+        WebviewMessage::StopFind,
+      ),
+    )
+  }
+
+  #[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  )))]
+  fn stop_find(&self) -> Result<()> {
+    Err(Error::Unsupported)
+  }
 }
 
 /// The Tauri [`WindowDispatch`] for [`Wry`].
@@ -1999,6 +2332,7 @@ impl<T: UserEvent> WindowDispatch<T> for WryWindowDispatcher<T> {
     )
   }
 
+  #[cfg(any(windows, target_os = "macos"))]
   fn set_content_protected(&self, protected: bool) -> Result<()> {
     send_user_message(
       &self.context,
@@ -2009,6 +2343,11 @@ impl<T: UserEvent> WindowDispatch<T> for WryWindowDispatcher<T> {
     )
   }
 
+  #[cfg(not(any(windows, target_os = "macos")))]
+  fn set_content_protected(&self, _protected: bool) -> Result<()> {
+    Err(Error::Unsupported)
+  }
+
   fn set_size(&self, size: Size) -> Result<()> {
     send_user_message(
       &self.context,
@@ -2198,6 +2537,58 @@ pub struct WebviewWrapper {
   // the key of the WebContext if it's not shared
   context_key: Option<PathBuf>,
   bounds: Arc<Mutex<Option<WebviewBounds>>>,
+  size_constraints: Arc<Mutex<WebviewSizeConstraints>>,
+  // the language sent in the Accept-Language header on navigation
+  accept_language: Arc<Mutex<Option<String>>>,
+  // the `navigator.language` script added by the last `set_accept_language` call
+  #[cfg(not(target_os = "android"))]
+  navigator_language_script: Rc<RefCell<Option<InitializationScript>>>,
+  // the paths of the current drag operation, `Over` events do not include them
+  dragged_paths: Arc<Mutex<Vec<PathBuf>>>,
+}
+
+impl WebviewWrapper {
+  /// Derives the window [`FileDropEvent`] targeting this webview from one of its drag and drop events.
+  fn file_drop_event(&self, event: &DragDropEvent) -> Option<FileDropEvent> {
+    let mut dragged_paths = self.dragged_paths.lock().unwrap();
+    match event {
+      DragDropEvent::Enter { paths, position } => {
+        *dragged_paths = paths.clone();
+        Some(FileDropEvent::Hovered {
+          webview: self.label.clone(),
+          paths: paths.clone(),
+          position: *position,
+        })
+      }
+      DragDropEvent::Over { position } => Some(FileDropEvent::Hovered {
+        webview: self.label.clone(),
+        paths: dragged_paths.clone(),
+        position: *position,
+      }),
+      DragDropEvent::Drop { paths, position } => {
+        dragged_paths.clear();
+        Some(FileDropEvent::Dropped {
+          webview: self.label.clone(),
+          paths: paths.clone(),
+          position: *position,
+        })
+      }
+      DragDropEvent::Leave => {
+        dragged_paths.clear();
+        Some(FileDropEvent::Cancelled {
+          webview: self.label.clone(),
+        })
+      }
+      _ => None,
+    }
+  }
+
+  /// Clamps the size of the given bounds to the webview size constraints.
+  fn constrain_bounds(&self, mut bounds: wry::Rect, scale_factor: f64) -> wry::Rect {
+    let size = bounds.size.to_logical::<f64>(scale_factor);
+    bounds.size = self.size_constraints.lock().unwrap().clamp(size).into();
+    bounds
+  }
 }
 
 impl Deref for WebviewWrapper {
@@ -2231,6 +2622,11 @@ pub struct WindowWrapper {
   // whether this window has child webviews
   // or it's just a container for a single webview
   has_children: AtomicBool,
+  // whether a user attention request is pending, cleared when the window gets focused
+  user_attention_requested: AtomicBool,
+  // whether the title bar style is `TitleBarStyle::Hidden`, which removes the decorations
+  #[cfg(all(desktop, not(target_os = "macos")))]
+  title_bar_hidden: AtomicBool,
   webviews: Vec<WebviewWrapper>,
   window_event_listeners: WindowEventListeners,
   #[cfg(windows)]
@@ -3005,6 +3401,10 @@ fn handle_user_message<T: UserEvent>(
           // Setters
           WindowMessage::Center => window.center(),
           WindowMessage::RequestUserAttention(request_type) => {
+            if let Some(w) = windows.0.borrow().get(&id) {
+              w.user_attention_requested
+                .store(request_type.is_some(), Ordering::Relaxed);
+            }
             window.request_user_attention(request_type.map(|r| r.0));
           }
           WindowMessage::SetResizable(resizable) => {
@@ -3051,7 +3451,19 @@ fn handle_user_message<T: UserEvent>(
           WindowMessage::SetAlwaysOnBottom(always_on_bottom) => {
             window.set_always_on_bottom(always_on_bottom)
           }
-          WindowMessage::SetAlwaysOnTop(always_on_top) => window.set_always_on_top(always_on_top),
+          WindowMessage::SetAlwaysOnTop(always_on_top) => {
+            // changing the z-order activates the window on Windows,
+            // which moves the keyboard focus away from the focused child webview
+            #[cfg(windows)]
+            let focused_webview = webviews.iter().find(|w| is_webview_focused(w));
+            window.set_always_on_top(always_on_top);
+            #[cfg(windows)]
+            if let Some(webview) = focused_webview {
+              if let Err(e) = webview.focus() {
+                log::error!("failed to restore webview focus: {e}");
+              }
+            }
+          }
           WindowMessage::SetVisibleOnAllWorkspaces(visible_on_all_workspaces) => {
             window.set_visible_on_all_workspaces(visible_on_all_workspaces)
           }
@@ -3157,6 +3569,15 @@ fn handle_user_message<T: UserEvent>(
             window.set_progress_bar(ProgressBarStateWrapper::from(progress_state).0);
           }
           WindowMessage::SetTitleBarStyle(_style) => {
+            // only switching to or from the hidden style toggles the decorations,
+            // so the other styles leave undecorated windows as they are
+            #[cfg(all(desktop, not(target_os = "macos")))]
+            if let Some(w) = windows.0.borrow().get(&id) {
+              let hidden = _style == TitleBarStyle::Hidden;
+              if w.title_bar_hidden.swap(hidden, Ordering::Relaxed) != hidden {
+                window.set_decorations(!hidden);
+              }
+            }
             #[cfg(target_os = "macos")]
             match _style {
               TitleBarStyle::Visible => {
@@ -3167,7 +3588,7 @@ fn handle_user_message<T: UserEvent>(
                 window.set_titlebar_transparent(true);
                 window.set_fullsize_content_view(false);
               }
-              TitleBarStyle::Overlay => {
+              TitleBarStyle::Overlay | TitleBarStyle::Hidden => {
                 window.set_titlebar_transparent(true);
                 window.set_fullsize_content_view(true);
               }
@@ -3293,10 +3714,23 @@ fn handle_user_message<T: UserEvent>(
             }
           }
           WebviewMessage::Navigate(url) => {
-            if let Err(e) = webview.load_url(url.as_str()) {
+            let result = match accept_language_headers(&webview.accept_language.lock().unwrap()) {
+              Ok(Some(headers)) => webview.load_url_with_headers(url.as_str(), headers),
+              Ok(None) => webview.load_url(url.as_str()),
+              Err(e) => {
+                log::error!("invalid Accept-Language header: {e}");
+                webview.load_url(url.as_str())
+              }
+            };
+            if let Err(e) = result {
               log::error!("failed to navigate to url {}: {}", url, e);
             }
           }
+          WebviewMessage::LoadHtml(html) => {
+            if let Err(e) = webview.load_html(&html) {
+              log::error!("failed to load HTML content: {e}");
+            }
+          }
           WebviewMessage::Show => {
             if let Err(e) = webview.set_visible(true) {
               log::error!("failed to change webview visibility: {e}");
@@ -3334,10 +3768,25 @@ fn handle_user_message<T: UserEvent>(
               b.y_rate = position.y / window_size.height;
             }
 
+            let bounds = webview.constrain_bounds(bounds, window.scale_factor());
             if let Err(e) = webview.set_bounds(bounds) {
               log::error!("failed to set webview size: {e}");
             }
           }
+          WebviewMessage::SetSizeConstraints(min, max) => {
+            *webview.size_constraints.lock().unwrap() = WebviewSizeConstraints { min, max };
+            match webview.bounds() {
+              Ok(bounds) => {
+                let bounds = webview.constrain_bounds(bounds, window.scale_factor());
+                if let Err(e) = webview.set_bounds(bounds) {
+                  log::error!("failed to set webview size: {e}");
+                }
+              }
+              Err(e) => {
+                log::error!("failed to get webview bounds: {e}");
+              }
+            }
+          }
           WebviewMessage::SetSize(size) => match webview.bounds() {
             Ok(mut bounds) => {
               bounds.size = size;
@@ -3351,6 +3800,7 @@ fn handle_user_message<T: UserEvent>(
                 b.height_rate = size.height / window_size.height;
               }
 
+              let bounds = webview.constrain_bounds(bounds, scale_factor);
               if let Err(e) = webview.set_bounds(bounds) {
                 log::error!("failed to set webview size: {e}");
               }
@@ -3397,6 +3847,141 @@ fn handle_user_message<T: UserEvent>(
               log::error!("failed to clear webview browsing data: {e}");
             }
           }
+          #[cfg(not(target_os = "android"))]
+          WebviewMessage::SetUserAgent(user_agent) => {
+            #[cfg(any(
+              target_os = "linux",
+              target_os = "dragonfly",
+              target_os = "freebsd",
+              target_os = "netbsd",
+              target_os = "openbsd"
+            ))]
+            {
+              use webkit2gtk::{SettingsExt, WebViewExt};
+              if let Some(settings) = WebViewExt::settings(&webview.webview()) {
+                settings.set_user_agent(Some(&user_agent));
+              }
+            }
+            #[cfg(target_os = "macos")]
+            {
+              use wry::WebViewExtMacOS;
+              unsafe {
+                webview
+                  .webview()
+                  .setCustomUserAgent(Some(&objc2_foundation::NSString::from_str(&user_agent)));
+              }
+            }
+            #[cfg(target_os = "ios")]
+            {
+              use wry::WebViewExtIOS;
+              unsafe {
+                webview
+                  .inner
+                  .webview()
+                  .setCustomUserAgent(Some(&objc2_foundation::NSString::from_str(&user_agent)));
+              }
+            }
+            #[cfg(windows)]
+            {
+              use webview2_com::Microsoft::Web::WebView2::Win32::ICoreWebView2Settings2;
+              use windows::core::HSTRING;
+              let result = unsafe {
+                webview
+                  .controller()
+                  .CoreWebView2()
+                  .and_then(|webview| webview.Settings())
+                  .and_then(|settings| settings.cast::<ICoreWebView2Settings2>())
+                  .and_then(|settings| settings.SetUserAgent(&HSTRING::from(user_agent)))
+              };
+              if let Err(e) = result {
+                log::error!("failed to set webview user agent: {e}");
+              }
+            }
+          }
+          #[cfg(not(target_os = "android"))]
+          WebviewMessage::SetAcceptLanguage(language) => {
+            let script = navigator_language_script(&language);
+            // replace the script of the previous call, the one added when the webview was created
+            // cannot be removed but this one runs after it
+            let previous = webview.navigator_language_script.borrow_mut().take();
+            if let Some(previous) = previous {
+              remove_initialization_script(&webview, previous);
+            }
+            let added = add_initialization_script(&webview, &script);
+            *webview.navigator_language_script.borrow_mut() = added;
+            // update the current page
+            if let Err(e) = webview.evaluate_script(&format!(
+              "{script}; window.dispatchEvent(new Event('languagechange'))"
+            )) {
+              log::error!("failed to set navigator language: {e}");
+            }
+            webview.accept_language.lock().unwrap().replace(language);
+          }
+          #[cfg(not(target_os = "android"))]
+          WebviewMessage::SetSpellcheck(enabled) => {
+            let script = spellcheck_script(enabled);
+            add_initialization_script(&webview, &script);
+            // update the current page
+            if let Err(e) = webview.evaluate_script(&script) {
+              log::error!("failed to set spellcheck: {e}");
+            }
+          }
+          #[cfg(windows)]
+          WebviewMessage::SetAutofill(enabled) => {
+            if let Err(e) = set_autofill(&webview, enabled) {
+              log::error!("failed to set webview autofill: {e}");
+            }
+          }
+          #[cfg(windows)]
+          WebviewMessage::SetSuspended(suspended) => {
+            if let Err(e) = set_suspended(&webview, suspended) {
+              log::error!("failed to suspend or resume the webview: {e}");
+            }
+          }
+          #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+          ))]
+          WebviewMessage::Find(query, options) => {
+            use webkit2gtk::{FindControllerExt, FindOptions as GtkFindOptions, WebViewExt};
+            if let Some(controller) = webview.webview().find_controller() {
+              let mut find_options = GtkFindOptions::WRAP_AROUND;
+              if !options.case_sensitive {
+                find_options |= GtkFindOptions::CASE_INSENSITIVE;
+              }
+              // WebKitGTK always highlights every match, so `highlight_all` has no effect here.
+              controller.search(&query, find_options.bits(), u32::MAX);
+            }
+          }
+          #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+          ))]
+          WebviewMessage::FindNext => {
+            use webkit2gtk::{FindControllerExt, WebViewExt};
+            if let Some(controller) = webview.webview().find_controller() {
+              controller.search_next();
+            }
+          }
+          #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+          ))]
+          WebviewMessage::StopFind => {
+            use webkit2gtk::{FindControllerExt, WebViewExt};
+            if let Some(controller) = webview.webview().find_controller() {
+              controller.search_finish();
+            }
+          }
           // Getters
           WebviewMessage::Url(tx) => {
             tx.send(
@@ -3437,6 +4022,19 @@ fn handle_user_message<T: UserEvent>(
             )
             .unwrap();
           }
+          #[cfg(windows)]
+          WebviewMessage::Favicon(tx) => {
+            // older WebView2 runtimes cannot read the favicon
+            let favicon = unsafe { webview.controller().CoreWebView2() }
+              .map_err(|_| Error::FailedToSendMessage)
+              .and_then(|webview| {
+                webview
+                  .cast::<ICoreWebView2_15>()
+                  .map_err(|_| Error::Unsupported)
+              })
+              .map(|webview| favicon_url(&webview));
+            tx.send(favicon).unwrap();
+          }
           WebviewMessage::SetFocus => {
             if let Err(e) = webview.focus() {
               log::error!("failed to focus webview: {e}");
@@ -3590,6 +4188,9 @@ fn handle_user_message<T: UserEvent>(
           WindowWrapper {
             label,
             has_children: AtomicBool::new(false),
+            user_attention_requested: AtomicBool::new(false),
+            #[cfg(all(desktop, not(target_os = "macos")))]
+            title_bar_hidden: AtomicBool::new(false),
             inner: Some(window.clone()),
             window_event_listeners: Default::default(),
             webviews: Vec::new(),
@@ -3685,6 +4286,13 @@ fn handle_event_loop<T: UserEvent>(
         if let Some(webview) = window.webviews.iter().find(|w| w.id == webview_id) {
           let label = webview.label.clone();
           let webview_event_listeners = webview.webview_event_listeners.clone();
+          // drag and drop events are also reported to the window, identifying the target webview
+          let file_drop_event = match &event {
+            WebviewEvent::DragDrop(event) => webview.file_drop_event(event),
+            _ => None,
+          };
+          let window_label = window.label.clone();
+          let window_event_listeners = window.window_event_listeners.clone();
 
           drop(windows_ref);
 
@@ -3692,10 +4300,25 @@ fn handle_event_loop<T: UserEvent>(
             label,
             event: event.clone(),
           });
-          let listeners = webview_event_listeners.lock().unwrap();
-          let handlers = listeners.values();
-          for handler in handlers {
-            handler(&event);
+          {
+            let listeners = webview_event_listeners.lock().unwrap();
+            let handlers = listeners.values();
+            for handler in handlers {
+              handler(&event);
+            }
+          }
+
+          if let Some(file_drop_event) = file_drop_event {
+            let event = WindowEvent::FileDrop(file_drop_event);
+            callback(RunEvent::WindowEvent {
+              label: window_label,
+              event: event.clone(),
+            });
+            let listeners = window_event_listeners.lock().unwrap();
+            let handlers = listeners.values();
+            for handler in handlers {
+              handler(&event);
+            }
           }
         }
       }
@@ -3703,20 +4326,32 @@ fn handle_event_loop<T: UserEvent>(
 
     Event::UserEvent(Message::Webview(
       window_id,
-      _webview_id,
+      webview_id,
       WebviewMessage::SynthesizedWindowEvent(event),
     )) => {
-      if let Some(event) = WindowEventWrapper::from(event).0 {
-        let windows_ref = windows.0.borrow();
-        let window = windows_ref.get(&window_id);
-        if let Some(window) = window {
-          let label = window.label.clone();
-          let window_event_listeners = window.window_event_listeners.clone();
+      let windows_ref = windows.0.borrow();
+      if let Some(window) = windows_ref.get(&window_id) {
+        // drag and drop events are also reported as file drops identifying the target webview
+        let file_drop_event = match &event {
+          SynthesizedWindowEvent::DragDrop(event) => window
+            .webviews
+            .iter()
+            .find(|w| w.id == webview_id)
+            .and_then(|webview| webview.file_drop_event(event)),
+          _ => None,
+        };
+        let label = window.label.clone();
+        let window_event_listeners = window.window_event_listeners.clone();
 
-          drop(windows_ref);
+        drop(windows_ref);
 
+        let events = WindowEventWrapper::from(event)
+          .0
+          .into_iter()
+          .chain(file_drop_event.map(WindowEvent::FileDrop));
+        for event in events {
           callback(RunEvent::WindowEvent {
-            label,
+            label: label.clone(),
             event: event.clone(),
           });
 
@@ -3771,6 +4406,15 @@ fn handle_event_loop<T: UserEvent>(
               }
             }
           }
+          TaoWindowEvent::Focused(true) => {
+            if let Some(w) = windows.0.borrow().get(&window_id) {
+              if w.user_attention_requested.swap(false, Ordering::Relaxed) {
+                if let Some(window) = &w.inner {
+                  window.request_user_attention(None);
+                }
+              }
+            }
+          }
           TaoWindowEvent::CloseRequested => {
             on_close_requested(callback, window_id, windows);
           }
@@ -3798,15 +4442,23 @@ fn handle_event_loop<T: UserEvent>(
               .get(&window_id)
               .map(|w| (w.inner.clone(), w.webviews.clone()))
             {
-              let size = size.to_logical::<f32>(window.scale_factor());
+              let scale_factor = window.scale_factor();
+              let size = size.to_logical::<f32>(scale_factor);
               for webview in webviews {
                 if let Some(b) = &*webview.bounds.lock().unwrap() {
-                  if let Err(e) = webview.set_bounds(wry::Rect {
-                    position: LogicalPosition::new(size.width * b.x_rate, size.height * b.y_rate)
+                  let bounds = webview.constrain_bounds(
+                    wry::Rect {
+                      position: LogicalPosition::new(size.width * b.x_rate, size.height * b.y_rate)
+                        .into(),
+                      size: LogicalSize::new(
+                        size.width * b.width_rate,
+                        size.height * b.height_rate,
+                      )
                       .into(),
-                    size: LogicalSize::new(size.width * b.width_rate, size.height * b.height_rate)
-                      .into(),
-                  }) {
+                    },
+                    scale_factor,
+                  );
+                  if let Err(e) = webview.set_bounds(bounds) {
                     log::error!("failed to autoresize webview: {e}");
                   }
                 }
@@ -3906,7 +4558,11 @@ fn on_window_close(window_id: WindowId, windows: Arc<WindowsStore>) {
 
 fn parse_proxy_url(url: &Url) -> Result<ProxyConfig> {
   let host = url.host().map(|h| h.to_string()).unwrap_or_default();
-  let port = url.port().map(|p| p.to_string()).unwrap_or_default();
+  // `http://host:80` URLs do not keep their default port
+  let port = url
+    .port_or_known_default()
+    .map(|p| p.to_string())
+    .unwrap_or_default();
 
   if url.scheme() == "http" {
     let config = ProxyConfig::Http(ProxyEndpoint { host, port });
@@ -4013,8 +4669,61 @@ fn create_window<T: UserEvent, F: Fn(RawWindow) + Send + 'static>(
     }
   }
 
+  #[cfg(any(
+    windows,
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  let app_id = window_builder.app_id.take();
+  // GTK reads the `WM_CLASS` when the window is realized, so it is only shown once the app id is set
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  let show_after_app_id = app_id.is_some() && window_builder.inner.window.visible;
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  if show_after_app_id {
+    window_builder.inner = window_builder.inner.with_visible(false);
+  }
+  #[cfg(all(desktop, not(target_os = "macos")))]
+  let title_bar_hidden = window_builder.title_bar_hidden;
+
   let window = window_builder.inner.build(event_loop).unwrap();
 
+  #[cfg(any(
+    windows,
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  if let Some(app_id) = &app_id {
+    window.set_app_id(app_id);
+  }
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  if show_after_app_id {
+    window.set_visible(true);
+  }
+
   #[cfg(feature = "tracing")]
   {
     drop(window_create_span);
@@ -4094,6 +4803,9 @@ fn create_window<T: UserEvent, F: Fn(RawWindow) + Send + 'static>(
   Ok(WindowWrapper {
     label,
     has_children: AtomicBool::new(false),
+    user_attention_requested: AtomicBool::new(false),
+    #[cfg(all(desktop, not(target_os = "macos")))]
+    title_bar_hidden: AtomicBool::new(title_bar_hidden),
     inner: Some(window),
     webviews,
     window_event_listeners,
@@ -4123,6 +4835,179 @@ struct WebviewBounds {
   height_rate: f32,
 }
 
+#[derive(Debug, Clone, Default)]
+struct WebviewSizeConstraints {
+  min: Option<LogicalSize<f64>>,
+  max: Option<LogicalSize<f64>>,
+}
+
+impl WebviewSizeConstraints {
+  // the minimum size wins when it is larger than the maximum size
+  fn clamp(&self, mut size: LogicalSize<f64>) -> LogicalSize<f64> {
+    if let Some(max) = self.max {
+      size.width = size.width.min(max.width);
+      size.height = size.height.min(max.height);
+    }
+    if let Some(min) = self.min {
+      size.width = size.width.max(min.width);
+      size.height = size.height.max(min.height);
+    }
+    size
+  }
+}
+
+/// Whether the webview, or one of its child windows, has the keyboard focus.
+#[cfg(windows)]
+fn is_webview_focused(webview: &WebviewWrapper) -> bool {
+  use windows::Win32::UI::{Input::KeyboardAndMouse::GetFocus, WindowsAndMessaging::IsChild};
+
+  let mut container = HWND::default();
+  if unsafe { webview.controller().ParentWindow(&mut container) }.is_err() {
+    return false;
+  }
+  let focus = unsafe { GetFocus() };
+  focus == container || unsafe { IsChild(container, focus) }.as_bool()
+}
+
+/// Reads the favicon URL of the current page, returning `None` if the page has no favicon.
+#[cfg(windows)]
+fn favicon_url(webview: &ICoreWebView2_15) -> Option<Url> {
+  let mut uri = windows::core::PWSTR::null();
+  unsafe { webview.FaviconUri(&mut uri) }.ok()?;
+  let uri = webview2_com::take_pwstr(uri);
+  if uri.is_empty() {
+    None
+  } else {
+    uri.parse().ok()
+  }
+}
+
+/// Reads the response of the main resource of the webview.
+#[cfg(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+fn main_frame_response(
+  webview: &webkit2gtk::WebView,
+) -> Option<tauri_runtime::window::MainFrameResponse> {
+  use webkit2gtk::{URIResponseExt, WebResourceExt, WebViewExt};
+
+  let response = webview.main_resource()?.response()?;
+  let url = response.uri()?.parse().ok()?;
+  let status = http::StatusCode::from_u16(response.status_code() as u16).ok()?;
+  let mut headers = http::HeaderMap::new();
+  if let Some(message_headers) = response.http_headers() {
+    message_headers.foreach(|name, value| {
+      if let (Ok(name), Ok(value)) = (
+        http::HeaderName::from_bytes(name.as_bytes()),
+        http::HeaderValue::from_str(value),
+      ) {
+        headers.append(name, value);
+      }
+    });
+  }
+  Some(tauri_runtime::window::MainFrameResponse {
+    url,
+    status,
+    headers,
+  })
+}
+
+/// Reports the responses of the main frame navigations of the webview.
+///
+/// WebView2 reports the responses of every resource, so only the one matching the URL of the current navigation is kept.
+#[cfg(windows)]
+fn listen_to_main_frame_responses<T: UserEvent>(
+  webview: &wry::WebView,
+  proxy: &TaoEventLoopProxy<Message<T>>,
+  window_id: Arc<Mutex<WindowId>>,
+  id: WebviewId,
+) -> windows::core::Result<()> {
+  use std::{cell::RefCell, rc::Rc};
+  use webview2_com::{
+    Microsoft::Web::WebView2::Win32::ICoreWebView2_2, NavigationStartingEventHandler,
+    WebResourceResponseReceivedEventHandler,
+  };
+  use windows::{core::PWSTR, Win32::Foundation::BOOL};
+
+  let webview = unsafe { webview.controller().CoreWebView2() }?.cast::<ICoreWebView2_2>()?;
+  // the fragment is not part of the requested URL
+  let strip_fragment = |uri: String| uri.split('#').next().unwrap_or_default().to_string();
+
+  let navigation_uri = Rc::new(RefCell::new(None::<String>));
+  let navigation_uri_ = navigation_uri.clone();
+  let mut token = EventRegistrationToken::default();
+  unsafe {
+    webview.add_NavigationStarting(
+      &NavigationStartingEventHandler::create(Box::new(move |_, args| {
+        if let Some(args) = args {
+          let mut uri = PWSTR::null();
+          args.Uri(&mut uri)?;
+          *navigation_uri_.borrow_mut() = Some(strip_fragment(webview2_com::take_pwstr(uri)));
+        }
+        Ok(())
+      })),
+      &mut token,
+    )
+  }?;
+
+  let proxy = proxy.clone();
+  unsafe {
+    webview.add_WebResourceResponseReceived(
+      &WebResourceResponseReceivedEventHandler::create(Box::new(move |_, args| {
+        let Some(args) = args else {
+          return Ok(());
+        };
+        let mut uri = PWSTR::null();
+        args.Request()?.Uri(&mut uri)?;
+        let uri = strip_fragment(webview2_com::take_pwstr(uri));
+        if navigation_uri.borrow().as_deref() != Some(uri.as_str()) {
+          return Ok(());
+        }
+
+        let response = args.Response()?;
+        let mut status = 0;
+        response.StatusCode(&mut status)?;
+
+        let mut headers = http::HeaderMap::new();
+        let iterator = response.Headers()?.GetIterator()?;
+        let mut has_current = BOOL::default();
+        iterator.HasCurrentHeader(&mut has_current)?;
+        while has_current.as_bool() {
+          let (mut name, mut value) = (PWSTR::null(), PWSTR::null());
+          iterator.GetCurrentHeader(&mut name, &mut value)?;
+          if let (Ok(name), Ok(value)) = (
+            http::HeaderName::from_bytes(webview2_com::take_pwstr(name).as_bytes()),
+            http::HeaderValue::from_str(&webview2_com::take_pwstr(value)),
+          ) {
+            headers.append(name, value);
+          }
+          iterator.MoveNext(&mut has_current)?;
+        }
+
+        if let (Ok(url), Ok(status)) = (uri.parse(), http::StatusCode::from_u16(status as u16)) {
+          let _ = proxy.send_event(Message::Webview(
+            *window_id.lock().unwrap(),
+            id,
+            WebviewMessage::WebviewEvent(WebviewEvent::MainFrameResponse(
+              tauri_runtime::window::MainFrameResponse {
+                url,
+                status,
+                headers,
+              },
+            )),
+          ));
+        }
+        Ok(())
+      })),
+      &mut token,
+    )
+  }
+}
+
 fn create_webview<T: UserEvent>(
   kind: WebviewKind,
   window: &Window,
@@ -4138,9 +5023,16 @@ fn create_webview<T: UserEvent>(
     label,
     ipc_handler,
     url,
+    html,
     ..
   } = pending;
 
+  // autofill can only be toggled on WebView2
+  #[cfg(not(windows))]
+  if webview_attributes.autofill.is_some() {
+    return Err(Error::Unsupported);
+  }
+
   let mut web_context = context
     .main_thread
     .web_context
@@ -4175,13 +5067,19 @@ fn create_webview<T: UserEvent>(
   let mut webview_builder = WebViewBuilder::with_web_context(&mut web_context.inner)
     .with_id(&label)
     .with_focused(webview_attributes.focus)
-    .with_url(&url)
+    .with_visible(webview_attributes.visible)
     .with_transparent(webview_attributes.transparent)
     .with_accept_first_mouse(webview_attributes.accept_first_mouse)
     .with_incognito(webview_attributes.incognito)
     .with_clipboard(webview_attributes.clipboard)
     .with_hotkeys_zoom(webview_attributes.zoom_hotkeys_enabled);
 
+  webview_builder = if let Some(html) = html {
+    webview_builder.with_html(html)
+  } else {
+    webview_builder.with_url(&url)
+  };
+
   #[cfg(any(target_os = "windows", target_os = "android"))]
   {
     webview_builder = webview_builder.with_https_scheme(webview_attributes.use_https_scheme);
@@ -4237,6 +5135,13 @@ fn create_webview<T: UserEvent>(
     });
   }
 
+  if let Some(new_window_handler) = pending.new_window_handler {
+    webview_builder = webview_builder.with_new_window_req_handler(move |url| match url.parse() {
+      Ok(url) => new_window_handler(url),
+      Err(_) => true,
+    });
+  }
+
   let webview_bounds = if let Some(bounds) = webview_attributes.bounds {
     let bounds: RectWrapper = bounds.into();
     let bounds = bounds.0;
@@ -4297,7 +5202,35 @@ fn create_webview<T: UserEvent>(
   }
 
   if let Some(page_load_handler) = pending.on_page_load_handler {
+    #[cfg(not(any(
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd"
+    )))]
+    let (proxy, window_id_) = (context.proxy.clone(), window_id.clone());
     webview_builder = webview_builder.with_on_page_load_handler(move |event, url| {
+      // no native progress reporting here, so report the start and end of the navigation instead
+      #[cfg(not(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+      )))]
+      {
+        let progress = match event {
+          wry::PageLoadEvent::Started => 0.0,
+          wry::PageLoadEvent::Finished => 1.0,
+        };
+        let _ = proxy.send_event(Message::Webview(
+          *window_id_.lock().unwrap(),
+          id,
+          WebviewMessage::WebviewEvent(WebviewEvent::LoadProgress(progress)),
+        ));
+      }
+
       let _ = url.parse().map(|url| {
         page_load_handler(
           url,
@@ -4310,10 +5243,33 @@ fn create_webview<T: UserEvent>(
     });
   }
 
+  if let Some(document_title_changed_handler) = pending.document_title_changed_handler {
+    webview_builder = webview_builder
+      .with_document_title_changed_handler(move |title| document_title_changed_handler(title));
+  }
+
   if let Some(user_agent) = webview_attributes.user_agent {
     webview_builder = webview_builder.with_user_agent(&user_agent);
   }
 
+  let accept_language = webview_attributes.accept_language;
+  if let Some(language) = &accept_language {
+    // the Android webview language follows the app locale
+    if cfg!(target_os = "android") {
+      return Err(Error::Unsupported);
+    }
+    tauri_runtime::webview::validate_language_tag(language)?;
+    if let Some(headers) = accept_language_headers(&accept_language)? {
+      webview_builder = webview_builder.with_headers(headers);
+    }
+    webview_builder =
+      webview_builder.with_initialization_script(&navigator_language_script(language));
+  }
+
+  if let Some(enabled) = webview_attributes.spellcheck {
+    webview_builder = webview_builder.with_initialization_script(&spellcheck_script(enabled));
+  }
+
   if let Some(proxy_url) = webview_attributes.proxy_url {
     let config = parse_proxy_url(&proxy_url)?;
 
@@ -4322,7 +5278,18 @@ fn create_webview<T: UserEvent>(
 
   #[cfg(windows)]
   {
-    if let Some(additional_browser_args) = webview_attributes.additional_browser_args {
+    #[allow(unused_mut)]
+    let mut additional_browser_args = webview_attributes.additional_browser_args;
+    #[cfg(any(debug_assertions, feature = "devtools"))]
+    if let Some(port) = webview_attributes.remote_debugging_port {
+      additional_browser_args = Some(format!(
+        "{} --remote-debugging-port={port}",
+        additional_browser_args
+          .as_deref()
+          .unwrap_or(DEFAULT_WEBVIEW2_BROWSER_ARGS)
+      ));
+    }
+    if let Some(additional_browser_args) = additional_browser_args {
       webview_builder = webview_builder.with_additional_browser_args(&additional_browser_args);
     }
 
@@ -4480,6 +5447,109 @@ fn create_webview<T: UserEvent>(
     }
   }
 
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  {
+    use webkit2gtk::{FindControllerExt, WebViewExt};
+    if let Some(controller) = webview.webview().find_controller() {
+      let proxy = context.proxy.clone();
+      let proxy_ = proxy.clone();
+      let window_id_ = window_id.clone();
+      let window_id__ = window_id.clone();
+      controller.connect_found_text(move |_, match_count| {
+        let _ = proxy.send_event(Message::Webview(
+          *window_id_.lock().unwrap(),
+          id,
+          WebviewMessage::WebviewEvent(WebviewEvent::FindMatchCount(match_count)),
+        ));
+      });
+      controller.connect_failed_to_find_text(move |_| {
+        let _ = proxy_.send_event(Message::Webview(
+          *window_id__.lock().unwrap(),
+          id,
+          WebviewMessage::WebviewEvent(WebviewEvent::FindMatchCount(0)),
+        ));
+      });
+    }
+
+    let proxy = context.proxy.clone();
+    let window_id_ = window_id.clone();
+    webview
+      .webview()
+      .connect_estimated_load_progress_notify(move |webview| {
+        let _ = proxy.send_event(Message::Webview(
+          *window_id_.lock().unwrap(),
+          id,
+          WebviewMessage::WebviewEvent(WebviewEvent::LoadProgress(
+            webview.estimated_load_progress(),
+          )),
+        ));
+      });
+
+    let proxy = context.proxy.clone();
+    let window_id_ = window_id.clone();
+    webview
+      .webview()
+      .connect_load_changed(move |webview, event| {
+        // the main resource response is known once the load is committed
+        if event != webkit2gtk::LoadEvent::Committed {
+          return;
+        }
+        if let Some(response) = main_frame_response(webview) {
+          let _ = proxy.send_event(Message::Webview(
+            *window_id_.lock().unwrap(),
+            id,
+            WebviewMessage::WebviewEvent(WebviewEvent::MainFrameResponse(response)),
+          ));
+        }
+      });
+  }
+
+  #[cfg(windows)]
+  if let Err(e) = listen_to_main_frame_responses(&webview, &context.proxy, window_id.clone(), id) {
+    log::error!("failed to listen to main frame responses: {e}");
+  }
+
+  #[cfg(windows)]
+  if let Some(enabled) = webview_attributes.autofill {
+    if let Err(e) = set_autofill(&webview, enabled) {
+      log::error!("failed to set webview autofill: {e}");
+    }
+  }
+
+  #[cfg(windows)]
+  if let Ok(webview) =
+    unsafe { webview.controller().CoreWebView2() }.and_then(|w| w.cast::<ICoreWebView2_15>())
+  {
+    let proxy = context.proxy.clone();
+    let window_id_ = window_id.clone();
+    let mut token = EventRegistrationToken::default();
+    let result = unsafe {
+      webview.add_FaviconChanged(
+        &FaviconChangedEventHandler::create(Box::new(move |sender, _| {
+          let favicon = sender
+            .and_then(|sender| sender.cast::<ICoreWebView2_15>().ok())
+            .and_then(|sender| favicon_url(&sender));
+          let _ = proxy.send_event(Message::Webview(
+            *window_id_.lock().unwrap(),
+            id,
+            WebviewMessage::WebviewEvent(WebviewEvent::FaviconChanged(favicon)),
+          ));
+          Ok(())
+        })),
+        &mut token,
+      )
+    };
+    if let Err(e) = result {
+      log::error!("failed to listen to favicon changes: {e}");
+    }
+  }
+
   #[cfg(windows)]
   if kind == WebviewKind::WindowContent {
     let controller = webview.controller();
@@ -4529,9 +5599,251 @@ fn create_webview<T: UserEvent>(
       web_context_key
     },
     bounds: Arc::new(Mutex::new(webview_bounds)),
+    size_constraints: Default::default(),
+    accept_language: Arc::new(Mutex::new(accept_language)),
+    #[cfg(not(target_os = "android"))]
+    navigator_language_script: Default::default(),
+    dragged_paths: Default::default(),
   })
 }
 
+/// The headers requesting the given language, if any.
+fn accept_language_headers(language: &Option<String>) -> Result<Option<http::HeaderMap>> {
+  language
+    .as_deref()
+    .map(|language| {
+      let mut headers = http::HeaderMap::new();
+      headers.insert(
+        http::header::ACCEPT_LANGUAGE,
+        http::HeaderValue::from_str(language)?,
+      );
+      Ok(headers)
+    })
+    .transpose()
+}
+
+/// A script overriding `navigator.language` and `navigator.languages`.
+///
+/// The language must be validated with [`tauri_runtime::webview::validate_language_tag`],
+/// which guarantees it can be embedded in a string literal.
+fn navigator_language_script(language: &str) -> String {
+  format!(
+    r#"(function () {{
+  var language = "{language}";
+  Object.defineProperty(Navigator.prototype, "language", {{ configurable: true, get: function () {{ return language; }} }});
+  Object.defineProperty(Navigator.prototype, "languages", {{ configurable: true, get: function () {{ return [language]; }} }});
+}})()"#
+  )
+}
+
+/// A script setting the `spellcheck` attribute of the document element,
+/// which is inherited by the editable elements that do not set their own.
+fn spellcheck_script(enabled: bool) -> String {
+  format!(
+    r#"(function () {{
+  function apply() {{ document.documentElement.spellcheck = {enabled}; }}
+  if (document.documentElement) {{ apply(); }} else {{ document.addEventListener("readystatechange", apply, {{ once: true }}); }}
+}})()"#
+  )
+}
+
+/// The browser arguments wry passes to WebView2 when no additional arguments are set,
+/// kept when adding the remote debugging port.
+#[cfg(all(windows, any(debug_assertions, feature = "devtools")))]
+const DEFAULT_WEBVIEW2_BROWSER_ARGS: &str =
+  "--disable-features=msWebOOUI,msPdfOOUI,msSmartScreenProtection";
+
+/// Enables or disables the general autofill and the password autosave of the webview.
+#[cfg(windows)]
+fn set_autofill(webview: &WebView, enabled: bool) -> windows::core::Result<()> {
+  use webview2_com::Microsoft::Web::WebView2::Win32::ICoreWebView2Settings4;
+  unsafe {
+    let settings = webview
+      .controller()
+      .CoreWebView2()?
+      .Settings()?
+      .cast::<ICoreWebView2Settings4>()?;
+    settings.SetIsGeneralAutofillEnabled(enabled)?;
+    settings.SetIsPasswordAutosaveEnabled(enabled)
+  }
+}
+
+/// Suspends the hidden webview or resumes it.
+///
+/// WebView2 only suspends webviews that are not visible, so this must be called after hiding the webview.
+#[cfg(windows)]
+fn set_suspended(webview: &WebView, suspended: bool) -> windows::core::Result<()> {
+  use webview2_com::{
+    Microsoft::Web::WebView2::Win32::ICoreWebView2_3, TrySuspendCompletedHandler,
+  };
+  unsafe {
+    let webview = webview
+      .controller()
+      .CoreWebView2()?
+      .cast::<ICoreWebView2_3>()?;
+    if suspended {
+      webview.TrySuspend(&TrySuspendCompletedHandler::create(Box::new(
+        |_result, _suspended| Ok(()),
+      )))
+    } else {
+      webview.Resume()
+    }
+  }
+}
+
+/// A script added by [`add_initialization_script`], which can be removed with [`remove_initialization_script`].
+#[cfg(not(target_os = "android"))]
+struct InitializationScript {
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  script: webkit2gtk::UserScript,
+  #[cfg(any(target_os = "macos", target_os = "ios"))]
+  script: objc2::rc::Retained<objc2_web_kit::WKUserScript>,
+  // WebView2 reports the script identifier asynchronously
+  #[cfg(windows)]
+  id: Arc<Mutex<Option<String>>>,
+}
+
+/// Adds a script that runs on every page loaded by the webview after it has been created.
+///
+/// Returns `None` if the script could not be added.
+#[cfg(not(target_os = "android"))]
+fn add_initialization_script(
+  webview: &WebviewWrapper,
+  script: &str,
+) -> Option<InitializationScript> {
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  {
+    use webkit2gtk::{
+      UserContentInjectedFrames, UserContentManagerExt, UserScript, UserScriptInjectionTime,
+      WebViewExt,
+    };
+    let manager = webview.webview().user_content_manager()?;
+    let script = UserScript::new(
+      script,
+      UserContentInjectedFrames::TopFrame,
+      UserScriptInjectionTime::Start,
+      &[],
+      &[],
+    );
+    manager.add_script(&script);
+    Some(InitializationScript { script })
+  }
+  #[cfg(any(target_os = "macos", target_os = "ios"))]
+  {
+    use objc2::ClassType;
+    use objc2_web_kit::{WKUserScript, WKUserScriptInjectionTime};
+    #[cfg(target_os = "ios")]
+    use wry::WebViewExtIOS;
+    #[cfg(target_os = "macos")]
+    use wry::WebViewExtMacOS;
+    unsafe {
+      let script = WKUserScript::initWithSource_injectionTime_forMainFrameOnly(
+        WKUserScript::alloc(),
+        &objc2_foundation::NSString::from_str(script),
+        WKUserScriptInjectionTime::AtDocumentStart,
+        true,
+      );
+      webview.inner.manager().addUserScript(&script);
+      Some(InitializationScript { script })
+    }
+  }
+  #[cfg(windows)]
+  {
+    use webview2_com::AddScriptToExecuteOnDocumentCreatedCompletedHandler;
+    use windows::core::HSTRING;
+    let id = Arc::new(Mutex::new(None));
+    let id_ = id.clone();
+    let result = unsafe {
+      webview.controller().CoreWebView2().and_then(|webview| {
+        webview.AddScriptToExecuteOnDocumentCreated(
+          &HSTRING::from(script),
+          &AddScriptToExecuteOnDocumentCreatedCompletedHandler::create(Box::new(
+            move |result, script_id| {
+              if result.is_ok() {
+                id_.lock().unwrap().replace(script_id);
+              }
+              Ok(())
+            },
+          )),
+        )
+      })
+    };
+    match result {
+      Ok(()) => Some(InitializationScript { id }),
+      Err(e) => {
+        log::error!("failed to add initialization script: {e}");
+        None
+      }
+    }
+  }
+}
+
+/// Removes a script added by [`add_initialization_script`], the pages that are already loaded are not affected.
+#[cfg(not(target_os = "android"))]
+fn remove_initialization_script(webview: &WebviewWrapper, script: InitializationScript) {
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  {
+    use webkit2gtk::{UserContentManagerExt, WebViewExt};
+    if let Some(manager) = webview.webview().user_content_manager() {
+      manager.remove_script(&script.script);
+    }
+  }
+  #[cfg(any(target_os = "macos", target_os = "ios"))]
+  {
+    use objc2::rc::Retained;
+    #[cfg(target_os = "ios")]
+    use wry::WebViewExtIOS;
+    #[cfg(target_os = "macos")]
+    use wry::WebViewExtMacOS;
+    // WKUserContentController can only remove all of its scripts, so add back the other ones
+    unsafe {
+      let manager = webview.inner.manager();
+      let scripts = manager.userScripts();
+      manager.removeAllUserScripts();
+      for i in 0..scripts.count() {
+        let other = scripts.objectAtIndex(i);
+        if Retained::as_ptr(&other) != Retained::as_ptr(&script.script) {
+          manager.addUserScript(&other);
+        }
+      }
+    }
+  }
+  #[cfg(windows)]
+  {
+    use windows::core::HSTRING;
+    let Some(id) = script.id.lock().unwrap().take() else {
+      return;
+    };
+    let result = unsafe {
+      webview
+        .controller()
+        .CoreWebView2()
+        .and_then(|webview| webview.RemoveScriptToExecuteOnDocumentCreated(&HSTRING::from(id)))
+    };
+    if let Err(e) = result {
+      log::error!("failed to remove initialization script: {e}");
+    }
+  }
+}
+
 /// Create a wry ipc handler from a tauri ipc handler.
 fn create_ipc_handler<T: UserEvent>(
   _kind: WebviewKind,
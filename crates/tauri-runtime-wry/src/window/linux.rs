@@ -28,4 +28,15 @@ impl super::WindowExt for tao::window::Window {
       self.set_outer_position(new_pos);
     }
   }
+
+  fn set_app_id(&self, app_id: &str) {
+    let window = self.gtk_window();
+    if window.is_realized() {
+      log::warn!("the app id of a realized window cannot be changed");
+      return;
+    }
+    // GTK has no replacement for the X11 window class
+    #[allow(deprecated)]
+    window.set_wmclass(app_id, app_id);
+  }
 }
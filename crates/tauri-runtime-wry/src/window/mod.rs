@@ -37,6 +37,15 @@ pub trait WindowExt {
   /// - **Android / iOS**: Unsupported.
   fn center(&self) {}
 
+  /// Sets the identifier used to group the window in the taskbar.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Windows:** Sets the AppUserModelID of the window.
+  /// - **Linux:** Sets the `WM_CLASS` of the window, which must not be realized yet.
+  /// - **macOS / Android / iOS**: Unsupported.
+  fn set_app_id(&self, _app_id: &str) {}
+
   /// Clears the window sufrace. i.e make it it transparent.
   #[cfg(windows)]
   fn draw_surface(
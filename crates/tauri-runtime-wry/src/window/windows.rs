@@ -2,10 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use windows::Win32::{
-  Foundation::{HWND, RECT},
-  Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS},
-  UI::Input::KeyboardAndMouse::{EnableWindow, IsWindowEnabled},
+use windows::{
+  core::PROPVARIANT,
+  Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS},
+    Storage::EnhancedStorage::PKEY_AppUserModel_ID,
+    UI::{
+      Input::KeyboardAndMouse::{EnableWindow, IsWindowEnabled},
+      Shell::PropertiesSystem::{IPropertyStore, SHGetPropertyStoreForWindow},
+    },
+  },
 };
 
 use tao::platform::windows::WindowExtWindows;
@@ -43,6 +50,20 @@ impl super::WindowExt for tao::window::Window {
     }
   }
 
+  fn set_app_id(&self, app_id: &str) {
+    let result = unsafe {
+      let store: windows::core::Result<IPropertyStore> =
+        SHGetPropertyStoreForWindow(HWND(self.hwnd() as _));
+      store.and_then(|store| {
+        store.SetValue(&PKEY_AppUserModel_ID, &PROPVARIANT::from(app_id))?;
+        store.Commit()
+      })
+    };
+    if let Err(e) = result {
+      log::error!("failed to set the AppUserModelID of the window: {e}");
+    }
+  }
+
   fn draw_surface(
     &self,
     surface: &mut softbuffer::Surface<
@@ -19,14 +19,14 @@ use std::{borrow::Cow, fmt::Debug, sync::mpsc::Sender};
 use tauri_utils::config::Color;
 use tauri_utils::Theme;
 use url::Url;
-use webview::{DetachedWebview, PendingWebview};
+use webview::{DetachedWebview, FindOptions, PendingWebview};
 
 /// Types useful for interacting with a user's monitors.
 pub mod monitor;
 pub mod webview;
 pub mod window;
 
-use dpi::{PhysicalPosition, PhysicalSize, Position, Size};
+use dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Position, Size};
 use monitor::Monitor;
 use window::{
   CursorIcon, DetachedWindow, PendingWindow, RawWindow, WebviewEvent, WindowEvent,
@@ -99,10 +99,12 @@ pub enum UserAttentionType {
   /// ## Platform-specific
   /// - **macOS:** Bounces the dock icon until the application is in focus.
   /// - **Windows:** Flashes both the window and the taskbar button until the application is in focus.
+  /// - **Linux:** Sets the urgency hint of the window.
   Critical,
   /// ## Platform-specific
   /// - **macOS:** Bounces the dock icon once.
   /// - **Windows:** Flashes the taskbar button until the application is in focus.
+  /// - **Linux:** Same as [`Self::Critical`], sets the urgency hint of the window.
   Informational,
 }
 
@@ -180,8 +182,13 @@ pub enum Error {
   EventLoopClosed,
   #[error("Invalid proxy url")]
   InvalidProxyUrl,
+  #[error("invalid language tag `{0}`")]
+  InvalidLanguageTag(String),
   #[error("window not found")]
   WindowNotFound,
+  /// The operation is not supported on the current platform.
+  #[error("this operation is not supported on the current platform")]
+  Unsupported,
 }
 
 /// Result type.
@@ -481,11 +488,17 @@ pub trait WebviewDispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + '
   /// Returns the physical size of the webviews's client area.
   fn size(&self) -> Result<PhysicalSize<u32>>;
 
+  /// Returns the URL of the current page favicon, or `None` if the page has no favicon.
+  fn favicon(&self) -> Result<Option<Url>>;
+
   // SETTER
 
   /// Navigate to the given URL.
   fn navigate(&self, url: Url) -> Result<()>;
 
+  /// Loads the given HTML content, replacing the current page.
+  fn load_html(&self, html: String) -> Result<()>;
+
   /// Opens the dialog to prints the contents of the webview.
   fn print(&self) -> Result<()>;
 
@@ -501,6 +514,15 @@ pub trait WebviewDispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + '
   /// Updates the webview position.
   fn set_position(&self, position: Position) -> Result<()>;
 
+  /// Sets the minimum and maximum logical size of the webview.
+  ///
+  /// Sizes requested by [`Self::set_bounds`], [`Self::set_size`] and by the auto resize behavior are clamped to these constraints.
+  fn set_size_constraints(
+    &self,
+    min: Option<LogicalSize<f64>>,
+    max: Option<LogicalSize<f64>>,
+  ) -> Result<()>;
+
   /// Bring the window to front and focus the webview.
   fn set_focus(&self) -> Result<()>;
 
@@ -527,6 +549,38 @@ pub trait WebviewDispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + '
 
   /// Clear all browsing data for this webview.
   fn clear_all_browsing_data(&self) -> Result<()>;
+
+  /// Sets the user agent used by the webview.
+  ///
+  /// The page that is currently loaded is not reloaded, so the new value only applies to the next navigation.
+  fn set_user_agent(&self, user_agent: &str) -> Result<()>;
+
+  /// Sets the language requested by the webview with the `Accept-Language` header and `navigator.language`.
+  ///
+  /// `navigator.language` is updated immediately; the header is sent on the next navigation.
+  fn set_accept_language(&self, language: &str) -> Result<()>;
+
+  /// Sets whether the spell checker checks the editable elements of the pages.
+  fn set_spellcheck(&self, enabled: bool) -> Result<()>;
+
+  /// Sets whether the webview suggests and saves form and password entries.
+  fn set_autofill(&self, enabled: bool) -> Result<()>;
+
+  /// Suspends the hidden webview, pausing its timers, animations and rendering, or resumes it.
+  ///
+  /// The webview must be hidden to be suspended, and resumed before it is shown.
+  fn set_suspended(&self, suspended: bool) -> Result<()>;
+
+  /// Searches the page for the given query, selecting the first match.
+  ///
+  /// The number of matches is reported through [`WebviewEvent::FindMatchCount`].
+  fn find(&self, query: &str, options: FindOptions) -> Result<()>;
+
+  /// Selects the next match of the current search.
+  fn find_next(&self) -> Result<()>;
+
+  /// Finishes the current search, clearing its highlights.
+  fn stop_find(&self) -> Result<()>;
 }
 
 /// Window dispatcher. A thread-safe handle to the window APIs.
@@ -759,6 +813,10 @@ pub trait WindowDispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + 's
   fn set_background_color(&self, color: Option<Color>) -> Result<()>;
 
   /// Prevents the window contents from being captured by other apps.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / iOS / Android:** Unsupported, returns [`Error::Unsupported`].
   fn set_content_protected(&self, protected: bool) -> Result<()>;
 
   /// Resizes the window.
@@ -840,11 +898,12 @@ pub trait WindowDispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + 's
   /// - **iOS / Android:** Unsupported.
   fn set_progress_bar(&self, progress_state: ProgressBarState) -> Result<()>;
 
-  /// Sets the title bar style. Available on macOS only.
+  /// Sets the title bar style.
   ///
   /// ## Platform-specific
   ///
-  /// - **Linux / Windows / iOS / Android:** Unsupported.
+  /// - **Linux / Windows:** Only [`tauri_utils::TitleBarStyle::Hidden`] is supported, which removes the window decorations.
+  /// - **iOS / Android:** Unsupported.
   fn set_title_bar_style(&self, style: tauri_utils::TitleBarStyle) -> Result<()>;
 
   /// Sets the theme for this window.
@@ -28,8 +28,12 @@ type WebResourceRequestHandler =
 
 type NavigationHandler = dyn Fn(&Url) -> bool + Send;
 
+type NewWindowHandler = dyn Fn(Url) -> bool + Send;
+
 type OnPageLoadHandler = dyn Fn(Url, PageLoadEvent) + Send;
 
+type DocumentTitleChangedHandler = dyn Fn(String) + Send;
+
 type DownloadHandler = dyn Fn(DownloadEvent) -> bool + Send + Sync;
 
 /// Download event.
@@ -70,6 +74,113 @@ pub enum PageLoadEvent {
   Finished,
 }
 
+/// Options for [`crate::WebviewDispatch::find`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FindOptions {
+  /// Whether the search should match the case of the query.
+  pub case_sensitive: bool,
+  /// Whether all matches should be highlighted instead of only the current one.
+  pub highlight_all: bool,
+}
+
+/// A proxy the network requests of a webview are routed through, see [`WebviewAttributes::proxy_url`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProxyConfig {
+  /// An HTTP proxy, parsed from a `http://host:port` URL. The port defaults to 80.
+  Http {
+    /// The host name or IP address of the proxy.
+    host: String,
+    /// The port of the proxy.
+    port: u16,
+  },
+  /// A SOCKS5 proxy, parsed from a `socks5://host:port` URL. The port is required.
+  Socks5 {
+    /// The host name or IP address of the proxy.
+    host: String,
+    /// The port of the proxy.
+    port: u16,
+  },
+}
+
+impl ProxyConfig {
+  /// The host name or IP address of the proxy.
+  pub fn host(&self) -> &str {
+    match self {
+      Self::Http { host, .. } | Self::Socks5 { host, .. } => host,
+    }
+  }
+
+  /// The port of the proxy.
+  pub fn port(&self) -> u16 {
+    match self {
+      Self::Http { port, .. } | Self::Socks5 { port, .. } => *port,
+    }
+  }
+
+  /// The URL of the proxy, e.g. `socks5://127.0.0.1:1080`.
+  pub fn url(&self) -> Url {
+    let scheme = match self {
+      Self::Http { .. } => "http",
+      Self::Socks5 { .. } => "socks5",
+    };
+    let host = self.host();
+    let host = if host.contains(':') {
+      format!("[{host}]")
+    } else {
+      host.to_string()
+    };
+    format!("{scheme}://{host}:{}", self.port())
+      .parse()
+      .expect("proxy config always has a valid URL")
+  }
+}
+
+impl TryFrom<&Url> for ProxyConfig {
+  type Error = crate::Error;
+
+  /// Parses a `http://host[:port]` or `socks5://host:port` URL.
+  ///
+  /// Credentials, paths, queries and fragments are rejected since the webviews cannot use them.
+  fn try_from(url: &Url) -> crate::Result<Self> {
+    let host = match url.host() {
+      Some(url::Host::Ipv6(addr)) => addr.to_string(),
+      Some(host) => host.to_string(),
+      None => return Err(crate::Error::InvalidProxyUrl),
+    };
+
+    if !url.username().is_empty()
+      || url.password().is_some()
+      || !matches!(url.path(), "" | "/")
+      || url.query().is_some()
+      || url.fragment().is_some()
+    {
+      return Err(crate::Error::InvalidProxyUrl);
+    }
+
+    match url.scheme() {
+      "http" => Ok(Self::Http {
+        host,
+        port: url.port_or_known_default().unwrap_or(80),
+      }),
+      "socks5" => Ok(Self::Socks5 {
+        host,
+        port: url.port().ok_or(crate::Error::InvalidProxyUrl)?,
+      }),
+      _ => Err(crate::Error::InvalidProxyUrl),
+    }
+  }
+}
+
+impl std::str::FromStr for ProxyConfig {
+  type Err = crate::Error;
+
+  fn from_str(s: &str) -> crate::Result<Self> {
+    let url = Url::parse(s).map_err(|_| crate::Error::InvalidProxyUrl)?;
+    Self::try_from(&url)
+  }
+}
+
 /// A webview that has yet to be built.
 pub struct PendingWebview<T: UserEvent, R: Runtime<T>> {
   /// The label that the webview will be named.
@@ -86,9 +197,16 @@ pub struct PendingWebview<T: UserEvent, R: Runtime<T>> {
   /// A handler to decide if incoming url is allowed to navigate.
   pub navigation_handler: Option<Box<NavigationHandler>>,
 
+  /// A handler to decide if a page request to open a new window (e.g. a `target=_blank` link) is allowed
+  /// to open a platform window.
+  pub new_window_handler: Option<Box<NewWindowHandler>>,
+
   /// The resolved URL to load on the webview.
   pub url: String,
 
+  /// HTML content to load on the webview instead of [`Self::url`].
+  pub html: Option<String>,
+
   #[cfg(target_os = "android")]
   #[allow(clippy::type_complexity)]
   pub on_webview_created:
@@ -98,6 +216,9 @@ pub struct PendingWebview<T: UserEvent, R: Runtime<T>> {
 
   pub on_page_load_handler: Option<Box<OnPageLoadHandler>>,
 
+  /// A handler called when the title of the loaded document changes.
+  pub document_title_changed_handler: Option<Box<DocumentTitleChangedHandler>>,
+
   pub download_handler: Option<Arc<DownloadHandler>>,
 }
 
@@ -117,11 +238,14 @@ impl<T: UserEvent, R: Runtime<T>> PendingWebview<T, R> {
         label,
         ipc_handler: None,
         navigation_handler: None,
+        new_window_handler: None,
         url: "tauri://localhost".to_string(),
+        html: None,
         #[cfg(target_os = "android")]
         on_webview_created: None,
         web_resource_request_handler: None,
         on_page_load_handler: None,
+        document_title_changed_handler: None,
         download_handler: None,
       })
     }
@@ -190,11 +314,34 @@ impl<T: UserEvent, R: Runtime<T>> PartialEq for DetachedWebview<T, R> {
   }
 }
 
+/// Checks that the given value is a well-formed BCP 47 language tag, e.g. `en`, `pt-BR` or `zh-Hant-TW`.
+///
+/// Returns [`crate::Error::InvalidLanguageTag`] otherwise.
+pub fn validate_language_tag(tag: &str) -> crate::Result<()> {
+  let mut subtags = tag.split('-');
+  // the primary subtag is a language code, or `i`/`x` for grandfathered and private use tags
+  let valid_primary = subtags.next().is_some_and(|primary| {
+    matches!(primary, "i" | "x")
+      || ((2..=8).contains(&primary.len()) && primary.chars().all(|c| c.is_ascii_alphabetic()))
+  });
+  let valid = valid_primary
+    && subtags.all(|subtag| {
+      (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+    });
+
+  if valid {
+    Ok(())
+  } else {
+    Err(crate::Error::InvalidLanguageTag(tag.to_string()))
+  }
+}
+
 /// The attributes used to create an webview.
 #[derive(Debug, Clone)]
 pub struct WebviewAttributes {
   pub url: WebviewUrl,
   pub user_agent: Option<String>,
+  pub accept_language: Option<String>,
   pub initialization_scripts: Vec<String>,
   pub data_directory: Option<PathBuf>,
   pub drag_drop_handler_enabled: bool,
@@ -205,6 +352,7 @@ pub struct WebviewAttributes {
   pub incognito: bool,
   pub transparent: bool,
   pub focus: bool,
+  pub visible: bool,
   pub bounds: Option<Rect>,
   pub auto_resize: bool,
   pub proxy_url: Option<Url>,
@@ -215,6 +363,9 @@ pub struct WebviewAttributes {
   pub use_https_scheme: bool,
   pub devtools: Option<bool>,
   pub background_color: Option<Color>,
+  pub spellcheck: Option<bool>,
+  pub autofill: Option<bool>,
+  pub remote_debugging_port: Option<u16>,
 }
 
 impl From<&WindowConfig> for WebviewAttributes {
@@ -259,6 +410,7 @@ impl WebviewAttributes {
     Self {
       url,
       user_agent: None,
+      accept_language: None,
       initialization_scripts: Vec::new(),
       data_directory: None,
       drag_drop_handler_enabled: true,
@@ -269,6 +421,7 @@ impl WebviewAttributes {
       incognito: false,
       transparent: false,
       focus: true,
+      visible: true,
       bounds: None,
       auto_resize: false,
       proxy_url: None,
@@ -279,6 +432,9 @@ impl WebviewAttributes {
       use_https_scheme: false,
       devtools: None,
       background_color: None,
+      spellcheck: None,
+      autofill: None,
+      remote_debugging_port: None,
     }
   }
 
@@ -289,6 +445,15 @@ impl WebviewAttributes {
     self
   }
 
+  /// Sets the language requested by the webview with the `Accept-Language` header and `navigator.language`.
+  ///
+  /// The language must be a BCP 47 language tag such as `en` or `pt-BR`, see [`validate_language_tag`].
+  #[must_use]
+  pub fn accept_language(mut self, language: &str) -> Self {
+    self.accept_language = Some(language.to_string());
+    self
+  }
+
   /// Sets the init script.
   #[must_use]
   pub fn initialization_script(mut self, script: &str) -> Self {
@@ -363,6 +528,13 @@ impl WebviewAttributes {
     self
   }
 
+  /// Whether the webview should be visible when it is created or not.
+  #[must_use]
+  pub fn visible(mut self, visible: bool) -> Self {
+    self.visible = visible;
+    self
+  }
+
   /// Sets the webview to automatically grow and shrink its size and position when the parent window resizes.
   #[must_use]
   pub fn auto_resize(mut self) -> Self {
@@ -377,6 +549,13 @@ impl WebviewAttributes {
     self
   }
 
+  /// Routes the network requests of the webview through the given proxy.
+  #[must_use]
+  pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+    self.proxy_url = Some(proxy.url());
+    self
+  }
+
   /// Whether page zooming by hotkeys is enabled
   ///
   /// ## Platform-specific:
@@ -444,6 +623,29 @@ impl WebviewAttributes {
     self.background_color = Some(color);
     self
   }
+
+  /// Whether the spell checker checks the editable elements of the pages. Uses the platform default if not set.
+  #[must_use]
+  pub fn spellcheck(mut self, enabled: bool) -> Self {
+    self.spellcheck = Some(enabled);
+    self
+  }
+
+  /// Whether the webview suggests and saves form and password entries. Uses the platform default if not set.
+  #[must_use]
+  pub fn autofill(mut self, enabled: bool) -> Self {
+    self.autofill = Some(enabled);
+    self
+  }
+
+  /// Serves the remote debugging protocol of the webview on the given local port.
+  ///
+  /// Only applied in **debug** builds or with the `devtools` feature flag.
+  #[must_use]
+  pub fn remote_debugging_port(mut self, port: u16) -> Self {
+    self.remote_debugging_port = Some(port);
+    self
+  }
 }
 
 /// IPC handler.
@@ -58,6 +58,8 @@ pub enum WindowEvent {
   },
   /// An event associated with the drag and drop action.
   DragDrop(DragDropEvent),
+  /// Files are dragged over or dropped onto one of the webviews of the window.
+  FileDrop(FileDropEvent),
   /// The system window theme has changed.
   ///
   /// Applications might wish to react to this to change the theme of the content of the window when the system changes the window theme.
@@ -69,6 +71,25 @@ pub enum WindowEvent {
 pub enum WebviewEvent {
   /// An event associated with the drag and drop action.
   DragDrop(DragDropEvent),
+  /// The number of matches found by the last find-in-page search.
+  FindMatchCount(u32),
+  /// The favicon of the current page has changed. `None` if the page has no favicon.
+  FaviconChanged(Option<url::Url>),
+  /// The estimated load progress of the main frame navigation, from `0.0` to `1.0`.
+  LoadProgress(f64),
+  /// The main frame navigation received a response.
+  MainFrameResponse(MainFrameResponse),
+}
+
+/// The response of a main frame navigation, see [`WebviewEvent::MainFrameResponse`].
+#[derive(Debug, Clone)]
+pub struct MainFrameResponse {
+  /// The URL of the response.
+  pub url: url::Url,
+  /// The HTTP status code of the response.
+  pub status: http::StatusCode,
+  /// The HTTP headers of the response.
+  pub headers: http::HeaderMap,
 }
 
 /// The drag drop event payload.
@@ -98,6 +119,49 @@ pub enum DragDropEvent {
   Leave,
 }
 
+/// A file drop event targeting one of the webviews of a window, see [`WindowEvent::FileDrop`].
+///
+/// Unlike [`DragDropEvent`], the event is emitted for the child webviews of a multiwebview window
+/// and identifies the webview under the cursor.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum FileDropEvent {
+  /// Files are being dragged over the webview.
+  Hovered {
+    /// The label of the webview.
+    webview: String,
+    /// List of paths that are being dragged.
+    paths: Vec<PathBuf>,
+    /// The position of the mouse cursor, relative to the webview.
+    position: dpi::PhysicalPosition<f64>,
+  },
+  /// Files have been dropped onto the webview.
+  Dropped {
+    /// The label of the webview.
+    webview: String,
+    /// List of paths that are being dropped.
+    paths: Vec<PathBuf>,
+    /// The position of the mouse cursor, relative to the webview.
+    position: dpi::PhysicalPosition<f64>,
+  },
+  /// The drag operation has been cancelled or left the webview.
+  Cancelled {
+    /// The label of the webview.
+    webview: String,
+  },
+}
+
+impl FileDropEvent {
+  /// The label of the webview targeted by the event.
+  pub fn webview(&self) -> &str {
+    match self {
+      Self::Hovered { webview, .. }
+      | Self::Dropped { webview, .. }
+      | Self::Cancelled { webview } => webview,
+    }
+  }
+}
+
 /// Describes the appearance of the mouse cursor.
 #[non_exhaustive]
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
@@ -418,11 +482,15 @@ pub trait WindowBuilder: WindowBuilderBase {
   #[must_use]
   fn drag_and_drop(self, enabled: bool) -> Self;
 
-  /// Hide the titlebar. Titlebar buttons will still be visible.
-  #[cfg(target_os = "macos")]
+  /// Sets the style of the title bar, see [`tauri_utils::TitleBarStyle`] for the platform support.
   #[must_use]
   fn title_bar_style(self, style: tauri_utils::TitleBarStyle) -> Self;
 
+  /// Sets the position of the traffic light buttons, relative to the top left corner of the window.
+  #[cfg(target_os = "macos")]
+  #[must_use]
+  fn traffic_light_position(self, x: f64, y: f64) -> Self;
+
   /// Hide the window title.
   #[cfg(target_os = "macos")]
   #[must_use]
@@ -449,6 +517,16 @@ pub trait WindowBuilder: WindowBuilderBase {
   /// Sets custom name for Windows' window class. **Windows only**.
   #[must_use]
   fn window_classname<S: Into<String>>(self, window_classname: S) -> Self;
+
+  /// Sets the identifier the taskbar and the window manager use to group the window with other windows.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** Sets the AppUserModelID of the window.
+  /// - **Linux:** Sets the `WM_CLASS` of the window, which is only used on X11.
+  /// - **macOS / Android / iOS:** Unsupported.
+  #[must_use]
+  fn app_id<S: Into<String>>(self, app_id: S) -> Self;
 }
 
 /// A window that has yet to be built.
@@ -5,19 +5,26 @@
 //! ACL items that are only useful inside of build script/codegen context.
 
 use std::{
-  collections::{BTreeMap, HashMap},
+  collections::{BTreeMap, BTreeSet, HashMap, HashSet},
   env, fs,
   path::{Path, PathBuf},
 };
 
+use cargo_metadata::{
+  semver::{Version, VersionReq},
+  MetadataCommand, Package,
+};
+
 use crate::{acl::Error, write_if_changed};
 
 use super::{
   capability::{Capability, CapabilityFile},
-  manifest::PermissionFile,
-  PERMISSION_SCHEMAS_FOLDER_NAME, PERMISSION_SCHEMA_FILE_NAME,
+  manifest::{PermissionFile, PermissionImport},
+  CARGO_FEATURES_FILE_NAME, PERMISSION_SCHEMAS_FOLDER_NAME, PERMISSION_SCHEMA_FILE_NAME,
 };
 
+pub use super::PERMISSION_FILE_EXTENSIONS;
+
 /// Known name of the folder containing autogenerated permissions.
 pub const AUTOGENERATED_FOLDER_NAME: &str = "autogenerated";
 
@@ -27,9 +34,6 @@ pub const PERMISSION_FILES_PATH_KEY: &str = "PERMISSION_FILES_PATH";
 /// Cargo cfg key for global scope schemas
 pub const GLOBAL_SCOPE_SCHEMA_PATH_KEY: &str = "GLOBAL_SCOPE_SCHEMA_PATH";
 
-/// Allowed permission file extensions
-pub const PERMISSION_FILE_EXTENSIONS: &[&str] = &["json", "toml"];
-
 /// Known filename of the permission documentation file
 pub const PERMISSION_DOCS_FILE_NAME: &str = "reference.md";
 
@@ -49,26 +53,32 @@ const CORE_PLUGIN_PERMISSIONS_TOKEN: &str = "__CORE_PLUGIN__";
 fn parse_permissions(paths: Vec<PathBuf>) -> Result<Vec<PermissionFile>, Error> {
   let mut permissions = Vec::new();
   for path in paths {
-    let ext = path.extension().unwrap().to_string_lossy().to_string();
-    let permission_file = fs::read_to_string(&path).map_err(|e| Error::ReadFile(e, path))?;
-    let permission: PermissionFile = match ext.as_str() {
-      "toml" => toml::from_str(&permission_file)?,
-      "json" => serde_json::from_str(&permission_file)?,
-      _ => return Err(Error::UnknownPermissionFormat(ext)),
-    };
+    let permission = PermissionFile::load(&path)?;
+    for p in &permission.permission {
+      p.validate_identifier()?;
+      // the description file may live outside of the permissions directory watched by the build script
+      if let Some(description_file) = &p.description_file {
+        println!(
+          "cargo:rerun-if-changed={}",
+          path.parent().unwrap().join(description_file).display()
+        );
+      }
+    }
     permissions.push(permission);
   }
   Ok(permissions)
 }
 
 /// Write the permissions to a temporary directory and pass it to the immediate consuming crate.
+///
+/// The permissions imported by the permission files are included, see [`resolve_permission_imports`].
 pub fn define_permissions<F: Fn(&Path) -> bool>(
   pattern: &str,
   pkg_name: &str,
   out_dir: &Path,
   filter_fn: F,
 ) -> Result<Vec<PermissionFile>, Error> {
-  let permission_files = glob::glob(pattern)?
+  let mut permission_files = glob::glob(pattern)?
     .flatten()
     .flat_map(|p| p.canonicalize())
     // filter extension
@@ -83,6 +93,12 @@ pub fn define_permissions<F: Fn(&Path) -> bool>(
     .filter(|p| p.parent().unwrap().file_name().unwrap() != PERMISSION_SCHEMAS_FOLDER_NAME)
     .collect::<Vec<PathBuf>>();
 
+  let mut permissions = parse_permissions(permission_files.clone())?;
+  // build scripts run in the package root
+  let imported_files = resolve_permission_imports(&permissions, Path::new("Cargo.toml"))?;
+  permissions.extend(parse_permissions(imported_files.clone())?);
+  permission_files.extend(imported_files);
+
   let pkg_name_valid_path = pkg_name.replace(':', "-");
   let permission_files_path = out_dir.join(format!("{}-permission-files", pkg_name_valid_path));
   let permission_files_json = serde_json::to_string(&permission_files)?;
@@ -101,7 +117,123 @@ pub fn define_permissions<F: Fn(&Path) -> bool>(
     );
   }
 
-  parse_permissions(permission_files)
+  Ok(permissions)
+}
+
+/// Resolves the [`PermissionFile#structfield.import`] entries of the given permission files,
+/// returning the paths of the imported permission files, including the ones they import themselves.
+///
+/// The imported crates are looked up in the dependency tree of the crate at `manifest_path` with `cargo metadata`,
+/// which only runs when there is something to import.
+/// A `rerun-if-changed` instruction is emitted for each imported permissions directory.
+pub fn resolve_permission_imports(
+  permission_files: &[PermissionFile],
+  manifest_path: &Path,
+) -> Result<Vec<PathBuf>, Error> {
+  let mut imports = permission_files
+    .iter()
+    .flat_map(|file| file.import.clone())
+    .collect::<Vec<_>>();
+  if imports.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  let metadata = MetadataCommand::new().manifest_path(manifest_path).exec()?;
+
+  let mut versions = HashMap::<String, Version>::new();
+  let mut imported_dirs = HashSet::new();
+  let mut paths = Vec::new();
+  let mut index = 0;
+  while index < imports.len() {
+    let import = imports[index].clone();
+    index += 1;
+
+    let package = find_import_package(&metadata.packages, &import)?;
+    if let Some(version) = versions.get(&import.crate_name) {
+      if *version != package.version {
+        return Err(Error::PermissionImportConflict {
+          crate_name: import.crate_name,
+          versions: format!("{version} and {}", package.version),
+        });
+      }
+    }
+    versions.insert(import.crate_name.clone(), package.version.clone());
+
+    let dir = package
+      .manifest_path
+      .parent()
+      .expect("manifest path has a parent")
+      .as_std_path()
+      .join(import.path.as_deref().unwrap_or(Path::new("permissions")));
+    if !imported_dirs.insert(dir.clone()) {
+      continue;
+    }
+    if !dir.is_dir() {
+      return Err(Error::ReadFile(std::io::ErrorKind::NotFound.into(), dir));
+    }
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut files = glob::glob(&dir.join("**").join("*").to_string_lossy())?
+      .flatten()
+      .filter(|p| {
+        p.extension()
+          .and_then(|e| e.to_str())
+          .map(|e| PERMISSION_FILE_EXTENSIONS.contains(&e))
+          .unwrap_or_default()
+      })
+      .filter(|p| p.parent().unwrap().file_name().unwrap() != PERMISSION_SCHEMAS_FOLDER_NAME)
+      .collect::<Vec<PathBuf>>();
+    files.sort();
+
+    for file in parse_permissions(files.clone())? {
+      imports.extend(file.import);
+    }
+    paths.extend(files);
+  }
+
+  Ok(paths)
+}
+
+/// Finds the package of an imported crate, the most recent version matching the import requirement.
+fn find_import_package<'a>(
+  packages: &'a [Package],
+  import: &PermissionImport,
+) -> Result<&'a Package, Error> {
+  let candidates = packages
+    .iter()
+    .filter(|p| p.name == import.crate_name)
+    .collect::<Vec<_>>();
+  if candidates.is_empty() {
+    return Err(Error::UnknownPermissionImport {
+      crate_name: import.crate_name.clone(),
+    });
+  }
+
+  let requirement = match &import.version {
+    Some(requirement) => {
+      VersionReq::parse(requirement).map_err(|e| Error::InvalidPermissionImportVersion {
+        crate_name: import.crate_name.clone(),
+        requirement: requirement.clone(),
+        error: e.to_string(),
+      })?
+    }
+    None => VersionReq::STAR,
+  };
+
+  candidates
+    .iter()
+    .filter(|p| requirement.matches(&p.version))
+    .max_by(|a, b| a.version.cmp(&b.version))
+    .copied()
+    .ok_or_else(|| Error::PermissionImportVersionMismatch {
+      crate_name: import.crate_name.clone(),
+      requested: requirement.to_string(),
+      found: candidates
+        .iter()
+        .map(|p| p.version.to_string())
+        .collect::<Vec<_>>()
+        .join(", "),
+    })
 }
 
 /// Read all permissions listed from the defined cargo cfg key value.
@@ -217,7 +349,7 @@ pub fn parse_capabilities(pattern: &str) -> Result<BTreeMap<String, Capability>,
           });
         }
 
-        capabilities_map.insert(capability.identifier.clone(), capability);
+        capabilities_map.insert(capability.identifier.clone(), *capability);
       }
       CapabilityFile::List(capabilities) | CapabilityFile::NamedList { capabilities } => {
         for capability in capabilities {
@@ -236,6 +368,58 @@ pub fn parse_capabilities(pattern: &str) -> Result<BTreeMap<String, Capability>,
   Ok(capabilities_map)
 }
 
+/// The Cargo features enabled on the crate being built.
+///
+/// This reads the `CARGO_FEATURE_<name>` environment variables, so it must be called inside a build script.
+/// The names are normalized with [`normalize_feature_name`].
+pub fn enabled_features() -> BTreeSet<String> {
+  env::vars_os()
+    .filter_map(|(key, _)| {
+      key
+        .to_str()?
+        .strip_prefix("CARGO_FEATURE_")
+        .map(ToString::to_string)
+    })
+    .collect()
+}
+
+/// Normalizes a Cargo feature name the way Cargo does for the `CARGO_FEATURE_<name>` environment variables.
+pub fn normalize_feature_name(feature: &str) -> String {
+  feature.to_uppercase().replace('-', "_")
+}
+
+/// Removes the capabilities that are not enabled by the given Cargo features,
+/// see [`Capability#structfield.enabled_on_features`] and [`enabled_features`].
+pub fn filter_capabilities_by_features(
+  capabilities: &mut BTreeMap<String, Capability>,
+  enabled_features: &BTreeSet<String>,
+) {
+  capabilities.retain(|_, capability| {
+    capability
+      .is_enabled_on_features(|feature| enabled_features.contains(&normalize_feature_name(feature)))
+  });
+}
+
+/// Saves the Cargo features enabled on the crate being built to the given directory,
+/// so they can be read back with [`read_enabled_features`] when generating the app context.
+pub fn save_enabled_features(out_dir: &Path) -> Result<(), Error> {
+  let path = out_dir.join(CARGO_FEATURES_FILE_NAME);
+  let json = serde_json::to_string(&enabled_features())?;
+  write_if_changed(&path, json).map_err(|e| Error::WriteFile(e, path))
+}
+
+/// Reads the Cargo features saved with [`save_enabled_features`].
+///
+/// Returns `None` if the features were not saved, for instance if the build script does not use `tauri-build`.
+pub fn read_enabled_features(out_dir: &Path) -> Result<Option<BTreeSet<String>>, Error> {
+  let path = out_dir.join(CARGO_FEATURES_FILE_NAME);
+  if !path.exists() {
+    return Ok(None);
+  }
+  let json = fs::read_to_string(&path).map_err(|e| Error::ReadFile(e, path))?;
+  serde_json::from_str(&json).map(Some).map_err(Into::into)
+}
+
 /// Permissions that are generated from commands using [`autogenerate_command_permissions`].
 pub struct AutogeneratedPermissions {
   /// The allow permissions generated from commands.
@@ -379,3 +563,172 @@ pub fn generate_docs(
 
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+
+  use super::{filter_capabilities_by_features, parse_permissions, resolve_permission_imports};
+  use crate::{
+    acl::{
+      capability::CapabilityBuilder,
+      manifest::{Manifest, PermissionFile},
+      resolved::Resolved,
+      Commands, Error, Identifier, Permission,
+    },
+    platform::Target,
+  };
+
+  #[test]
+  fn loads_description_file() {
+    let dir = std::env::temp_dir().join(format!(
+      "tauri_permission_description_file_test_{}",
+      std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("docs")).unwrap();
+
+    fs::write(
+      dir.join("docs").join("read.md"),
+      "#### Read\n\nAllows reading files.\n",
+    )
+    .unwrap();
+    let permission_file = dir.join("read.toml");
+    fs::write(
+      &permission_file,
+      r#"
+[[permission]]
+identifier = "allow-read"
+description_file = "docs/read.md"
+commands.allow = ["read"]
+"#,
+    )
+    .unwrap();
+
+    let permissions = parse_permissions(vec![permission_file.clone()]).unwrap();
+    assert_eq!(
+      permissions[0].permission[0].description.as_deref(),
+      Some("#### Read\n\nAllows reading files.")
+    );
+
+    fs::remove_file(dir.join("docs").join("read.md")).unwrap();
+    assert!(matches!(
+      parse_permissions(vec![permission_file]),
+      Err(Error::ReadFile(_, path)) if path == dir.join("docs").join("read.md")
+    ));
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn imports_permissions_from_path_dependency() {
+    let dir = std::env::temp_dir().join(format!(
+      "tauri_permission_import_test_{}",
+      std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+
+    let shared = dir.join("shared-permissions");
+    fs::create_dir_all(shared.join("src")).unwrap();
+    fs::create_dir_all(shared.join("permissions")).unwrap();
+    fs::write(
+      shared.join("Cargo.toml"),
+      "[package]\nname = \"shared-permissions\"\nversion = \"1.2.0\"\nedition = \"2021\"\n",
+    )
+    .unwrap();
+    fs::write(shared.join("src").join("lib.rs"), "").unwrap();
+    fs::write(
+      shared.join("permissions").join("fs.toml"),
+      r#"
+[[permission]]
+identifier = "allow-read-config"
+commands.allow = ["read_config"]
+"#,
+    )
+    .unwrap();
+
+    let plugin = dir.join("plugin");
+    fs::create_dir_all(plugin.join("src")).unwrap();
+    fs::write(
+      plugin.join("Cargo.toml"),
+      "[package]\nname = \"plugin\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\nshared-permissions = { path = \"../shared-permissions\" }\n",
+    )
+    .unwrap();
+    fs::write(plugin.join("src").join("lib.rs"), "").unwrap();
+    let manifest_path = plugin.join("Cargo.toml");
+
+    let importing = |import: &str| -> Vec<PermissionFile> { vec![toml::from_str(import).unwrap()] };
+
+    let paths = resolve_permission_imports(
+      &importing("[[import]]\ncrate = \"shared-permissions\"\nversion = \"^1.1\""),
+      &manifest_path,
+    )
+    .unwrap();
+    let permissions = parse_permissions(paths).unwrap();
+    assert_eq!(permissions.len(), 1);
+    assert_eq!(permissions[0].permission[0].identifier, "allow-read-config");
+
+    assert!(matches!(
+      resolve_permission_imports(
+        &importing("[[import]]\ncrate = \"shared-permissions\"\nversion = \"^2\""),
+        &manifest_path,
+      ),
+      Err(Error::PermissionImportVersionMismatch { crate_name, found, .. })
+        if crate_name == "shared-permissions" && found == "1.2.0"
+    ));
+    assert!(matches!(
+      resolve_permission_imports(
+        &importing("[[import]]\ncrate = \"unknown-permissions\""),
+        &manifest_path,
+      ),
+      Err(Error::UnknownPermissionImport { crate_name }) if crate_name == "unknown-permissions"
+    ));
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn filters_capabilities_by_features() {
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [(
+          "allow-read".to_string(),
+          Permission {
+            identifier: "allow-read".into(),
+            commands: Commands {
+              allow: vec!["read".into()],
+              deny: Vec::new(),
+            },
+            ..Default::default()
+          },
+        )]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capabilities = || {
+      let capability = CapabilityBuilder::new()
+        .identifier("devtools")
+        .window("main")
+        .permission(Identifier::try_from("fs:allow-read".to_string()).unwrap())
+        .enabled_on_feature("acl-test-devtools")
+        .build()
+        .unwrap();
+      [(capability.identifier.clone(), capability)].into()
+    };
+
+    let mut enabled = capabilities();
+    filter_capabilities_by_features(&mut enabled, &["ACL_TEST_DEVTOOLS".to_string()].into());
+    let resolved = Resolved::resolve(&acl, enabled, Target::current()).unwrap();
+    assert!(resolved.allowed_commands.contains_key("plugin:fs|read"));
+
+    let mut disabled = capabilities();
+    filter_capabilities_by_features(&mut disabled, &Default::default());
+    assert!(disabled.is_empty());
+    let resolved = Resolved::resolve(&acl, disabled, Target::current()).unwrap();
+    assert!(!resolved.allowed_commands.contains_key("plugin:fs|read"));
+  }
+}
@@ -4,7 +4,7 @@
 
 //! End-user abstraction for selecting permissions a window has access to.
 
-use std::{path::Path, str::FromStr};
+use std::{collections::BTreeMap, num::NonZeroU64, path::Path, str::FromStr};
 
 use crate::{acl::Identifier, platform::Target};
 use serde::{
@@ -13,7 +13,7 @@ use serde::{
 };
 use serde_untagged::UntaggedEnumVisitor;
 
-use super::Scopes;
+use super::{RemoteUrlPattern, Scopes};
 
 /// An entry for a permission value in a [`Capability`] can be either a raw permission [`Identifier`]
 /// or an object that references a permission and extends its scope.
@@ -27,6 +27,11 @@ pub enum PermissionEntry {
   ExtendedPermission {
     /// Identifier of the permission or permission set.
     identifier: Identifier,
+    /// The version of the permission or permission set this capability was written for.
+    ///
+    /// The ACL resolution fails if the referenced permission or permission set has a different version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<NonZeroU64>,
     /// Scope to append to the existing permission scope.
     #[serde(default, flatten)]
     scope: Scopes,
@@ -38,10 +43,15 @@ impl PermissionEntry {
   pub fn identifier(&self) -> &Identifier {
     match self {
       Self::PermissionRef(identifier) => identifier,
-      Self::ExtendedPermission {
-        identifier,
-        scope: _,
-      } => identifier,
+      Self::ExtendedPermission { identifier, .. } => identifier,
+    }
+  }
+
+  /// The version of the permission or permission set requested by this entry.
+  pub fn version(&self) -> Option<NonZeroU64> {
+    match self {
+      Self::PermissionRef(_) => None,
+      Self::ExtendedPermission { version, .. } => *version,
     }
   }
 }
@@ -54,6 +64,8 @@ impl<'de> Deserialize<'de> for PermissionEntry {
     #[derive(Deserialize)]
     struct ExtendedPermissionStruct {
       identifier: Identifier,
+      #[serde(default)]
+      version: Option<NonZeroU64>,
       #[serde(default, flatten)]
       scope: Scopes,
     }
@@ -65,8 +77,15 @@ impl<'de> Deserialize<'de> for PermissionEntry {
       })
       .map(|map| {
         let ext_perm = map.deserialize::<ExtendedPermissionStruct>()?;
+        if !ext_perm.scope.include.is_empty() {
+          return Err(serde::de::Error::custom(format!(
+            "permission `{}` includes scope fragments, which can only be included by permissions and not by capabilities",
+            ext_perm.identifier.get()
+          )));
+        }
         Ok(Self::ExtendedPermission {
           identifier: ext_perm.identifier,
+          version: ext_perm.version,
           scope: ext_perm.scope,
         })
       })
@@ -195,6 +214,38 @@ pub struct Capability {
   /// `["macOS","windows"]`
   #[serde(skip_serializing_if = "Option::is_none")]
   pub platforms: Option<Vec<Target>>,
+  /// Only include this capability in the app when one of these Cargo features of the app crate is enabled.
+  ///
+  /// Disabled capabilities are dropped by `tauri-build`, so they are not part of the compiled ACL.
+  /// By default the capability is always included.
+  ///
+  /// ## Example
+  ///
+  /// `["devtools"]`
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub enabled_on_features: Vec<String>,
+  /// Whether the permissions required by the permissions of this capability are granted automatically.
+  ///
+  /// By default, resolving the capability fails when one of its permissions requires
+  /// a permission that the capability does not grant.
+  #[serde(default, skip_serializing_if = "is_false")]
+  pub include_required_permissions: bool,
+  /// Variables substituted into the scopes of the permissions of this capability when the ACL is resolved.
+  ///
+  /// A scope value references a variable with the `{name}` placeholder.
+  /// This happens before the scope is used by a command, unlike the `$APPDATA`-like path variables resolved at runtime.
+  ///
+  /// ## Example
+  ///
+  /// ```json
+  /// { "log_dir": "$APPDATA/logs" }
+  /// ```
+  ///
+  /// With this variable, a permission scope `{ "path": "{log_dir}/*" }` allows `$APPDATA/logs/*`.
+  ///
+  /// The scopes are left untouched when no variable is defined.
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub variables: BTreeMap<String, String>,
 }
 
 impl Capability {
@@ -206,6 +257,250 @@ impl Capability {
       .map(|platforms| platforms.contains(target))
       .unwrap_or(true)
   }
+
+  /// Checks the window and webview patterns of this capability against the known labels,
+  /// returning a warning message for each pattern that likely targets more than intended.
+  ///
+  /// `labels` maps each known window label to the labels of the child webviews it hosts.
+  ///
+  /// Currently reports:
+  ///
+  /// - glob patterns matching every known label, such as a bare `*`, when there are several labels.
+  /// - window glob patterns matching windows that host child webviews when [`Self::webviews`] is empty,
+  ///   since all of these webviews are then granted the permissions.
+  pub fn lint_targets(&self, labels: &BTreeMap<String, Vec<String>>) -> Vec<String> {
+    let windows = labels.keys().map(String::as_str).collect::<Vec<_>>();
+    let webviews = labels
+      .iter()
+      .flat_map(|(window, webviews)| webviews.iter().filter(move |webview| *webview != window))
+      .map(String::as_str)
+      .collect::<Vec<_>>();
+
+    let mut warnings = Vec::new();
+
+    for (kind, patterns, labels) in [
+      ("window", &self.windows, &windows),
+      ("webview", &self.webviews, &webviews),
+    ] {
+      for pattern in patterns.iter().filter(|p| is_glob(p)) {
+        let matched = matching_labels(pattern, labels.iter().copied());
+        if matched.len() > 1 && matched.len() == labels.len() {
+          warnings.push(format!(
+            "{kind} pattern `{pattern}` of capability `{}` matches all the {kind}s ({}); list the intended {kind} labels instead",
+            self.identifier,
+            matched.join(", ")
+          ));
+        }
+      }
+    }
+
+    if self.webviews.is_empty() {
+      for pattern in self.windows.iter().filter(|p| is_glob(p)) {
+        let children = matching_labels(pattern, windows.iter().copied())
+          .into_iter()
+          .flat_map(|window| {
+            labels[window]
+              .iter()
+              .filter(move |webview| *webview != window)
+              .map(String::as_str)
+          })
+          .collect::<Vec<_>>();
+        if !children.is_empty() {
+          warnings.push(format!(
+            "window pattern `{pattern}` of capability `{}` also grants its permissions to the child webviews {}; set `webviews` to target specific webviews",
+            self.identifier,
+            children.join(", ")
+          ));
+        }
+      }
+    }
+
+    warnings
+  }
+
+  /// Whether this capability should be included based on the enabled Cargo features or not,
+  /// see [`Self::enabled_on_features`].
+  pub fn is_enabled_on_features<F: Fn(&str) -> bool>(&self, is_feature_enabled: F) -> bool {
+    self.enabled_on_features.is_empty()
+      || self
+        .enabled_on_features
+        .iter()
+        .any(|feature| is_feature_enabled(feature))
+  }
+}
+
+fn is_glob(pattern: &str) -> bool {
+  pattern.contains(['*', '?', '['])
+}
+
+fn matching_labels<'a>(pattern: &str, labels: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+  match glob::Pattern::new(pattern) {
+    Ok(pattern) => labels.filter(|label| pattern.matches(label)).collect(),
+    Err(_) => Vec::new(),
+  }
+}
+
+/// A builder for a [`Capability`], useful to generate capabilities programmatically in build scripts.
+///
+/// ## Example
+///
+/// ```
+/// use tauri_utils::{
+///   acl::{capability::CapabilityBuilder, Identifier},
+///   platform::Target,
+/// };
+///
+/// let capability = CapabilityBuilder::new()
+///   .identifier("main-capability")
+///   .window("main")
+///   .permission(Identifier::try_from("core:default".to_string()).unwrap())
+///   .platform(Target::MacOS)
+///   .build()
+///   .unwrap();
+/// assert_eq!(capability.windows, vec!["main"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CapabilityBuilder {
+  identifier: Option<String>,
+  capability: Capability,
+}
+
+impl Default for CapabilityBuilder {
+  fn default() -> Self {
+    Self {
+      identifier: None,
+      capability: Capability {
+        identifier: String::new(),
+        description: String::new(),
+        remote: None,
+        local: default_capability_local(),
+        windows: Vec::new(),
+        webviews: Vec::new(),
+        permissions: Vec::new(),
+        platforms: None,
+        enabled_on_features: Vec::new(),
+        include_required_permissions: false,
+        variables: BTreeMap::new(),
+      },
+    }
+  }
+}
+
+impl CapabilityBuilder {
+  /// Creates a new capability builder.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the identifier of the capability. Required.
+  #[must_use]
+  pub fn identifier(mut self, identifier: impl Into<String>) -> Self {
+    self.identifier.replace(identifier.into());
+    self
+  }
+
+  /// Sets the description of the capability.
+  #[must_use]
+  pub fn description(mut self, description: impl Into<String>) -> Self {
+    self.capability.description = description.into();
+    self
+  }
+
+  /// Allows the capability to be used by URLs matching the given pattern.
+  #[must_use]
+  pub fn remote(mut self, url: RemoteUrlPattern) -> Self {
+    self
+      .capability
+      .remote
+      .get_or_insert_with(Default::default)
+      .urls
+      .push(url.as_str().to_string());
+    self
+  }
+
+  /// Whether the capability is applied on local app URLs or not. Defaults to `true`.
+  #[must_use]
+  pub fn local(mut self, local: bool) -> Self {
+    self.capability.local = local;
+    self
+  }
+
+  /// Links the capability to the given window label or glob pattern.
+  #[must_use]
+  pub fn window(mut self, window: impl Into<String>) -> Self {
+    self.capability.windows.push(window.into());
+    self
+  }
+
+  /// Links the capability to the given webview label or glob pattern.
+  #[must_use]
+  pub fn webview(mut self, webview: impl Into<String>) -> Self {
+    self.capability.webviews.push(webview.into());
+    self
+  }
+
+  /// Adds a permission to the capability.
+  #[must_use]
+  pub fn permission(mut self, permission: impl Into<PermissionEntry>) -> Self {
+    self.capability.permissions.push(permission.into());
+    self
+  }
+
+  /// Adds a target platform for the capability.
+  ///
+  /// By default all platforms are targeted.
+  #[must_use]
+  pub fn platform(mut self, platform: Target) -> Self {
+    self
+      .capability
+      .platforms
+      .get_or_insert_with(Default::default)
+      .push(platform);
+    self
+  }
+
+  /// Only includes the capability when the given Cargo feature is enabled.
+  ///
+  /// By default the capability is always included.
+  #[must_use]
+  pub fn enabled_on_feature(mut self, feature: impl Into<String>) -> Self {
+    self.capability.enabled_on_features.push(feature.into());
+    self
+  }
+
+  /// Grants the permissions required by the permissions of this capability automatically,
+  /// see [`Capability::include_required_permissions`].
+  #[must_use]
+  pub fn include_required_permissions(mut self, include: bool) -> Self {
+    self.capability.include_required_permissions = include;
+    self
+  }
+
+  /// Defines a variable substituted into the permission scopes, see [`Capability::variables`].
+  #[must_use]
+  pub fn variable(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.capability.variables.insert(name.into(), value.into());
+    self
+  }
+
+  /// Builds the capability.
+  ///
+  /// Returns [`super::Error::MissingCapabilityIdentifier`] if [`Self::identifier`] was not called.
+  pub fn build(self) -> Result<Capability, super::Error> {
+    let identifier = self
+      .identifier
+      .ok_or(super::Error::MissingCapabilityIdentifier)?;
+    Ok(Capability {
+      identifier,
+      ..self.capability
+    })
+  }
+}
+
+impl From<Identifier> for PermissionEntry {
+  fn from(identifier: Identifier) -> Self {
+    Self::PermissionRef(identifier)
+  }
 }
 
 #[cfg(feature = "schema")]
@@ -227,6 +522,10 @@ fn default_capability_local() -> bool {
   true
 }
 
+fn is_false(value: &bool) -> bool {
+  !value
+}
+
 /// Configuration for remote URLs that are associated with the capability.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -239,6 +538,34 @@ pub struct CapabilityRemote {
   /// - "https://*.mydomain.dev": allows subdomains of mydomain.dev
   /// - "https://mydomain.dev/api/*": allows any subpath of mydomain.dev/api
   pub urls: Vec<String>,
+  /// Remote domains that can be added to this capability at runtime using the [URLPattern standard](https://urlpattern.spec.whatwg.org/).
+  ///
+  /// These patterns are not allowed by default, they only bound the URLs the app is allowed to add
+  /// after startup, for instance when the allowed origins are fetched from a configuration server.
+  ///
+  /// ## Examples
+  ///
+  /// - "https://*.mydomain.dev": any subdomain of mydomain.dev can be added at runtime
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub runtime_urls: Vec<String>,
+}
+
+impl CapabilityRemote {
+  /// Whether the given remote URL pattern is within the [`Self::runtime_urls`] patterns.
+  ///
+  /// The pattern is compared by its URL form, so `https://*.mydomain.dev` is only within
+  /// a pattern that also matches `*` as a subdomain of `mydomain.dev`.
+  pub fn allows_runtime_url(&self, url: &RemoteUrlPattern) -> bool {
+    let Ok(url) = url::Url::parse(url.as_str()) else {
+      return false;
+    };
+    self.runtime_urls.iter().any(|pattern| {
+      pattern
+        .parse::<RemoteUrlPattern>()
+        .map(|pattern| pattern.test(&url))
+        .unwrap_or_default()
+    })
+  }
 }
 
 /// Capability formats accepted in a capability file.
@@ -247,7 +574,7 @@ pub struct CapabilityRemote {
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub enum CapabilityFile {
   /// A single capability.
-  Capability(Capability),
+  Capability(Box<Capability>),
   /// A list of capabilities.
   List(Vec<Capability>),
   /// A list of capabilities.
@@ -297,7 +624,7 @@ impl<'de> Deserialize<'de> for CapabilityFile {
             .map_err(|e| serde_untagged::de::Error::custom(e.to_string()))
         } else {
           serde_json::from_value::<Capability>(value.into())
-            .map(Self::Capability)
+            .map(|capability| Self::Capability(Box::new(capability)))
             .map_err(|e| serde_untagged::de::Error::custom(e.to_string()))
         }
       })
@@ -328,10 +655,12 @@ mod build {
   impl ToTokens for CapabilityRemote {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let urls = vec_lit(&self.urls, str_lit);
+      let runtime_urls = vec_lit(&self.runtime_urls, str_lit);
       literal_struct!(
         tokens,
         ::tauri::utils::acl::capability::CapabilityRemote,
-        urls
+        urls,
+        runtime_urls
       );
     }
   }
@@ -344,9 +673,18 @@ mod build {
         Self::PermissionRef(id) => {
           quote! { #prefix::PermissionRef(#id) }
         }
-        Self::ExtendedPermission { identifier, scope } => {
+        Self::ExtendedPermission {
+          identifier,
+          version,
+          scope,
+        } => {
+          let version = opt_lit_owned(version.as_ref().map(|v| {
+            let v = v.get();
+            quote!(::core::num::NonZeroU64::new(#v).unwrap())
+          }));
           quote! { #prefix::ExtendedPermission {
             identifier: #identifier,
+            version: #version,
             scope: #scope
           } }
         }
@@ -364,6 +702,14 @@ mod build {
       let webviews = vec_lit(&self.webviews, str_lit);
       let permissions = vec_lit(&self.permissions, identity);
       let platforms = opt_vec_lit(self.platforms.as_ref(), identity);
+      let enabled_on_features = vec_lit(&self.enabled_on_features, str_lit);
+      let include_required_permissions = self.include_required_permissions;
+      let variables = map_lit(
+        quote! { ::std::collections::BTreeMap },
+        &self.variables,
+        str_lit,
+        str_lit,
+      );
 
       literal_struct!(
         tokens,
@@ -375,7 +721,10 @@ mod build {
         windows,
         webviews,
         permissions,
-        platforms
+        platforms,
+        enabled_on_features,
+        include_required_permissions,
+        variables
       );
     }
   }
@@ -383,9 +732,11 @@ mod build {
 
 #[cfg(test)]
 mod tests {
+  use std::num::NonZeroU64;
+
   use crate::acl::{Identifier, Scopes};
 
-  use super::{Capability, CapabilityFile, PermissionEntry};
+  use super::{Capability, CapabilityBuilder, CapabilityFile, CapabilityRemote, PermissionEntry};
 
   #[test]
   fn permission_entry_de() {
@@ -404,13 +755,37 @@ mod tests {
       }))
       .unwrap(),
       PermissionEntry::ExtendedPermission {
-        identifier,
+        identifier: identifier.clone(),
+        version: None,
         scope: Scopes {
           allow: Some(vec![]),
-          deny: None
+          deny: None,
+          include: Vec::new(),
         }
       }
     );
+
+    assert_eq!(
+      serde_json::from_value::<PermissionEntry>(serde_json::json!({
+        "identifier": identifier,
+        "version": 2
+      }))
+      .unwrap(),
+      PermissionEntry::ExtendedPermission {
+        identifier,
+        version: NonZeroU64::new(2),
+        scope: Scopes::default()
+      }
+    );
+
+    // scope fragments are only expanded in permission files
+    assert!(
+      serde_json::from_value::<PermissionEntry>(serde_json::json!({
+        "identifier": "plugin:perm",
+        "include": ["home-dir"]
+      }))
+      .is_err()
+    );
   }
 
   #[test]
@@ -424,12 +799,15 @@ mod tests {
       webviews: vec![],
       permissions: vec![],
       platforms: None,
+      enabled_on_features: vec![],
+      include_required_permissions: false,
+      variables: Default::default(),
     };
     let capability_json = serde_json::to_string(&capability).unwrap();
 
     assert_eq!(
       serde_json::from_str::<CapabilityFile>(&capability_json).unwrap(),
-      CapabilityFile::Capability(capability.clone())
+      CapabilityFile::Capability(Box::new(capability.clone()))
     );
 
     assert_eq!(
@@ -447,4 +825,96 @@ mod tests {
       }
     );
   }
+
+  #[test]
+  fn capability_builder_round_trip() {
+    let capability = CapabilityBuilder::new()
+      .identifier("main")
+      .description("main window capability")
+      .window("main")
+      .remote("https://*.tauri.app".parse().unwrap())
+      .permission(Identifier::try_from("core:default".to_string()).unwrap())
+      .platform(crate::platform::Target::Linux)
+      .build()
+      .unwrap();
+
+    let json = serde_json::json!({
+      "identifier": "main",
+      "description": "main window capability",
+      "remote": { "urls": ["https://*.tauri.app"] },
+      "local": true,
+      "windows": ["main"],
+      "permissions": ["core:default"],
+      "platforms": ["linux"]
+    });
+
+    assert_eq!(serde_json::to_value(&capability).unwrap(), json);
+    assert_eq!(
+      serde_json::from_value::<Capability>(json).unwrap(),
+      capability
+    );
+
+    assert!(matches!(
+      CapabilityBuilder::new().window("main").build(),
+      Err(crate::acl::Error::MissingCapabilityIdentifier)
+    ));
+  }
+
+  #[test]
+  fn capability_remote_runtime_urls() {
+    let remote = CapabilityRemote {
+      urls: Vec::new(),
+      runtime_urls: vec!["https://*.tauri.app".into()],
+    };
+    let allows = |url: &str| remote.allows_runtime_url(&url.parse().unwrap());
+
+    assert!(allows("https://acme.tauri.app"));
+    assert!(allows("https://*.tauri.app"));
+    assert!(!allows("https://tauri.app.evil.com"));
+    assert!(!allows("http://acme.tauri.app"));
+    assert!(!allows("https://*"));
+  }
+
+  #[test]
+  fn lint_broad_target_patterns() {
+    // the multiwebview example: window `main` hosting the `menu` and `content` webviews
+    let labels = [
+      (
+        "main".to_string(),
+        vec!["menu".to_string(), "content".to_string()],
+      ),
+      ("settings".to_string(), vec!["settings".to_string()]),
+    ]
+    .into();
+    let capability = |windows: &[&str], webviews: &[&str]| {
+      let mut builder = CapabilityBuilder::new()
+        .identifier("main")
+        .permission(Identifier::try_from("core:default".to_string()).unwrap());
+      for window in windows {
+        builder = builder.window(*window);
+      }
+      for webview in webviews {
+        builder = builder.webview(*webview);
+      }
+      builder.build().unwrap()
+    };
+
+    assert_eq!(
+      capability(&["*"], &[]).lint_targets(&labels),
+      vec![
+        "window pattern `*` of capability `main` matches all the windows (main, settings); list the intended window labels instead".to_string(),
+        "window pattern `*` of capability `main` also grants its permissions to the child webviews menu, content; set `webviews` to target specific webviews".to_string(),
+      ]
+    );
+    assert_eq!(
+      capability(&["main"], &["*"]).lint_targets(&labels),
+      vec!["webview pattern `*` of capability `main` matches all the webviews (menu, content); list the intended webview labels instead".to_string()]
+    );
+
+    assert!(capability(&["main"], &[]).lint_targets(&labels).is_empty());
+    assert!(capability(&["set*"], &[]).lint_targets(&labels).is_empty());
+    assert!(capability(&["m*"], &["cont*"])
+      .lint_targets(&labels)
+      .is_empty());
+  }
 }
@@ -4,9 +4,16 @@
 
 //! Plugin ACL types.
 
-use std::{collections::BTreeMap, num::NonZeroU64};
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  num::NonZeroU64,
+  path::{Path, PathBuf},
+};
 
-use super::{Permission, PermissionSet};
+use super::{
+  Error, Permission, PermissionSet, Scopes, ALL_COMMANDS, PERMISSION_FILE_EXTENSIONS,
+  PERMISSION_SCHEMAS_FOLDER_NAME, UNCATEGORIZED_PERMISSION_CATEGORY,
+};
 #[cfg(feature = "schema")]
 use schemars::schema::*;
 use serde::{Deserialize, Serialize};
@@ -26,9 +33,41 @@ pub struct DefaultPermission {
   pub description: Option<String>,
 
   /// All permissions this set contains.
+  ///
+  /// Permission sets can also be referenced and are expanded to their permissions.
   pub permissions: Vec<String>,
 }
 
+/// The permissions of another crate to include in the plugin permissions.
+///
+/// The crate must be a dependency of the plugin, it is resolved with `cargo metadata` at build time.
+///
+/// ## Example
+///
+/// ```toml
+/// [[import]]
+/// crate = "acme-permissions"
+/// version = "^1.2"
+/// path = "permissions/fs"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PermissionImport {
+  /// The name of the crate to import the permissions from.
+  #[serde(rename = "crate")]
+  pub crate_name: String,
+
+  /// The version requirement of the crate, e.g. `^1.2`.
+  ///
+  /// Resolving the import fails if the crate version in the dependency tree does not match it.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+
+  /// The folder of the permission files, relative to the crate root. Defaults to `permissions`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub path: Option<PathBuf>,
+}
+
 /// Permission file that can define a default permission, a set of permissions or a list of inlined permissions.
 #[derive(Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -43,6 +82,75 @@ pub struct PermissionFile {
   /// A list of inlined permissions
   #[serde(default)]
   pub permission: Vec<Permission>,
+
+  /// The permissions of other crates to include, see [`PermissionImport`].
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub import: Vec<PermissionImport>,
+
+  /// Named scopes that the permissions of this file can add to their scope with [`Scopes#structfield.include`],
+  /// to share scope values between permissions.
+  ///
+  /// Fragments cannot include other fragments.
+  ///
+  /// ## Example
+  ///
+  /// ```toml
+  /// [scope_fragments.home-dir]
+  /// allow = [{ path = "$HOME" }, { path = "$HOME/**" }]
+  ///
+  /// [[permission]]
+  /// identifier = "allow-read-home"
+  /// commands.allow = ["read"]
+  /// scope.include = ["home-dir"]
+  /// ```
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub scope_fragments: BTreeMap<String, Scopes>,
+}
+
+impl PermissionFile {
+  /// Load the given permission file, reading the permission descriptions from their
+  /// [`Permission#structfield.description_file`].
+  ///
+  /// The permission identifiers are not validated, see [`Permission::validate_identifier`].
+  pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+    let path = path.as_ref();
+    let permission_file =
+      std::fs::read_to_string(path).map_err(|e| Error::ReadFile(e, path.into()))?;
+    let ext = path.extension().unwrap().to_string_lossy().to_string();
+    let mut file: Self = match ext.as_str() {
+      "toml" => toml::from_str(&permission_file)?,
+      "json" => serde_json::from_str(&permission_file)?,
+      #[cfg(feature = "config-json5")]
+      "json5" => json5::from_str(&permission_file)?,
+      _ => return Err(Error::UnknownPermissionFormat(ext)),
+    };
+    for p in &mut file.permission {
+      if let Some(description_file) = &p.description_file {
+        let description_path = path.parent().unwrap().join(description_file);
+        let description = std::fs::read_to_string(&description_path)
+          .map_err(|e| Error::ReadFile(e, description_path))?;
+        p.description.replace(description.trim().to_string());
+      }
+    }
+    Ok(file)
+  }
+
+  /// Load the permission files in the given directory and its subdirectories, skipping the schemas folder.
+  pub fn load_dir<P: AsRef<Path>>(dir: P) -> Result<Vec<Self>, Error> {
+    let mut paths = glob::glob(&dir.as_ref().join("**").join("*").to_string_lossy())?
+      .flatten()
+      .filter(|p| {
+        p.extension()
+          .and_then(|e| e.to_str())
+          .map(|e| PERMISSION_FILE_EXTENSIONS.contains(&e))
+          .unwrap_or_default()
+      })
+      .filter(|p| p.parent().unwrap().file_name().unwrap() != PERMISSION_SCHEMAS_FOLDER_NAME)
+      .collect::<Vec<PathBuf>>();
+    paths.sort();
+
+    paths.iter().map(Self::load).collect()
+  }
 }
 
 /// Plugin manifest.
@@ -60,10 +168,19 @@ pub struct Manifest {
 
 impl Manifest {
   /// Creates a new manifest from the given plugin permission files and global scope schema.
+  ///
+  /// The permission identifiers are validated, see [`Permission::validate_identifier`].
+  /// With the `schema` feature, the global scope schema is compiled to make sure it is a valid JSON Schema.
   pub fn new(
     permission_files: Vec<PermissionFile>,
     global_scope_schema: Option<serde_json::Value>,
-  ) -> Self {
+  ) -> Result<Self, Error> {
+    #[cfg(feature = "schema")]
+    if let Some(schema) = &global_scope_schema {
+      jsonschema::JSONSchema::compile(schema)
+        .map_err(|e| Error::InvalidGlobalScopeSchema(e.to_string()))?;
+    }
+
     let mut manifest = Self {
       default_permission: None,
       permissions: BTreeMap::new(),
@@ -74,6 +191,7 @@ impl Manifest {
     for permission_file in permission_files {
       if let Some(default) = permission_file.default {
         manifest.default_permission.replace(PermissionSet {
+          version: default.version,
           identifier: "default".into(),
           description: default
             .description
@@ -82,7 +200,9 @@ impl Manifest {
         });
       }
 
-      for permission in permission_file.permission {
+      for mut permission in permission_file.permission {
+        permission.validate_identifier()?;
+        include_scope_fragments(&mut permission, &permission_file.scope_fragments)?;
         let key = permission.identifier.clone();
         manifest.permissions.insert(key, permission);
       }
@@ -93,7 +213,210 @@ impl Manifest {
       }
     }
 
-    manifest
+    let inherited = manifest
+      .permissions
+      .values()
+      .filter(|p| p.inherits.is_some())
+      .map(|p| {
+        manifest
+          .inherited_permission(&p.identifier, &mut Vec::new())
+          .map(|permission| (p.identifier.clone(), permission))
+      })
+      .collect::<Result<Vec<_>, Error>>()?;
+    manifest.permissions.extend(inherited);
+
+    Ok(manifest)
+  }
+
+  /// The commands of the plugin, which are the commands allowed or denied by any of its permissions.
+  ///
+  /// This is the list of commands allowed by [`ALL_COMMANDS`].
+  pub fn commands(&self) -> BTreeSet<&str> {
+    self
+      .permissions
+      .values()
+      .flat_map(|p| p.commands.allow.iter().chain(&p.commands.deny))
+      .map(String::as_str)
+      .filter(|command| *command != ALL_COMMANDS)
+      .collect()
+  }
+
+  /// The permissions grouped by their [`Permission#structfield.category`].
+  ///
+  /// Permissions without a category are grouped under [`UNCATEGORIZED_PERMISSION_CATEGORY`].
+  pub fn permissions_by_category(&self) -> BTreeMap<&str, Vec<&Permission>> {
+    let mut categories = BTreeMap::<&str, Vec<&Permission>>::new();
+    for permission in self.permissions.values() {
+      categories
+        .entry(
+          permission
+            .category
+            .as_deref()
+            .unwrap_or(UNCATEGORIZED_PERMISSION_CATEGORY),
+        )
+        .or_default()
+        .push(permission);
+    }
+    categories
+  }
+
+  /// The given permission merged with its base permissions, see [`Permission#structfield.inherits`].
+  ///
+  /// `chain` holds the identifiers of the permissions inheriting from this one, to detect cycles.
+  fn inherited_permission(
+    &self,
+    identifier: &str,
+    chain: &mut Vec<String>,
+  ) -> Result<Permission, Error> {
+    let permission = &self.permissions[identifier];
+    let Some(base) = &permission.inherits else {
+      return Ok(permission.extend(&Permission::default()));
+    };
+
+    chain.push(identifier.to_string());
+    if chain.contains(base) {
+      chain.push(base.clone());
+      return Err(Error::PermissionInheritanceCycle {
+        cycle: chain.join(" -> "),
+      });
+    }
+    if !self.permissions.contains_key(base) {
+      return Err(Error::UnknownBasePermission {
+        permission: identifier.to_string(),
+        base: base.clone(),
+      });
+    }
+
+    let base = self.inherited_permission(base, chain)?;
+    Ok(permission.extend(&base))
+  }
+
+  /// Checks the permissions and permission sets of this manifest for mistakes.
+  ///
+  /// Invalid identifiers and set members that do not resolve to a permission or permission set are errors,
+  /// while overlapping allow and deny commands, permissions that grant nothing
+  /// and permissions that are not part of any set are warnings.
+  /// Set members referencing other plugins (`plugin-name:permission-name`) are not checked.
+  pub fn lint(&self) -> ManifestLints {
+    let mut lints = ManifestLints::default();
+
+    for permission in self.permissions.values() {
+      if let Err(e) = permission.validate_identifier() {
+        lints.errors.push(e.to_string());
+      }
+      lints.warnings.extend(permission.lint());
+      if permission.commands.allow.is_empty()
+        && permission.commands.deny.is_empty()
+        && permission.scope.is_empty()
+      {
+        lints.warnings.push(format!(
+          "permission `{}` has no commands and no scope, so it grants nothing",
+          permission.identifier
+        ));
+      }
+    }
+
+    let sets = self
+      .default_permission
+      .iter()
+      .chain(self.permission_sets.values());
+    let mut referenced = Vec::new();
+    for set in sets {
+      for member in &set.permissions {
+        if member.contains(':') {
+          continue;
+        }
+        referenced.push(member.as_str());
+        if member != "default"
+          && !self.permissions.contains_key(member)
+          && !self.permission_sets.contains_key(member)
+        {
+          lints.errors.push(format!(
+            "permission set `{}` references `{member}`, which is not a permission or permission set of this manifest",
+            set.identifier
+          ));
+        }
+      }
+    }
+
+    for permission in self.permissions.values() {
+      if !referenced.contains(&permission.identifier.as_str()) && !is_command_permission(permission)
+      {
+        lints.warnings.push(format!(
+          "permission `{}` is not part of the default permission or any permission set",
+          permission.identifier
+        ));
+      }
+    }
+
+    lints
+  }
+
+  /// Like [`Self::lint`], but also reports the permissions without a [`Permission#structfield.version`] as errors.
+  ///
+  /// Meant for published plugins, as apps can only check the compatibility of versioned permissions.
+  /// The default permission is a permission set, so it does not need a version.
+  /// The `allow-<command>` and `deny-<command>` permissions autogenerated from the plugin commands are not versioned either.
+  pub fn lint_strict(&self) -> ManifestLints {
+    let mut lints = self.lint();
+    for permission in self.permissions.values() {
+      if permission.version.is_none() && !is_command_permission(permission) {
+        lints.errors.push(format!(
+          "permission `{}` has no version, which is required to publish the plugin",
+          permission.identifier
+        ));
+      }
+    }
+    lints
+  }
+}
+
+/// Adds the scope fragments included by the permission to its scope, see [`PermissionFile#structfield.scope_fragments`].
+fn include_scope_fragments(
+  permission: &mut Permission,
+  fragments: &BTreeMap<String, Scopes>,
+) -> Result<(), Error> {
+  for name in std::mem::take(&mut permission.scope.include) {
+    let fragment = fragments
+      .get(&name)
+      .ok_or_else(|| Error::UnknownScopeFragment {
+        permission: permission.identifier.clone(),
+        fragment: name.clone(),
+      })?;
+    for (scope, fragment_scope) in [
+      (&mut permission.scope.allow, &fragment.allow),
+      (&mut permission.scope.deny, &fragment.deny),
+    ] {
+      if let Some(values) = fragment_scope {
+        scope
+          .get_or_insert_with(Default::default)
+          .extend(values.iter().cloned());
+      }
+    }
+  }
+  Ok(())
+}
+
+/// The result of [`Manifest::lint`] and [`Manifest::lint_strict`].
+#[derive(Debug, Default)]
+pub struct ManifestLints {
+  /// Mistakes that make the manifest unusable.
+  pub errors: Vec<String>,
+  /// Likely mistakes.
+  pub warnings: Vec<String>,
+}
+
+/// Whether the permission allows or denies a single command, like the ones generated from the plugin commands.
+///
+/// These are meant to be referenced by capabilities directly, so they are not expected to be part of a set.
+fn is_command_permission(permission: &Permission) -> bool {
+  match (
+    permission.commands.allow.as_slice(),
+    permission.commands.deny.as_slice(),
+  ) {
+    ([command], []) => permission.identifier == format!("allow-{}", command.replace('_', "-")),
+    ([], [command]) => permission.identifier == format!("deny-{}", command.replace('_', "-")),
+    _ => false,
   }
 }
 
@@ -185,3 +508,397 @@ mod build {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{Error, Manifest, PermissionFile};
+
+  #[test]
+  fn includes_scope_fragments() {
+    let file = |contents: &str| toml::from_str::<PermissionFile>(contents).unwrap();
+
+    let manifest = Manifest::new(
+      vec![file(
+        r#"
+[scope_fragments.home-dir]
+allow = [{ path = "$HOME" }, { path = "$HOME/**" }]
+deny = [{ path = "$HOME/.ssh/**" }]
+
+[[permission]]
+identifier = "allow-read-home"
+commands.allow = ["read"]
+scope.include = ["home-dir"]
+
+[[permission]]
+identifier = "allow-write-home-docs"
+commands.allow = ["write"]
+scope.allow = [{ path = "$DOCUMENT/**" }]
+scope.include = ["home-dir"]
+"#,
+      )],
+      None,
+    )
+    .unwrap();
+
+    let read = &manifest.permissions["allow-read-home"].scope;
+    assert_eq!(
+      read.allow,
+      Some(vec![
+        serde_json::json!({ "path": "$HOME" }).into(),
+        serde_json::json!({ "path": "$HOME/**" }).into(),
+      ])
+    );
+    assert_eq!(
+      read.deny,
+      Some(vec![serde_json::json!({ "path": "$HOME/.ssh/**" }).into()])
+    );
+    assert!(read.include.is_empty());
+
+    let write = &manifest.permissions["allow-write-home-docs"].scope;
+    assert_eq!(
+      write.allow,
+      Some(vec![
+        serde_json::json!({ "path": "$DOCUMENT/**" }).into(),
+        serde_json::json!({ "path": "$HOME" }).into(),
+        serde_json::json!({ "path": "$HOME/**" }).into(),
+      ])
+    );
+
+    // fragments are local to their permission file
+    let err = Manifest::new(
+      vec![
+        file(
+          r#"
+[scope_fragments.home-dir]
+allow = [{ path = "$HOME/**" }]
+"#,
+        ),
+        file(
+          r#"
+[[permission]]
+identifier = "allow-read-home"
+commands.allow = ["read"]
+scope.include = ["home-dir"]
+"#,
+        ),
+      ],
+      None,
+    )
+    .unwrap_err();
+    assert!(matches!(
+      err,
+      Error::UnknownScopeFragment { ref permission, ref fragment }
+        if permission == "allow-read-home" && fragment == "home-dir"
+    ));
+  }
+
+  #[test]
+  fn rejects_invalid_permission_identifiers() {
+    let file = toml::from_str::<PermissionFile>(
+      r#"
+[[permission]]
+identifier = "Invalid"
+commands.allow = ["invalid"]
+"#,
+    )
+    .unwrap();
+
+    assert!(matches!(
+      Manifest::new(vec![file], None),
+      Err(Error::InvalidPermissionIdentifier { ref identifier, .. }) if identifier == "Invalid"
+    ));
+  }
+
+  #[test]
+  fn lints_unversioned_permissions_in_strict_mode() {
+    use crate::acl::{Commands, Permission};
+
+    let permission = |identifier: &str, command: &str, version: Option<u64>| {
+      (
+        identifier.to_string(),
+        Permission {
+          version: version.and_then(std::num::NonZeroU64::new),
+          identifier: identifier.to_string(),
+          commands: Commands {
+            allow: vec![command.to_string()],
+            deny: Vec::new(),
+          },
+          ..Default::default()
+        },
+      )
+    };
+
+    let mut manifest = Manifest {
+      permissions: [
+        permission("read-files", "read", Some(1)),
+        permission("write-files", "write", None),
+        // autogenerated command permissions are never versioned
+        permission("allow-read-file", "read_file", None),
+      ]
+      .into(),
+      ..Default::default()
+    };
+    assert!(manifest.lint().errors.is_empty());
+    assert_eq!(
+      manifest.lint_strict().errors,
+      vec!["permission `write-files` has no version, which is required to publish the plugin"]
+    );
+
+    manifest
+      .permissions
+      .extend([permission("write-files", "write", Some(2))]);
+    assert!(manifest.lint_strict().errors.is_empty());
+  }
+
+  #[test]
+  fn lints_manifest() {
+    let dir =
+      std::env::temp_dir().join(format!("tauri_permission_lint_test_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("schemas")).unwrap();
+    std::fs::write(dir.join("schemas").join("schema.json"), "{}").unwrap();
+    std::fs::write(
+      dir.join("fs.toml"),
+      r#"
+[default]
+permissions = ["read-files", "missing", "other:default"]
+
+[[set]]
+identifier = "read-files"
+description = "read files"
+permissions = ["allow-read", "allow-exists"]
+
+[[permission]]
+identifier = "allow-read"
+commands.allow = ["read"]
+
+[[permission]]
+identifier = "allow-exists"
+commands.allow = ["exists"]
+commands.deny = ["exists"]
+
+[[permission]]
+identifier = "allow-read-write"
+commands.allow = ["read", "write"]
+
+[[permission]]
+identifier = "allow-nothing"
+
+[[permission]]
+identifier = "allow-write"
+commands.allow = ["write"]
+"#,
+    )
+    .unwrap();
+
+    let files = PermissionFile::load_dir(&dir).unwrap();
+    let _ = std::fs::remove_dir_all(&dir);
+    assert_eq!(files.len(), 1);
+
+    let lints = Manifest::new(files, None).unwrap().lint();
+
+    assert_eq!(lints.errors.len(), 1, "{:?}", lints.errors);
+    assert!(lints.errors[0].contains("references `missing`"));
+
+    assert!(lints
+      .warnings
+      .iter()
+      .any(|w| w.contains("command `exists` is in both allow and deny")));
+    assert!(lints.warnings.contains(
+      &"permission `allow-nothing` has no commands and no scope, so it grants nothing".into()
+    ));
+    assert!(lints.warnings.contains(
+      &"permission `allow-read-write` is not part of the default permission or any permission set"
+        .into()
+    ));
+    // single command permissions are meant to be used directly
+    assert!(!lints.warnings.iter().any(|w| w.contains("`allow-write`")));
+  }
+
+  #[test]
+  fn resolves_inherited_permissions() {
+    let file: PermissionFile = toml::from_str(
+      r#"
+[[permission]]
+identifier = "allow-read-base"
+commands.allow = ["read", "stat"]
+scope.allow = [{ path = "$APPDATA" }]
+
+[[permission]]
+identifier = "allow-read"
+inherits = "allow-read-base"
+commands.allow = ["exists", "read"]
+
+[[permission]]
+identifier = "allow-read-all"
+inherits = "allow-read"
+scope.allow = [{ path = "$HOME" }]
+"#,
+    )
+    .unwrap();
+
+    let manifest = Manifest::new(vec![file], None).unwrap();
+
+    let read = &manifest.permissions["allow-read"];
+    assert_eq!(read.commands.allow, vec!["read", "stat", "exists"]);
+    assert_eq!(read.scope.allow.as_ref().unwrap().len(), 1);
+    assert_eq!(read.inherits.as_deref(), Some("allow-read-base"));
+
+    let read_all = &manifest.permissions["allow-read-all"];
+    assert_eq!(read_all.commands.allow, vec!["read", "stat", "exists"]);
+    assert_eq!(read_all.scope.allow.as_ref().unwrap().len(), 2);
+
+    let cycle: PermissionFile = toml::from_str(
+      r#"
+[[permission]]
+identifier = "a"
+inherits = "b"
+
+[[permission]]
+identifier = "b"
+inherits = "a"
+"#,
+    )
+    .unwrap();
+    assert!(matches!(
+      Manifest::new(vec![cycle], None),
+      Err(Error::PermissionInheritanceCycle { .. })
+    ));
+
+    let unknown: PermissionFile = toml::from_str(
+      r#"
+[[permission]]
+identifier = "a"
+inherits = "missing"
+"#,
+    )
+    .unwrap();
+    assert!(matches!(
+      Manifest::new(vec![unknown], None),
+      Err(Error::UnknownBasePermission { ref base, .. }) if base == "missing"
+    ));
+  }
+
+  #[test]
+  fn groups_permissions_by_category() {
+    let file: PermissionFile = toml::from_str(
+      r#"
+[[permission]]
+identifier = "allow-read"
+category = "filesystem"
+commands.allow = ["read"]
+
+[[permission]]
+identifier = "allow-read-all"
+inherits = "allow-read"
+
+[[permission]]
+identifier = "allow-fetch"
+category = "network"
+commands.allow = ["fetch"]
+
+[[permission]]
+identifier = "allow-ping"
+commands.allow = ["ping"]
+"#,
+    )
+    .unwrap();
+
+    let manifest = Manifest::new(vec![file], None).unwrap();
+    let categories = manifest
+      .permissions_by_category()
+      .into_iter()
+      .map(|(category, permissions)| {
+        (
+          category,
+          permissions
+            .into_iter()
+            .map(|p| p.identifier.as_str())
+            .collect::<Vec<_>>(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      categories,
+      vec![
+        ("filesystem", vec!["allow-read", "allow-read-all"]),
+        ("network", vec!["allow-fetch"]),
+        ("uncategorized", vec!["allow-ping"]),
+      ]
+    );
+  }
+
+  #[cfg(feature = "config-json5")]
+  #[test]
+  fn loads_json5_permission_file() {
+    let dir = std::env::temp_dir().join("tauri-acl-json5-permissions");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+      dir.join("fs.json5"),
+      r#"{
+  // hand-written permissions
+  default: {
+    permissions: ["allow-read"],
+  },
+  permission: [
+    {
+      identifier: "allow-read",
+      description: "Allows reading files.",
+      commands: { allow: ["read"] }, // trailing commas are fine
+    },
+  ],
+}"#,
+    )
+    .unwrap();
+
+    let files = PermissionFile::load_dir(&dir).unwrap();
+    let _ = std::fs::remove_dir_all(&dir);
+    assert_eq!(files.len(), 1);
+    let file = &files[0];
+    assert_eq!(
+      file.default.as_ref().unwrap().permissions,
+      vec!["allow-read".to_string()]
+    );
+    assert_eq!(file.permission[0].identifier, "allow-read");
+    assert_eq!(file.permission[0].commands.allow, vec!["read".to_string()]);
+  }
+
+  #[test]
+  fn unknown_permission_format_lists_supported_formats() {
+    let dir = std::env::temp_dir().join("tauri-acl-unknown-permission-format");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("fs.yaml");
+    std::fs::write(&path, "").unwrap();
+
+    let err = PermissionFile::load(&path).unwrap_err();
+    let _ = std::fs::remove_dir_all(&dir);
+    assert!(matches!(err, Error::UnknownPermissionFormat(ref ext) if ext == "yaml"));
+    assert!(err.to_string().contains("json"));
+    assert!(err.to_string().contains("toml"));
+    #[cfg(feature = "config-json5")]
+    assert!(err.to_string().contains("json5"));
+  }
+
+  #[cfg(feature = "schema")]
+  #[test]
+  fn validates_global_scope_schema() {
+    let schema = serde_json::json!({
+      "$schema": "http://json-schema.org/draft-07/schema#",
+      "type": "object",
+      "properties": { "path": { "type": "string" } },
+      "required": ["path"]
+    });
+    let manifest = Manifest::new(Vec::new(), Some(schema.clone())).unwrap();
+    assert_eq!(manifest.global_scope_schema, Some(schema));
+
+    let malformed = serde_json::json!({
+      "type": "object",
+      "properties": { "path": { "type": "strnig" } }
+    });
+    assert!(matches!(
+      Manifest::new(Vec::new(), Some(malformed)),
+      Err(Error::InvalidGlobalScopeSchema(_))
+    ));
+  }
+}
@@ -21,8 +21,14 @@
 //! [ignore unknown fields when destructuring]: https://doc.rust-lang.org/book/ch18-03-pattern-syntax.html#ignoring-remaining-parts-of-a-value-with-
 //! [Struct Update Syntax]: https://doc.rust-lang.org/book/ch05-01-defining-structs.html#creating-instances-from-other-instances-with-struct-update-syntax
 
-use serde::{Deserialize, Serialize};
-use std::{num::NonZeroU64, path::PathBuf, str::FromStr, sync::Arc};
+use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use serde_untagged::UntaggedEnumVisitor;
+use std::{
+  num::{NonZeroU32, NonZeroU64},
+  path::PathBuf,
+  str::FromStr,
+  sync::Arc,
+};
 use thiserror::Error;
 use url::Url;
 
@@ -30,16 +36,42 @@ use crate::platform::Target;
 
 pub use self::{identifier::*, value::*};
 
+/// Allowed permission file extensions
+pub const PERMISSION_FILE_EXTENSIONS: &[&str] = &[
+  "json",
+  #[cfg(feature = "config-json5")]
+  "json5",
+  "toml",
+];
 /// Known foldername of the permission schema files
 pub const PERMISSION_SCHEMAS_FOLDER_NAME: &str = "schemas";
 /// Known filename of the permission schema JSON file
 pub const PERMISSION_SCHEMA_FILE_NAME: &str = "schema.json";
 /// Known ACL key for the app permissions.
 pub const APP_ACL_KEY: &str = "__app-acl__";
+/// The command pattern that allows or denies every command of a plugin,
+/// see [`Commands#structfield.allow`] and [`Commands#structfield.deny`].
+pub const ALL_COMMANDS: &str = "*";
+/// The category of permissions that do not set [`Permission#structfield.category`].
+pub const UNCATEGORIZED_PERMISSION_CATEGORY: &str = "uncategorized";
+/// The scope entry key that constrains the arguments of a command, see [`Value::matches`].
+///
+/// Constraints are enforced before the command runs: the arguments must not match a denied constraint,
+/// and must match an allowed one unless a permission allows the command without constraining its arguments.
+///
+/// ## Example
+///
+/// ```toml
+/// [[scope.allow]]
+/// "$args" = { mode = ["read", "write"] }
+/// ```
+pub const ARGUMENTS_SCOPE_KEY: &str = "$args";
 /// Known acl manifests file
 pub const ACL_MANIFESTS_FILE_NAME: &str = "acl-manifests.json";
 /// Known capabilityies file
 pub const CAPABILITIES_FILE_NAME: &str = "capabilities.json";
+/// Known file listing the Cargo features enabled on the app crate
+pub const CARGO_FEATURES_FILE_NAME: &str = "cargo-features.json";
 
 #[cfg(feature = "build")]
 pub mod build;
@@ -91,6 +123,50 @@ pub enum Error {
   #[error("failed to execute: {0}")]
   Metadata(#[from] ::cargo_metadata::Error),
 
+  /// A permission file imports the permissions of a crate that is not in the dependency tree.
+  #[cfg(feature = "build")]
+  #[error("cannot import the permissions of `{crate_name}`, it is not a dependency")]
+  UnknownPermissionImport {
+    /// Name of the imported crate.
+    crate_name: String,
+  },
+
+  /// The version requirement of a permission import is invalid.
+  #[cfg(feature = "build")]
+  #[error(
+    "invalid version requirement `{requirement}` for the permissions of `{crate_name}`: {error}"
+  )]
+  InvalidPermissionImportVersion {
+    /// Name of the imported crate.
+    crate_name: String,
+    /// The version requirement of the import.
+    requirement: String,
+    /// The parse error.
+    error: String,
+  },
+
+  /// The version of an imported crate does not match the version requirement of the import.
+  #[cfg(feature = "build")]
+  #[error("cannot import the permissions of `{crate_name}`: requested version {requested} but found {found}")]
+  PermissionImportVersionMismatch {
+    /// Name of the imported crate.
+    crate_name: String,
+    /// The version requirement of the import.
+    requested: String,
+    /// The versions of the crate in the dependency tree.
+    found: String,
+  },
+
+  /// The permissions of a crate are imported with requirements resolving to different versions of the crate.
+  #[cfg(feature = "build")]
+  #[error("the permissions of `{crate_name}` are imported from different versions: {versions}")]
+  PermissionImportConflict {
+    /// Name of the imported crate.
+    crate_name: String,
+    /// The imported versions.
+    versions: String,
+  },
+
   /// Invalid glob
   #[error("failed to run glob: {0}")]
   Glob(#[from] glob::PatternError),
@@ -109,7 +185,7 @@ pub enum Error {
   Json5(#[from] json5::Error),
 
   /// Invalid permissions file format
-  #[error("unknown permission format {0}")]
+  #[error("unknown permission format {0}, expected one of {exts}", exts = PERMISSION_FILE_EXTENSIONS.join(", "))]
   UnknownPermissionFormat(String),
 
   /// Invalid capabilities file format
@@ -150,6 +226,88 @@ pub enum Error {
     /// Capability identifier.
     identifier: String,
   },
+
+  /// Capability referenced by the configuration does not exist.
+  #[error("capability with identifier `{identifier}` not found")]
+  UnknownCapability {
+    /// Capability identifier.
+    identifier: String,
+  },
+
+  /// Capability built without an identifier.
+  #[error("capability identifier must be set")]
+  MissingCapabilityIdentifier,
+
+  /// Capability requires a version of a permission or permission set that is not the available one.
+  #[error(
+    "capability requires version {requested} of {permission}, but the available version is {}",
+    .found.map(|v| v.to_string()).unwrap_or_else(|| "unset".into())
+  )]
+  PermissionVersionMismatch {
+    /// Permission or permission set identifier.
+    permission: String,
+    /// Version required by the capability.
+    requested: NonZeroU64,
+    /// Version of the permission or permission set.
+    found: Option<NonZeroU64>,
+  },
+
+  /// A permission inherits from a permission that does not exist in the plugin.
+  #[error("permission {permission} inherits from unknown permission {base}")]
+  UnknownBasePermission {
+    /// Identifier of the inheriting permission.
+    permission: String,
+    /// Identifier of the base permission.
+    base: String,
+  },
+
+  /// A permission includes a scope fragment that is not defined in its permission file.
+  #[error("permission {permission} includes unknown scope fragment {fragment}")]
+  UnknownScopeFragment {
+    /// Identifier of the permission.
+    permission: String,
+    /// Name of the scope fragment.
+    fragment: String,
+  },
+
+  /// Permissions inherit from each other in a cycle.
+  #[error("permission inheritance cycle: {cycle}")]
+  PermissionInheritanceCycle {
+    /// The permissions of the cycle, e.g. `a -> b -> a`.
+    cycle: String,
+  },
+
+  /// A scope value references a variable that the capability does not define.
+  #[error("undefined scope variable `{variable}`, it must be defined in the `variables` of the capability")]
+  UndefinedScopeVariable {
+    /// Variable name.
+    variable: String,
+  },
+
+  /// A permission requires another permission that the capability does not grant.
+  #[error("permission {permission} requires {required}, which is not granted by capability {capability}; add it to the capability or enable `include_required_permissions`")]
+  MissingRequiredPermission {
+    /// Capability identifier.
+    capability: String,
+    /// Identifier of the permission with the requirement.
+    permission: String,
+    /// Identifier of the required permission.
+    required: String,
+  },
+
+  /// Permission identifier does not follow the `plugin-name:permission-name` convention.
+  #[error("invalid permission identifier `{identifier}`: {reason}")]
+  InvalidPermissionIdentifier {
+    /// Permission identifier.
+    identifier: String,
+    /// Why the identifier is invalid.
+    reason: &'static str,
+  },
+
+  /// The global scope schema of a plugin is not a valid JSON Schema.
+  #[cfg(feature = "schema")]
+  #[error("invalid global scope schema: {0}")]
+  InvalidGlobalScopeSchema(String),
 }
 
 /// Allowed and denied commands inside a permission.
@@ -159,10 +317,16 @@ pub enum Error {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Commands {
   /// Allowed command.
+  ///
+  /// `*` allows every command of the plugin, which are the commands listed by any of its permissions,
+  /// including the permissions generated for each command. The `deny` list still applies.
+  /// Only use it for trusted plugins: the capability also grants the commands added by future versions of the plugin.
   #[serde(default)]
   pub allow: Vec<String>,
 
   /// Denied command, which takes priority.
+  ///
+  /// `*` denies every command of the plugin, even the commands allowed by other permissions.
   #[serde(default)]
   pub deny: Vec<String>,
 }
@@ -189,11 +353,18 @@ pub struct Scopes {
   /// Data that defines what is denied by the scope. This should be prioritized by validation logic.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub deny: Option<Vec<Value>>,
+  /// Names of the scope fragments to add to this scope,
+  /// see [`manifest::PermissionFile#structfield.scope_fragments`].
+  ///
+  /// Only permissions can include fragments, which are expanded when the plugin manifest is created.
+  /// Capabilities that set it on a permission entry fail to load.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub include: Vec<String>,
 }
 
 impl Scopes {
   fn is_empty(&self) -> bool {
-    self.allow.is_none() && self.deny.is_none()
+    self.allow.is_none() && self.deny.is_none() && self.include.is_empty()
   }
 }
 
@@ -218,6 +389,13 @@ pub struct Permission {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub description: Option<String>,
 
+  /// Path to a markdown file containing the description of the permission,
+  /// relative to the file that defines the permission.
+  ///
+  /// The file is read at build time and its content replaces [`Self::description`].
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description_file: Option<PathBuf>,
+
   /// Allowed or denied commands when using this permission.
   #[serde(default)]
   pub commands: Commands,
@@ -227,25 +405,356 @@ pub struct Permission {
   pub scope: Scopes,
 
   /// Target platforms this permission applies. By default all platforms are affected by this permission.
+  ///
+  /// ## Example
+  ///
+  /// Enable the permission on every platform except Android:
+  ///
+  /// ```toml
+  /// platforms = { except = ["android"] }
+  /// ```
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub platforms: Option<TargetFilter>,
+
+  /// Build profiles this permission applies. By default the permission is enabled in every build profile.
+  ///
+  /// ## Example
+  ///
+  /// Only enable the permission in debug builds:
+  ///
+  /// ```toml
+  /// profiles = ["debug"]
+  /// ```
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub profiles: Option<Vec<Profile>>,
+
+  /// The kind of execution context this permission applies to. By default the permission applies to both local and remote calls.
+  ///
+  /// ## Example
+  ///
+  /// Only allow the commands to be called from the remote URLs of a capability:
+  ///
+  /// ```toml
+  /// execution_context = "remote"
+  /// ```
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub execution_context: Option<ExecutionContextKind>,
+
+  /// The maximum number of calls per minute allowed for each command of this permission, counted per execution context.
+  /// Calls over the limit are rejected. By default the commands are not rate limited.
+  ///
+  /// When a command is allowed by several permissions, the least restrictive limit applies.
+  ///
+  /// ## Example
+  ///
+  /// ```toml
+  /// max_calls_per_minute = 10
+  /// ```
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub platforms: Option<Vec<Target>>,
+  pub max_calls_per_minute: Option<NonZeroU32>,
+
+  /// Permissions that must also be granted by a capability that grants this permission,
+  /// for instance a write permission that is useless without the matching read permission.
+  ///
+  /// Identifiers without a plugin prefix refer to permissions of the same plugin.
+  /// Resolving a capability that misses a required permission fails, unless the capability
+  /// enables [`include_required_permissions`](capability::Capability#structfield.include_required_permissions).
+  ///
+  /// ## Example
+  ///
+  /// ```toml
+  /// requires = ["allow-read", "dialog:allow-open"]
+  /// ```
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub requires: Vec<String>,
+
+  /// Identifier of a permission of the same plugin that this permission extends.
+  ///
+  /// The commands, scopes and required permissions of the base permission come first,
+  /// followed by the ones of this permission. The platforms, profiles, execution context, rate limit
+  /// and category of the base permission apply unless this permission sets its own.
+  /// The identifier, version and description are never inherited.
+  ///
+  /// Inheritance is resolved when the plugin manifest is built, see [`manifest::Manifest::new`].
+  ///
+  /// ## Example
+  ///
+  /// ```toml
+  /// inherits = "allow-read"
+  /// ```
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub inherits: Option<String>,
+
+  /// Category used by tooling to group permissions, e.g. in generated docs and permission pickers.
+  ///
+  /// See [`manifest::Manifest::permissions_by_category`].
+  ///
+  /// ## Example
+  ///
+  /// ```toml
+  /// category = "filesystem"
+  /// ```
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub category: Option<String>,
 }
 
 impl Permission {
+  /// Validates the permission identifier.
+  ///
+  /// Permissions are referenced as `${plugin-name}:${identifier}`, so the identifier must only include
+  /// lowercase ASCII alphanumeric characters and hyphens which are not leading or trailing.
+  /// The `default` identifier is reserved for the default permission set.
+  pub fn validate_identifier(&self) -> Result<(), Error> {
+    let reason = if self.identifier.is_empty() {
+      Some("identifiers cannot be empty")
+    } else if self.identifier == "default" {
+      Some("`default` is reserved for the default permission set")
+    } else if self.identifier.contains(':') {
+      Some("the plugin name prefix is added automatically and must not be included")
+    } else if !self
+      .identifier
+      .bytes()
+      .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    {
+      Some("identifiers can only include lowercase ASCII alphanumeric characters and hyphens")
+    } else if self.identifier.starts_with('-') || self.identifier.ends_with('-') {
+      Some("identifiers cannot have leading or trailing hyphens")
+    } else {
+      None
+    };
+
+    match reason {
+      Some(reason) => Err(Error::InvalidPermissionIdentifier {
+        identifier: self.identifier.clone(),
+        reason,
+      }),
+      None => Ok(()),
+    }
+  }
+
+  /// Checks the permission for likely mistakes, returning a warning message for each one.
+  ///
+  /// Currently reports commands that are both allowed and denied, since the deny entry always wins.
+  pub fn lint(&self) -> Vec<String> {
+    let mut overlapping = self
+      .commands
+      .allow
+      .iter()
+      .filter(|command| self.commands.deny.contains(command))
+      .collect::<Vec<_>>();
+    overlapping.sort();
+    overlapping.dedup();
+
+    overlapping
+      .into_iter()
+      .map(|command| {
+        format!(
+          "command `{command}` is in both allow and deny of permission `{}`; it will be denied",
+          self.identifier
+        )
+      })
+      .collect()
+  }
+
+  /// Merges this permission on top of its base permission, see [`Self::inherits`].
+  fn extend(&self, base: &Permission) -> Permission {
+    fn merge<T: Clone + PartialEq>(base: &[T], own: &[T]) -> Vec<T> {
+      let mut merged = base.to_vec();
+      for item in own {
+        if !merged.contains(item) {
+          merged.push(item.clone());
+        }
+      }
+      merged
+    }
+    fn merge_scope(base: &Option<Vec<Value>>, own: &Option<Vec<Value>>) -> Option<Vec<Value>> {
+      match (base, own) {
+        (None, None) => None,
+        (base, own) => Some(merge(
+          base.as_deref().unwrap_or_default(),
+          own.as_deref().unwrap_or_default(),
+        )),
+      }
+    }
+
+    Permission {
+      version: self.version,
+      identifier: self.identifier.clone(),
+      description: self.description.clone(),
+      description_file: self.description_file.clone(),
+      commands: Commands {
+        allow: merge(&base.commands.allow, &self.commands.allow),
+        deny: merge(&base.commands.deny, &self.commands.deny),
+      },
+      scope: Scopes {
+        allow: merge_scope(&base.scope.allow, &self.scope.allow),
+        deny: merge_scope(&base.scope.deny, &self.scope.deny),
+        include: merge(&base.scope.include, &self.scope.include),
+      },
+      platforms: self.platforms.clone().or_else(|| base.platforms.clone()),
+      profiles: self.profiles.clone().or_else(|| base.profiles.clone()),
+      execution_context: self.execution_context.or(base.execution_context),
+      max_calls_per_minute: self.max_calls_per_minute.or(base.max_calls_per_minute),
+      requires: merge(&base.requires, &self.requires),
+      inherits: self.inherits.clone(),
+      category: self.category.clone().or_else(|| base.category.clone()),
+    }
+  }
+
   /// Whether this permission should be active based on the platform target or not.
   pub fn is_active(&self, target: &Target) -> bool {
     self
       .platforms
       .as_ref()
-      .map(|platforms| platforms.contains(target))
+      .map(|platforms| platforms.matches(target))
+      .unwrap_or(true)
+  }
+
+  /// Whether this permission should be active based on the build profile or not.
+  pub fn is_active_in_profile(&self, profile: &Profile) -> bool {
+    self
+      .profiles
+      .as_ref()
+      .map(|profiles| profiles.contains(profile))
+      .unwrap_or(true)
+  }
+
+  /// Whether this permission applies to calls made from the given execution context or not.
+  pub fn is_active_in_context(&self, context: &ExecutionContext) -> bool {
+    self
+      .execution_context
+      .map(|kind| kind == context.kind())
       .unwrap_or(true)
   }
 }
 
+/// The target platforms a [`Permission`] applies to.
+///
+/// Deserialized from a list of targets, or from an object with an `except` list of targets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TargetFilter {
+  /// Only the listed targets.
+  Only(Vec<Target>),
+  /// All targets except the listed ones.
+  Except(Vec<Target>),
+}
+
+impl TargetFilter {
+  /// Whether the given target is allowed by this filter.
+  pub fn matches(&self, target: &Target) -> bool {
+    match self {
+      Self::Only(targets) => targets.contains(target),
+      Self::Except(targets) => !targets.contains(target),
+    }
+  }
+}
+
+impl From<Vec<Target>> for TargetFilter {
+  fn from(targets: Vec<Target>) -> Self {
+    Self::Only(targets)
+  }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct ExceptTargets {
+  /// Targets the permission does not apply to.
+  except: Vec<Target>,
+}
+
+impl<'de> Deserialize<'de> for TargetFilter {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    UntaggedEnumVisitor::new()
+      .seq(|seq| seq.deserialize().map(Self::Only))
+      .map(|map| {
+        map
+          .deserialize::<ExceptTargets>()
+          .map(|targets| Self::Except(targets.except))
+      })
+      .deserialize(deserializer)
+  }
+}
+
+impl Serialize for TargetFilter {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Self::Only(targets) => targets.serialize(serializer),
+      Self::Except(targets) => {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("except", targets)?;
+        map.end()
+      }
+    }
+  }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for TargetFilter {
+  fn schema_name() -> String {
+    "TargetFilter".to_string()
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    use schemars::schema::{Metadata, Schema, SchemaObject, SubschemaValidation};
+
+    let mut only = gen.subschema_for::<Vec<Target>>().into_object();
+    only.metadata().description = Some("Only the listed targets.".into());
+    let mut except = gen.subschema_for::<ExceptTargets>().into_object();
+    except.metadata().description = Some("All targets except the listed ones.".into());
+
+    SchemaObject {
+      metadata: Some(Box::new(Metadata {
+        description: Some("The target platforms a permission applies to.".into()),
+        ..Default::default()
+      })),
+      subschemas: Some(Box::new(SubschemaValidation {
+        any_of: Some(vec![Schema::Object(only), Schema::Object(except)]),
+        ..Default::default()
+      })),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
+/// A build profile, used to restrict a [`Permission`] to debug or release builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum Profile {
+  /// Builds with debug assertions enabled, such as `tauri dev` or `tauri build --debug`.
+  Debug,
+  /// Builds without debug assertions.
+  Release,
+}
+
+impl Profile {
+  /// The build profile of the current compilation.
+  pub fn current() -> Self {
+    if cfg!(debug_assertions) {
+      Self::Debug
+    } else {
+      Self::Release
+    }
+  }
+}
+
 /// A set of direct permissions grouped together under a new name.
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PermissionSet {
+  /// The version of the permission set.
+  ///
+  /// Capabilities can require a specific version, see [`capability::PermissionEntry::ExtendedPermission`].
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<NonZeroU64>,
+
   /// A unique identifier for the permission.
   pub identifier: String,
 
@@ -258,7 +767,19 @@ pub struct PermissionSet {
 
 /// UrlPattern for [`ExecutionContext::Remote`].
 #[derive(Debug, Clone)]
-pub struct RemoteUrlPattern(Arc<urlpattern::UrlPattern>, String);
+pub struct RemoteUrlPattern(Arc<urlpattern::UrlPattern>, String, Option<String>);
+
+/// Schemes the URL standard treats as special, see <https://url.spec.whatwg.org/#special-scheme>.
+const SPECIAL_SCHEMES: &[&str] = &["http", "https", "ws", "wss", "ftp", "file"];
+
+/// Whether the given protocol pattern is a literal non-special scheme, e.g. `myapp`.
+fn is_custom_scheme(protocol: &str) -> bool {
+  !protocol.is_empty()
+    && protocol
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    && !SPECIAL_SCHEMES.contains(&protocol.to_ascii_lowercase().as_str())
+}
 
 impl FromStr for RemoteUrlPattern {
   type Err = urlpattern::quirks::Error;
@@ -279,8 +800,18 @@ impl FromStr for RemoteUrlPattern {
     {
       init.pathname.replace("*".to_string());
     }
+    // paths of non-special schemes are opaque to the URL pattern parser (`/` gets percent-encoded),
+    // so custom schemes are matched as `https` URLs and their scheme is compared separately
+    let custom_scheme = init
+      .protocol
+      .as_deref()
+      .filter(|p| is_custom_scheme(p))
+      .map(|p| p.to_ascii_lowercase());
+    if custom_scheme.is_some() {
+      init.protocol.replace("https".to_string());
+    }
     let pattern = urlpattern::UrlPattern::parse(init, Default::default())?;
-    Ok(Self(Arc::new(pattern), s.to_string()))
+    Ok(Self(Arc::new(pattern), s.to_string(), custom_scheme))
   }
 }
 
@@ -291,17 +822,93 @@ impl RemoteUrlPattern {
   }
 
   /// Test if a given URL matches the pattern.
+  ///
+  /// URLs with non-special schemes (e.g. `myapp://` or `tauri://`) are normalized the same way
+  /// special schemes (`http`, `https`, `ws`, `wss`) are: the host is compared case-insensitively
+  /// and an empty path is treated as `/`, so `myapp://Localhost` matches `myapp://localhost/*`.
   pub fn test(&self, url: &Url) -> bool {
+    let url = match &self.2 {
+      Some(scheme) => {
+        if url.scheme() != scheme {
+          return false;
+        }
+        match Url::parse(&format!("https{}", &url.as_str()[url.scheme().len()..])) {
+          Ok(url) => url,
+          Err(_) => return false,
+        }
+      }
+      None => normalize_url(url),
+    };
     self
       .0
-      .test(urlpattern::UrlPatternMatchInput::Url(url.clone()))
+      .test(urlpattern::UrlPatternMatchInput::Url(url))
       .unwrap_or_default()
   }
+
+  /// The pattern of the URL protocol, e.g. `https` or `*`.
+  pub fn protocol(&self) -> &str {
+    self.2.as_deref().unwrap_or_else(|| self.0.protocol())
+  }
+
+  /// The pattern of the URL username.
+  pub fn username(&self) -> &str {
+    self.0.username()
+  }
+
+  /// The pattern of the URL password.
+  pub fn password(&self) -> &str {
+    self.0.password()
+  }
+
+  /// The pattern of the URL hostname, e.g. `api.tauri.app` or `*.tauri.app`.
+  pub fn hostname(&self) -> &str {
+    self.0.hostname()
+  }
+
+  /// The pattern of the URL port.
+  pub fn port(&self) -> &str {
+    self.0.port()
+  }
+
+  /// The pattern of the URL pathname, e.g. `/api/*`.
+  ///
+  /// Patterns without a path or with the `/` path match any path, so this is `*` for them.
+  pub fn pathname(&self) -> &str {
+    self.0.pathname()
+  }
+
+  /// The pattern of the URL search (query string).
+  pub fn search(&self) -> &str {
+    self.0.search()
+  }
+
+  /// The pattern of the URL hash (fragment).
+  pub fn hash(&self) -> &str {
+    self.0.hash()
+  }
+}
+
+/// Normalizes the parts of a URL the `url` crate leaves untouched for non-special schemes.
+fn normalize_url(url: &Url) -> Url {
+  let mut url = url.clone();
+  if url.has_host() {
+    if let Some(host) = url
+      .host_str()
+      .filter(|h| h.chars().any(|c| c.is_ascii_uppercase()))
+    {
+      let host = host.to_ascii_lowercase();
+      let _ = url.set_host(Some(&host));
+    }
+    if url.path().is_empty() {
+      url.set_path("/");
+    }
+  }
+  url
 }
 
 impl PartialEq for RemoteUrlPattern {
   fn eq(&self, other: &Self) -> bool {
-    self.0.protocol() == other.0.protocol()
+    self.protocol() == other.protocol()
       && self.0.username() == other.0.username()
       && self.0.password() == other.0.password()
       && self.0.hostname() == other.0.hostname()
@@ -327,9 +934,110 @@ pub enum ExecutionContext {
   },
 }
 
+impl ExecutionContext {
+  /// The kind of this execution context.
+  pub fn kind(&self) -> ExecutionContextKind {
+    match self {
+      Self::Local => ExecutionContextKind::Local,
+      Self::Remote { .. } => ExecutionContextKind::Remote,
+    }
+  }
+
+  /// Whether a call made from the given origin runs in this execution context.
+  pub fn matches(&self, origin: CommandOrigin<'_>) -> bool {
+    match (self, origin) {
+      (Self::Local, CommandOrigin::Local) => true,
+      (Self::Remote { url: pattern }, CommandOrigin::Remote(url)) => pattern.test(url),
+      _ => false,
+    }
+  }
+}
+
+/// The origin of an IPC call, matched against the [`ExecutionContext`] of the resolved commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandOrigin<'a> {
+  /// The call is made from the local app URL.
+  Local,
+  /// The call is made from the given remote URL.
+  Remote(&'a Url),
+}
+
+/// The kind of an [`ExecutionContext`], used to restrict a [`Permission`] to local or remote calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ExecutionContextKind {
+  /// Calls made from the local app URL.
+  Local,
+  /// Calls made from the remote URLs allowed by a capability.
+  Remote,
+}
+
 #[cfg(test)]
 mod tests {
-  use crate::acl::RemoteUrlPattern;
+  use crate::acl::{Commands, Error, Permission, RemoteUrlPattern};
+
+  #[test]
+  fn validate_permission_identifier() {
+    let permission = |identifier: &str| Permission {
+      identifier: identifier.to_string(),
+      ..Default::default()
+    };
+
+    for valid in ["allow-read", "deny-read-text-file", "scope-2"] {
+      assert!(permission(valid).validate_identifier().is_ok(), "{valid}");
+    }
+
+    for invalid in [
+      "",
+      "fs:allow-read",
+      "Allow-Read",
+      "allow_read",
+      "-read",
+      "read-",
+    ] {
+      assert!(
+        matches!(
+          permission(invalid).validate_identifier(),
+          Err(Error::InvalidPermissionIdentifier { .. })
+        ),
+        "{invalid}"
+      );
+    }
+
+    assert!(matches!(
+      permission("default").validate_identifier(),
+      Err(Error::InvalidPermissionIdentifier { identifier, .. }) if identifier == "default"
+    ));
+  }
+
+  #[test]
+  fn lint_overlapping_commands() {
+    let permission = Permission {
+      identifier: "allow-read".into(),
+      commands: Commands {
+        allow: vec!["read".into(), "stat".into()],
+        deny: vec!["read".into()],
+      },
+      ..Default::default()
+    };
+    assert_eq!(
+      permission.lint(),
+      vec![
+        "command `read` is in both allow and deny of permission `allow-read`; it will be denied"
+      ]
+    );
+
+    let permission = Permission {
+      identifier: "allow-read".into(),
+      commands: Commands {
+        allow: vec!["read".into()],
+        deny: vec!["write".into()],
+      },
+      ..Default::default()
+    };
+    assert!(permission.lint().is_empty());
+  }
 
   #[test]
   fn url_pattern_domain_wildcard() {
@@ -365,6 +1073,88 @@ mod tests {
     assert!(pattern.test(&"https://localhost/path?q=1".parse().unwrap()));
     assert!(pattern.test(&"custom://localhost/path".parse().unwrap()));
   }
+
+  #[test]
+  fn url_pattern_websocket() {
+    let pattern: RemoteUrlPattern = "ws://*.example.com".parse().unwrap();
+    assert!(pattern.test(&"ws://api.example.com".parse().unwrap()));
+    assert!(pattern.test(&"ws://api.example.com/socket?token=1".parse().unwrap()));
+    assert!(!pattern.test(&"ws://example.com/socket".parse().unwrap()));
+    assert!(!pattern.test(&"wss://api.example.com/socket".parse().unwrap()));
+    assert!(!pattern.test(&"http://api.example.com/socket".parse().unwrap()));
+    assert!(!pattern.test(&"ws://api.example.com:8080/socket".parse().unwrap()));
+
+    let pattern: RemoteUrlPattern = "wss://*.example.com:8443/*".parse().unwrap();
+    assert!(pattern.test(&"wss://api.example.com:8443/socket".parse().unwrap()));
+    assert!(!pattern.test(&"wss://api.example.com/socket".parse().unwrap()));
+  }
+
+  #[test]
+  fn url_pattern_custom_scheme() {
+    let pattern: RemoteUrlPattern = "myapp://localhost".parse().unwrap();
+    assert!(pattern.test(&"myapp://localhost".parse().unwrap()));
+    assert!(pattern.test(&"myapp://localhost/path?q=1".parse().unwrap()));
+    assert!(!pattern.test(&"myapp://tauri.app/path".parse().unwrap()));
+    assert!(!pattern.test(&"otherapp://localhost/path".parse().unwrap()));
+
+    let pattern: RemoteUrlPattern = "myapp://*.tauri.app/*".parse().unwrap();
+    assert!(pattern.test(&"myapp://api.tauri.app".parse().unwrap()));
+    assert!(pattern.test(&"myapp://API.Tauri.app/path".parse().unwrap()));
+    assert!(!pattern.test(&"myapp://tauri.app/path".parse().unwrap()));
+  }
+
+  #[test]
+  fn permission_target_filter() {
+    use super::TargetFilter;
+    use crate::platform::Target;
+
+    let permission: Permission = serde_json::from_value(serde_json::json!({
+      "identifier": "allow-read",
+      "platforms": ["linux", "windows"]
+    }))
+    .unwrap();
+    assert_eq!(
+      permission.platforms,
+      Some(TargetFilter::Only(vec![Target::Linux, Target::Windows]))
+    );
+    assert!(permission.is_active(&Target::Linux));
+    assert!(!permission.is_active(&Target::Android));
+
+    let permission: Permission = serde_json::from_value(serde_json::json!({
+      "identifier": "allow-read",
+      "platforms": { "except": ["android"] }
+    }))
+    .unwrap();
+    assert_eq!(
+      permission.platforms,
+      Some(TargetFilter::Except(vec![Target::Android]))
+    );
+    assert!(permission.is_active(&Target::Linux));
+    assert!(permission.is_active(&Target::Ios));
+    assert!(!permission.is_active(&Target::Android));
+
+    assert_eq!(
+      serde_json::to_value(&permission).unwrap()["platforms"],
+      serde_json::json!({ "except": ["android"] })
+    );
+  }
+
+  #[test]
+  fn url_pattern_components() {
+    let pattern: RemoteUrlPattern = "https://api.tauri.app:8080/v1/*".parse().unwrap();
+    assert_eq!(pattern.protocol(), "https");
+    assert_eq!(pattern.hostname(), "api.tauri.app");
+    assert_eq!(pattern.port(), "8080");
+    assert_eq!(pattern.pathname(), "/v1/*");
+    assert_eq!(pattern.search(), "*");
+    assert_eq!(pattern.hash(), "*");
+
+    let pattern: RemoteUrlPattern = "http://*.tauri.app".parse().unwrap();
+    assert_eq!(pattern.protocol(), "http");
+    assert_eq!(pattern.hostname(), "*.tauri.app");
+    assert_eq!(pattern.port(), "");
+    assert_eq!(pattern.pathname(), "*");
+  }
 }
 
 #[cfg(feature = "build")]
@@ -405,7 +1195,8 @@ mod build_ {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let allow = opt_vec_lit(self.allow.as_ref(), identity);
       let deny = opt_vec_lit(self.deny.as_ref(), identity);
-      literal_struct!(tokens, ::tauri::utils::acl::Scopes, allow, deny)
+      let include = vec_lit(&self.include, str_lit);
+      literal_struct!(tokens, ::tauri::utils::acl::Scopes, allow, deny, include)
     }
   }
 
@@ -417,9 +1208,20 @@ mod build_ {
       }));
       let identifier = str_lit(&self.identifier);
       let description = opt_str_lit(self.description.as_ref());
+      // the file content is loaded into the description at build time
+      let description_file = quote!(::core::option::Option::None);
       let commands = &self.commands;
       let scope = &self.scope;
-      let platforms = opt_vec_lit(self.platforms.as_ref(), identity);
+      let platforms = opt_lit(self.platforms.as_ref());
+      let profiles = opt_vec_lit(self.profiles.as_ref(), identity);
+      let execution_context = opt_lit(self.execution_context.as_ref());
+      let max_calls_per_minute = opt_lit_owned(self.max_calls_per_minute.as_ref().map(|v| {
+        let v = v.get();
+        quote!(::core::num::NonZeroU32::new(#v).unwrap())
+      }));
+      let requires = vec_lit(&self.requires, str_lit);
+      let inherits = opt_str_lit(self.inherits.as_ref());
+      let category = opt_str_lit(self.category.as_ref());
 
       literal_struct!(
         tokens,
@@ -427,21 +1229,72 @@ mod build_ {
         version,
         identifier,
         description,
+        description_file,
         commands,
         scope,
-        platforms
+        platforms,
+        profiles,
+        execution_context,
+        max_calls_per_minute,
+        requires,
+        inherits,
+        category
       )
     }
   }
 
+  impl ToTokens for ExecutionContextKind {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::acl::ExecutionContextKind };
+
+      tokens.append_all(match self {
+        Self::Local => quote! { #prefix::Local },
+        Self::Remote => quote! { #prefix::Remote },
+      });
+    }
+  }
+
+  impl ToTokens for TargetFilter {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::acl::TargetFilter };
+
+      tokens.append_all(match self {
+        Self::Only(targets) => {
+          let targets = vec_lit(targets, identity);
+          quote! { #prefix::Only(#targets) }
+        }
+        Self::Except(targets) => {
+          let targets = vec_lit(targets, identity);
+          quote! { #prefix::Except(#targets) }
+        }
+      });
+    }
+  }
+
+  impl ToTokens for Profile {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::acl::Profile };
+
+      tokens.append_all(match self {
+        Self::Debug => quote! { #prefix::Debug },
+        Self::Release => quote! { #prefix::Release },
+      });
+    }
+  }
+
   impl ToTokens for PermissionSet {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+      let version = opt_lit_owned(self.version.as_ref().map(|v| {
+        let v = v.get();
+        quote!(::core::num::NonZeroU64::new(#v).unwrap())
+      }));
       let identifier = str_lit(&self.identifier);
       let description = str_lit(&self.description);
       let permissions = vec_lit(&self.permissions, str_lit);
       literal_struct!(
         tokens,
         ::tauri::utils::acl::PermissionSet,
+        version,
         identifier,
         description,
         permissions
@@ -4,15 +4,19 @@
 
 //! Resolved ACL for runtime usage.
 
-use std::{collections::BTreeMap, fmt};
+use std::{
+  collections::{BTreeMap, BTreeSet, HashSet},
+  fmt,
+  num::{NonZeroU32, NonZeroU64},
+};
 
 use crate::platform::Target;
 
 use super::{
   capability::{Capability, PermissionEntry},
   manifest::Manifest,
-  Commands, Error, ExecutionContext, Identifier, Permission, PermissionSet, Scopes, Value,
-  APP_ACL_KEY,
+  CommandOrigin, Commands, Error, ExecutionContext, Identifier, Permission, PermissionSet, Profile,
+  Scopes, Value, ALL_COMMANDS, APP_ACL_KEY,
 };
 
 /// A key for a scope, used to link a [`ResolvedCommand#structfield.scope`] to the store [`Resolved#structfield.scopes`].
@@ -20,7 +24,7 @@ pub type ScopeKey = u64;
 
 /// Metadata for what referenced a [`ResolvedCommand`].
 #[cfg(debug_assertions)]
-#[derive(Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct ResolvedCommandReference {
   /// Identifier of the capability.
   pub capability: String,
@@ -42,6 +46,17 @@ pub struct ResolvedCommand {
   pub webviews: Vec<glob::Pattern>,
   /// The reference of the scope that is associated with this command. See [`Resolved#structfield.command_scopes`].
   pub scope_id: Option<ScopeKey>,
+  /// The maximum number of calls per minute, see [`Permission#structfield.max_calls_per_minute`].
+  pub max_calls_per_minute: Option<NonZeroU32>,
+}
+
+impl ResolvedCommand {
+  /// Whether this command can be called from the given origin by the given window or webview.
+  pub fn matches(&self, origin: CommandOrigin<'_>, window: &str, webview: &str) -> bool {
+    self.context.matches(origin)
+      && (self.webviews.iter().any(|w| w.matches(webview))
+        || self.windows.iter().any(|w| w.matches(window)))
+  }
 }
 
 impl fmt::Debug for ResolvedCommand {
@@ -51,12 +66,14 @@ impl fmt::Debug for ResolvedCommand {
       .field("windows", &self.windows)
       .field("webviews", &self.webviews)
       .field("scope_id", &self.scope_id)
+      .field("max_calls_per_minute", &self.max_calls_per_minute)
       .finish()
   }
 }
 
 /// A resolved scope. Merges all scopes defined for a single command.
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "test-util", derive(serde::Serialize))]
 pub struct ResolvedScope {
   /// Allows something on the command.
   pub allow: Vec<Value>,
@@ -75,14 +92,59 @@ pub struct Resolved {
   pub command_scope: BTreeMap<ScopeKey, ResolvedScope>,
   /// The global scope.
   pub global_scope: BTreeMap<String, ResolvedScope>,
+  /// Capabilities that declare remote URLs which can be added at runtime,
+  /// see [`CapabilityRemote#structfield.runtime_urls`](crate::acl::capability::CapabilityRemote#structfield.runtime_urls).
+  pub runtime_remote_capabilities: BTreeMap<String, Capability>,
 }
 
 impl Resolved {
+  /// Whether the command can be called from the given origin by the given window and webview,
+  /// using the same rules as the runtime authority.
+  ///
+  /// A command denied for the origin is never allowed, whatever window or webview calls it, see [`Self::is_denied`].
+  /// The webview of a webview window has the same label as its window.
+  pub fn is_allowed(
+    &self,
+    command: &str,
+    origin: CommandOrigin<'_>,
+    window: &str,
+    webview: &str,
+  ) -> bool {
+    !self.is_denied(command, origin)
+      && self.allowed_commands.get(command).is_some_and(|resolved| {
+        resolved
+          .iter()
+          .any(|cmd| cmd.matches(origin, window, webview))
+      })
+  }
+
+  /// Whether the command is denied for the given origin.
+  ///
+  /// Denied commands apply to every window and webview of the capability execution context.
+  pub fn is_denied(&self, command: &str, origin: CommandOrigin<'_>) -> bool {
+    self
+      .denied_commands
+      .get(command)
+      .is_some_and(|resolved| resolved.iter().any(|cmd| cmd.context.matches(origin)))
+  }
+
   /// Resolves the ACL for the given plugin permissions and app capabilities.
+  ///
+  /// Permissions restricted to other build profiles are ignored, see [`Profile::current`].
   pub fn resolve(
+    acl: &BTreeMap<String, Manifest>,
+    capabilities: BTreeMap<String, Capability>,
+    target: Target,
+  ) -> Result<Self, Error> {
+    Self::resolve_for_profile(acl, capabilities, target, Profile::current())
+  }
+
+  /// Resolves the ACL for the given plugin permissions and app capabilities on the given build profile.
+  pub fn resolve_for_profile(
     acl: &BTreeMap<String, Manifest>,
     mut capabilities: BTreeMap<String, Capability>,
     target: Target,
+    profile: Profile,
   ) -> Result<Self, Error> {
     let mut allowed_commands = BTreeMap::new();
     let mut denied_commands = BTreeMap::new();
@@ -91,14 +153,28 @@ impl Resolved {
     let mut command_scope = BTreeMap::new();
     let mut global_scope: BTreeMap<String, Vec<Scopes>> = BTreeMap::new();
 
+    let runtime_remote_capabilities = capabilities
+      .iter()
+      .filter(|(_, c)| {
+        c.is_active(&target)
+          && c
+            .remote
+            .as_ref()
+            .is_some_and(|remote| !remote.runtime_urls.is_empty())
+      })
+      .map(|(identifier, c)| (identifier.clone(), c.clone()))
+      .collect();
+
     // resolve commands
     for capability in capabilities.values_mut().filter(|c| c.is_active(&target)) {
       with_resolved_permissions(
         capability,
         acl,
         target,
+        profile,
         |ResolvedPermission {
            key,
+           permission,
            commands,
            scope,
            #[cfg_attr(not(debug_assertions), allow(unused))]
@@ -133,6 +209,7 @@ impl Resolved {
                   format!("plugin:{key}|{allowed_command}")
                 },
                 capability,
+                permission,
                 scope_id,
                 #[cfg(debug_assertions)]
                 permission_name.to_string(),
@@ -150,6 +227,7 @@ impl Resolved {
                   format!("plugin:{key}|{denied_command}")
                 },
                 capability,
+                permission,
                 scope_id,
                 #[cfg(debug_assertions)]
                 permission_name.to_string(),
@@ -184,12 +262,390 @@ impl Resolved {
     let resolved = Self {
       allowed_commands,
       denied_commands,
+      runtime_remote_capabilities,
       command_scope,
       global_scope,
     };
 
     Ok(resolved)
   }
+
+  /// Checks the capabilities active on the given target for permissions that grant nothing on it,
+  /// returning a warning message for each one.
+  ///
+  /// Currently reports:
+  ///
+  /// - capabilities that do not reference any permission, which is usually a mistake.
+  /// - permission entries whose permissions are all restricted to other platforms,
+  ///   see [`Permission#structfield.platforms`], which explains why their commands are not available on the target.
+  /// - permission entries whose allowed commands are all denied by another entry applying to the same windows,
+  ///   e.g. `fs:allow-read` next to a broad deny like `fs:deny-all`, since a deny always wins over an allow
+  ///   no matter how specific the allow is.
+  pub fn lint(
+    acl: &BTreeMap<String, Manifest>,
+    capabilities: &BTreeMap<String, Capability>,
+    target: Target,
+  ) -> Result<Vec<String>, Error> {
+    let mut warnings = Vec::new();
+
+    for capability in capabilities.values().filter(|c| c.is_active(&target)) {
+      if capability.permissions.is_empty() {
+        warnings.push(format!(
+          "capability `{}` grants nothing: it does not reference any permission",
+          capability.identifier
+        ));
+      }
+
+      for permission_entry in &capability.permissions {
+        let permission_id = permission_entry.identifier();
+        let permissions = get_permissions(permission_id, acl)?;
+
+        if !permissions.is_empty() && permissions.iter().all(|p| !p.permission.is_active(&target)) {
+          warnings.push(format!(
+            "permission `{}` of capability `{}` grants nothing on {target}: all of its permissions are restricted to other platforms",
+            permission_id.get(),
+            capability.identifier
+          ));
+        }
+      }
+    }
+
+    let mut entries = Vec::new();
+    for capability in capabilities.values().filter(|c| c.is_active(&target)) {
+      for permission_entry in &capability.permissions {
+        let mut allow = HashSet::new();
+        let mut deny = HashSet::new();
+        for p in get_permissions(permission_entry.identifier(), acl)?
+          .into_iter()
+          .filter(|p| p.permission.is_active(&target))
+        {
+          allow.extend(
+            p.permission
+              .commands
+              .allow
+              .iter()
+              .map(|c| (p.key.clone(), c.clone())),
+          );
+          deny.extend(
+            p.permission
+              .commands
+              .deny
+              .iter()
+              .map(|c| (p.key.clone(), c.clone())),
+          );
+        }
+        entries.push((capability, permission_entry.identifier(), allow, deny));
+      }
+    }
+
+    for (capability, permission_id, allow, _) in &entries {
+      if allow.is_empty() {
+        continue;
+      }
+      for (deny_capability, deny_id, _, deny) in &entries {
+        if deny_id == permission_id
+          || !allow.is_subset(deny)
+          || !capabilities_overlap(capability, deny_capability)
+        {
+          continue;
+        }
+
+        let mut commands = allow.iter().map(|(_, c)| c.as_str()).collect::<Vec<_>>();
+        commands.sort_unstable();
+        warnings.push(format!(
+          "permission `{}` of capability `{}` is shadowed by `{}` of capability `{}`: all the commands it allows ({}) are denied, and a deny always takes precedence over an allow no matter how specific the allow is",
+          permission_id.get(),
+          capability.identifier,
+          deny_id.get(),
+          deny_capability.identifier,
+          commands.join(", ")
+        ));
+        break;
+      }
+    }
+
+    Ok(warnings)
+  }
+
+  /// Lists the capabilities that are not resolved on the given target because of their
+  /// [`Capability#structfield.platforms`], returning a message explaining why for each one.
+  pub fn skipped_capabilities(
+    capabilities: &BTreeMap<String, Capability>,
+    target: Target,
+  ) -> Vec<String> {
+    capabilities
+      .values()
+      .filter(|capability| !capability.is_active(&target))
+      .map(|capability| {
+        let platforms = capability
+          .platforms
+          .iter()
+          .flatten()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>();
+        format!(
+          "capability `{}` is skipped on {target}: it only applies to {}",
+          capability.identifier,
+          if platforms.is_empty() {
+            "no platform".to_string()
+          } else {
+            platforms.join(", ")
+          }
+        )
+      })
+      .collect()
+  }
+
+  /// The capabilities and permissions that explicitly denied the given command.
+  ///
+  /// Returns an empty list if the command is not denied.
+  #[cfg(debug_assertions)]
+  pub fn denied_by(&self, command: &str) -> Vec<&ResolvedCommandReference> {
+    self
+      .denied_commands
+      .get(command)
+      .map(|resolved| resolved.iter().map(|cmd| &cmd.referenced_by).collect())
+      .unwrap_or_default()
+  }
+
+  /// Computes a SHA-256 digest of this ACL, hex encoded.
+  ///
+  /// The digest only depends on what the ACL grants: commands, execution contexts,
+  /// window and webview patterns, scopes and runtime remote capabilities.
+  /// It does not depend on the order in which capabilities and permissions were declared,
+  /// on the internal scope keys, or on debug-only metadata,
+  /// so the same ACL produces the same digest on every platform and build profile.
+  pub fn digest(&self) -> String {
+    use sha2::{Digest, Sha256};
+
+    fn value(value: &Value) -> String {
+      serde_json::to_string(value).unwrap_or_default()
+    }
+
+    fn scope(scope: &ResolvedScope) -> String {
+      let mut allow = scope.allow.iter().map(value).collect::<Vec<_>>();
+      allow.sort();
+      let mut deny = scope.deny.iter().map(value).collect::<Vec<_>>();
+      deny.sort();
+      format!("allow[{}]deny[{}]", allow.join(","), deny.join(","))
+    }
+
+    fn capability(capability: &Capability) -> String {
+      // these lists are sets, so their declaration order must not change the digest
+      let mut capability = capability.clone();
+      capability.windows.sort();
+      capability.webviews.sort();
+      capability
+        .permissions
+        .sort_by_cached_key(|permission| serde_json::to_string(permission).unwrap_or_default());
+      if let Some(platforms) = &mut capability.platforms {
+        platforms.sort_by_cached_key(|platform| platform.to_string());
+      }
+      capability.enabled_on_features.sort();
+      if let Some(remote) = &mut capability.remote {
+        remote.urls.sort();
+        remote.runtime_urls.sort();
+      }
+      serde_json::to_string(&capability).unwrap_or_default()
+    }
+
+    fn commands(
+      commands: &BTreeMap<String, Vec<ResolvedCommand>>,
+      command_scope: &BTreeMap<ScopeKey, ResolvedScope>,
+    ) -> Vec<String> {
+      let mut entries = commands
+        .iter()
+        .flat_map(|(command, resolved)| {
+          resolved.iter().map(move |cmd| {
+            let context = match &cmd.context {
+              ExecutionContext::Local => "local".to_string(),
+              ExecutionContext::Remote { url } => format!("remote:{}", url.as_str()),
+            };
+            let mut windows = cmd.windows.iter().map(|w| w.as_str()).collect::<Vec<_>>();
+            windows.sort();
+            let mut webviews = cmd.webviews.iter().map(|w| w.as_str()).collect::<Vec<_>>();
+            webviews.sort();
+            let scope = cmd
+              .scope_id
+              .and_then(|id| command_scope.get(&id))
+              .map(scope)
+              .unwrap_or_default();
+            let max_calls_per_minute = cmd
+              .max_calls_per_minute
+              .map(|max| max.to_string())
+              .unwrap_or_default();
+            format!(
+              "{command}\0{context}\0{}\0{}\0{scope}\0{max_calls_per_minute}",
+              windows.join(","),
+              webviews.join(",")
+            )
+          })
+        })
+        .collect::<Vec<_>>();
+      entries.sort();
+      entries.dedup();
+      entries
+    }
+
+    let mut hasher = Sha256::new();
+    let mut section = |name: &str, entries: Vec<String>| {
+      hasher.update(name.as_bytes());
+      hasher.update((entries.len() as u64).to_le_bytes());
+      for entry in entries {
+        hasher.update((entry.len() as u64).to_le_bytes());
+        hasher.update(entry.as_bytes());
+      }
+    };
+
+    section(
+      "allowed_commands",
+      commands(&self.allowed_commands, &self.command_scope),
+    );
+    section(
+      "denied_commands",
+      commands(&self.denied_commands, &self.command_scope),
+    );
+    section(
+      "global_scope",
+      self
+        .global_scope
+        .iter()
+        .map(|(key, s)| format!("{key}\0{}", scope(s)))
+        .collect(),
+    );
+    section(
+      "runtime_remote_capabilities",
+      self
+        .runtime_remote_capabilities
+        .iter()
+        .map(|(identifier, c)| format!("{identifier}\0{}", capability(c)))
+        .collect(),
+    );
+
+    format!("{:x}", hasher.finalize())
+  }
+
+  /// Compares the allowed commands with an expected allowlist, e.g. one maintained for security reviews.
+  ///
+  /// Commands are named as in [`Self::allowed_commands`]: `plugin:<plugin>|<command>` for plugin commands
+  /// and the command name for app commands.
+  /// Denied commands are not subtracted from the allowed ones since a deny only applies
+  /// to the windows and execution contexts of its capability.
+  pub fn diff_allowlist<'a, I: IntoIterator<Item = &'a str>>(&self, expected: I) -> AllowlistDiff {
+    let expected = expected.into_iter().collect::<BTreeSet<_>>();
+
+    AllowlistDiff {
+      unexpected: self
+        .allowed_commands
+        .keys()
+        .filter(|command| !expected.contains(command.as_str()))
+        .cloned()
+        .collect(),
+      missing: expected
+        .into_iter()
+        .filter(|command| !self.allowed_commands.contains_key(*command))
+        .map(ToString::to_string)
+        .collect(),
+    }
+  }
+}
+
+/// The drift between a [`Resolved`] ACL and an expected allowlist, see [`Resolved::diff_allowlist`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowlistDiff {
+  /// Commands allowed by the ACL that are not in the allowlist.
+  pub unexpected: BTreeSet<String>,
+  /// Commands in the allowlist that the ACL does not allow.
+  pub missing: BTreeSet<String>,
+}
+
+impl AllowlistDiff {
+  /// Whether the ACL allows exactly the commands of the allowlist.
+  pub fn is_empty(&self) -> bool {
+    self.unexpected.is_empty() && self.missing.is_empty()
+  }
+}
+
+/// A canonical and serializable view of a [`Resolved`] ACL, meant to be used in snapshot tests.
+///
+/// Every list is sorted so the snapshot is the same across runs.
+#[cfg(feature = "test-util")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResolvedSnapshot {
+  /// The commands that are allowed.
+  pub allowed_commands: BTreeMap<String, Vec<ResolvedCommandSnapshot>>,
+  /// The commands that are denied.
+  pub denied_commands: BTreeMap<String, Vec<ResolvedCommandSnapshot>>,
+  /// The store of scopes referenced by a [`ResolvedCommandSnapshot`].
+  pub command_scope: BTreeMap<ScopeKey, ResolvedScope>,
+  /// The global scope.
+  pub global_scope: BTreeMap<String, ResolvedScope>,
+}
+
+/// A serializable view of a [`ResolvedCommand`].
+#[cfg(feature = "test-util")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub struct ResolvedCommandSnapshot {
+  /// The execution context, either `local` or `remote: <url pattern>`.
+  pub context: String,
+  /// The window label patterns.
+  pub windows: Vec<String>,
+  /// The webview label patterns.
+  pub webviews: Vec<String>,
+  /// The reference of the scope in [`ResolvedSnapshot#structfield.command_scope`].
+  pub scope_id: Option<ScopeKey>,
+}
+
+#[cfg(feature = "test-util")]
+impl Resolved {
+  /// Creates a canonical snapshot of this ACL.
+  pub fn snapshot(&self) -> ResolvedSnapshot {
+    fn commands(
+      commands: &BTreeMap<String, Vec<ResolvedCommand>>,
+    ) -> BTreeMap<String, Vec<ResolvedCommandSnapshot>> {
+      commands
+        .iter()
+        .map(|(command, resolved)| {
+          let mut resolved = resolved
+            .iter()
+            .map(|cmd| ResolvedCommandSnapshot {
+              context: match &cmd.context {
+                ExecutionContext::Local => "local".into(),
+                ExecutionContext::Remote { url } => format!("remote: {}", url.as_str()),
+              },
+              windows: cmd.windows.iter().map(|w| w.as_str().to_string()).collect(),
+              webviews: cmd
+                .webviews
+                .iter()
+                .map(|w| w.as_str().to_string())
+                .collect(),
+              scope_id: cmd.scope_id,
+            })
+            .collect::<Vec<_>>();
+          resolved.sort();
+          (command.clone(), resolved)
+        })
+        .collect()
+    }
+
+    ResolvedSnapshot {
+      allowed_commands: commands(&self.allowed_commands),
+      denied_commands: commands(&self.denied_commands),
+      command_scope: self.command_scope.clone(),
+      global_scope: self.global_scope.clone(),
+    }
+  }
+}
+
+/// Resolves the ACL for the given plugin permissions and app capabilities and returns its [`ResolvedSnapshot`].
+#[cfg(feature = "test-util")]
+pub fn resolve_snapshot(
+  acl: &BTreeMap<String, Manifest>,
+  capabilities: BTreeMap<String, Capability>,
+  target: Target,
+) -> Result<ResolvedSnapshot, Error> {
+  Resolved::resolve(acl, capabilities, target).map(|resolved| resolved.snapshot())
 }
 
 fn parse_glob_patterns(mut raw: Vec<String>) -> Result<Vec<glob::Pattern>, Error> {
@@ -207,6 +663,7 @@ fn resolve_command(
   commands: &mut BTreeMap<String, Vec<ResolvedCommand>>,
   command: String,
   capability: &Capability,
+  permission: &Permission,
   scope_id: Option<ScopeKey>,
   #[cfg(debug_assertions)] referenced_by_permission_identifier: String,
 ) -> Result<(), Error> {
@@ -224,7 +681,11 @@ fn resolve_command(
     }));
   }
 
-  for context in contexts {
+  // permissions restricted to an execution context are not resolved for the other contexts
+  for context in contexts
+    .into_iter()
+    .filter(|context| permission.is_active_in_context(context))
+  {
     let resolved_list = commands.entry(command.clone()).or_default();
 
     resolved_list.push(ResolvedCommand {
@@ -237,6 +698,7 @@ fn resolve_command(
       windows: parse_glob_patterns(capability.windows.clone())?,
       webviews: parse_glob_patterns(capability.webviews.clone())?,
       scope_id,
+      max_calls_per_minute: permission.max_calls_per_minute,
     });
   }
 
@@ -246,6 +708,7 @@ fn resolve_command(
 struct ResolvedPermission<'a> {
   key: &'a str,
   permission_name: &'a str,
+  permission: &'a Permission,
   commands: Commands,
   scope: Scopes,
 }
@@ -256,71 +719,177 @@ fn with_resolved_permissions<F: FnMut(ResolvedPermission<'_>) -> Result<(), Erro
   capability: &Capability,
   acl: &BTreeMap<String, Manifest>,
   target: Target,
+  profile: Profile,
   mut f: F,
 ) -> Result<(), Error> {
+  let is_active = |p: &TraversedPermission<'_>| {
+    p.permission.is_active(&target) && p.permission.is_active_in_profile(&profile)
+  };
+
+  let mut traversed_permissions = Vec::new();
+
   for permission_entry in &capability.permissions {
     let permission_id = permission_entry.identifier();
 
-    let permissions = get_permissions(permission_id, acl)?
-      .into_iter()
-      .filter(|p| p.permission.is_active(&target));
+    let permissions = get_permissions(permission_id, acl)?;
 
-    for TraversedPermission {
+    if let Some(requested) = permission_entry.version() {
+      let found = get_permission_version(permission_id, acl);
+      if found != Some(requested) {
+        return Err(Error::PermissionVersionMismatch {
+          permission: permission_id.get().to_string(),
+          requested,
+          found,
+        });
+      }
+    }
+
+    traversed_permissions.extend(
+      permissions
+        .into_iter()
+        .filter(|p| is_active(p))
+        .map(|p| (Some(permission_entry), p)),
+    );
+  }
+
+  // check the permissions required by the granted permissions,
+  // including the requirements of the automatically granted ones
+  let mut granted = traversed_permissions
+    .iter()
+    .map(|(_, p)| (p.key.clone(), p.permission_name.clone()))
+    .collect::<HashSet<_>>();
+  let mut index = 0;
+  while index < traversed_permissions.len() {
+    let (
+      _,
+      TraversedPermission {
+        key,
+        permission_name,
+        permission,
+      },
+    ) = &traversed_permissions[index];
+    let (key, permission_name, permission) = (key.clone(), permission_name.clone(), *permission);
+    index += 1;
+
+    for required in &permission.requires {
+      let required_id = required_permission_id(&key, required)?;
+      let missing = get_permissions(&required_id, acl)?
+        .into_iter()
+        .filter(|p| is_active(p) && !granted.contains(&(p.key.clone(), p.permission_name.clone())))
+        .collect::<Vec<_>>();
+
+      if missing.is_empty() {
+        continue;
+      }
+
+      if !capability.include_required_permissions {
+        return Err(Error::MissingRequiredPermission {
+          capability: capability.identifier.clone(),
+          permission: if key == APP_ACL_KEY {
+            permission_name
+          } else {
+            format!("{key}:{permission_name}")
+          },
+          required: required_id.get().to_string(),
+        });
+      }
+
+      for p in missing {
+        granted.insert((p.key.clone(), p.permission_name.clone()));
+        traversed_permissions.push((None, p));
+      }
+    }
+  }
+
+  for (
+    permission_entry,
+    TraversedPermission {
       key,
       permission_name,
       permission,
-    } in permissions
-    {
-      let mut resolved_scope = Scopes::default();
-      let mut commands = Commands::default();
-
-      if let PermissionEntry::ExtendedPermission {
-        identifier: _,
-        scope,
-      } = permission_entry
-      {
-        if let Some(allow) = scope.allow.clone() {
-          resolved_scope
-            .allow
-            .get_or_insert_with(Default::default)
-            .extend(allow);
-        }
-        if let Some(deny) = scope.deny.clone() {
-          resolved_scope
-            .deny
-            .get_or_insert_with(Default::default)
-            .extend(deny);
-        }
-      }
-
-      if let Some(allow) = permission.scope.allow.clone() {
+    },
+  ) in traversed_permissions
+  {
+    let mut resolved_scope = Scopes::default();
+    let mut commands = Commands::default();
+
+    if let Some(PermissionEntry::ExtendedPermission { scope, .. }) = permission_entry {
+      if let Some(allow) = scope.allow.clone() {
         resolved_scope
           .allow
           .get_or_insert_with(Default::default)
           .extend(allow);
       }
-      if let Some(deny) = permission.scope.deny.clone() {
+      if let Some(deny) = scope.deny.clone() {
         resolved_scope
           .deny
           .get_or_insert_with(Default::default)
           .extend(deny);
       }
+    }
+
+    if let Some(allow) = permission.scope.allow.clone() {
+      resolved_scope
+        .allow
+        .get_or_insert_with(Default::default)
+        .extend(allow);
+    }
+    if let Some(deny) = permission.scope.deny.clone() {
+      resolved_scope
+        .deny
+        .get_or_insert_with(Default::default)
+        .extend(deny);
+    }
 
-      commands.allow.extend(permission.commands.allow.clone());
-      commands.deny.extend(permission.commands.deny.clone());
+    // capabilities without variables keep their scopes as is, so existing braces are never reinterpreted
+    if !capability.variables.is_empty() {
+      for values in [&mut resolved_scope.allow, &mut resolved_scope.deny]
+        .into_iter()
+        .flatten()
+      {
+        for value in values {
+          *value = value.substitute_variables(&capability.variables)?;
+        }
+      }
+    }
 
-      f(ResolvedPermission {
-        key: &key,
-        permission_name: &permission_name,
-        commands,
-        scope: resolved_scope,
-      })?;
+    for (resolved, listed) in [
+      (&mut commands.allow, &permission.commands.allow),
+      (&mut commands.deny, &permission.commands.deny),
+    ] {
+      if listed.iter().any(|c| c == ALL_COMMANDS) {
+        resolved.extend(acl[&key].commands().into_iter().map(ToString::to_string));
+      } else {
+        resolved.extend(listed.clone());
+      }
     }
+
+    f(ResolvedPermission {
+      key: &key,
+      permission_name: &permission_name,
+      permission,
+      commands,
+      scope: resolved_scope,
+    })?;
   }
 
   Ok(())
 }
 
+/// The identifier of a permission required by a permission of the `key` manifest,
+/// resolving identifiers without a prefix to the same manifest.
+fn required_permission_id(key: &str, required: &str) -> Result<Identifier, Error> {
+  let id = if key == APP_ACL_KEY || required.contains(':') {
+    required.to_string()
+  } else {
+    format!("{key}:{required}")
+  };
+  Identifier::try_from(id).map_err(|_| Error::InvalidPermissionIdentifier {
+    identifier: required.to_string(),
+    reason: "the required permission identifier is invalid",
+  })
+}
+
 #[derive(Debug)]
 struct TraversedPermission<'a> {
   key: String,
@@ -328,9 +897,57 @@ struct TraversedPermission<'a> {
   permission: &'a Permission,
 }
 
+/// Whether a command denied by one of the capabilities can also be denied for a window allowed by the other.
+fn capabilities_overlap(a: &Capability, b: &Capability) -> bool {
+  fn patterns_overlap(a: &[String], b: &[String]) -> bool {
+    let matches = |pattern: &str, label: &str| {
+      glob::Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(label))
+    };
+    a.iter()
+      .any(|a| b.iter().any(|b| a == b || matches(a, b) || matches(b, a)))
+  }
+
+  let same_context = (a.local && b.local) || (a.remote.is_some() && b.remote.is_some());
+  same_context
+    && (patterns_overlap(&a.windows, &b.windows) || patterns_overlap(&a.webviews, &b.webviews))
+}
+
+/// Expands the given permission identifier into the permissions it references.
+///
+/// Permission sets (including the default permission) are expanded in declaration order, recursively,
+/// and a permission referenced more than once is only kept at its first occurrence.
+/// This keeps the resolved ACL, and the code generated from it, identical across builds.
 fn get_permissions<'a>(
   permission_id: &Identifier,
   acl: &'a BTreeMap<String, Manifest>,
+) -> Result<Vec<TraversedPermission<'a>>, Error> {
+  let mut permissions = expand_permission(permission_id, acl)?;
+  let mut seen = HashSet::new();
+  permissions.retain(|p| seen.insert((p.key.clone(), p.permission_name.clone())));
+  Ok(permissions)
+}
+
+/// The version of the permission or permission set referenced by the given identifier.
+fn get_permission_version(
+  permission_id: &Identifier,
+  acl: &BTreeMap<String, Manifest>,
+) -> Option<NonZeroU64> {
+  let key = permission_id.get_prefix().unwrap_or(APP_ACL_KEY);
+  let permission_name = permission_id.get_base();
+  let manifest = acl.get(key)?;
+
+  if permission_name == "default" {
+    manifest.default_permission.as_ref()?.version
+  } else if let Some(set) = manifest.permission_sets.get(permission_name) {
+    set.version
+  } else {
+    manifest.permissions.get(permission_name)?.version
+  }
+}
+
+fn expand_permission<'a>(
+  permission_id: &Identifier,
+  acl: &'a BTreeMap<String, Manifest>,
 ) -> Result<Vec<TraversedPermission<'a>>, Error> {
   let key = permission_id.get_prefix().unwrap_or(APP_ACL_KEY);
   let permission_name = permission_id.get_base();
@@ -468,6 +1085,10 @@ mod build {
         quote!(#w.parse().unwrap())
       });
       let scope_id = opt_lit(self.scope_id.as_ref());
+      let max_calls_per_minute = opt_lit_owned(self.max_calls_per_minute.as_ref().map(|v| {
+        let v = v.get();
+        quote!(::core::num::NonZeroU32::new(#v).unwrap())
+      }));
 
       #[cfg(debug_assertions)]
       {
@@ -478,7 +1099,8 @@ mod build {
           referenced_by,
           windows,
           webviews,
-          scope_id
+          scope_id,
+          max_calls_per_minute
         )
       }
       #[cfg(not(debug_assertions))]
@@ -488,7 +1110,8 @@ mod build {
         context,
         windows,
         webviews,
-        scope_id
+        scope_id,
+        max_calls_per_minute
       )
     }
   }
@@ -536,13 +1159,21 @@ mod build {
         identity,
       );
 
+      let runtime_remote_capabilities = map_lit(
+        quote! { ::std::collections::BTreeMap },
+        &self.runtime_remote_capabilities,
+        str_lit,
+        identity,
+      );
+
       literal_struct!(
         tokens,
         ::tauri::utils::acl::resolved::Resolved,
         allowed_commands,
         denied_commands,
         command_scope,
-        global_scope
+        global_scope,
+        runtime_remote_capabilities
       )
     }
   }
@@ -551,7 +1182,11 @@ mod build {
 #[cfg(test)]
 mod tests {
 
-  use super::{get_permissions, Identifier, Manifest, Permission, PermissionSet};
+  use super::{
+    get_permissions, Capability, Identifier, Manifest, Permission, PermissionEntry, PermissionSet,
+  };
+  #[cfg(debug_assertions)]
+  use super::{Commands, Resolved, Target};
 
   fn manifest<const P: usize, const S: usize>(
     name: &str,
@@ -564,6 +1199,7 @@ mod tests {
       Manifest {
         default_permission: default_set.map(|perms| PermissionSet {
           identifier: "default".to_string(),
+          version: None,
           description: "default set".to_string(),
           permissions: perms.iter().map(|s| s.to_string()).collect(),
         }),
@@ -585,6 +1221,7 @@ mod tests {
             (
               s.to_string(),
               PermissionSet {
+                version: None,
                 identifier: s.to_string(),
                 description: format!("{s} set"),
                 permissions: perms.iter().map(|s| s.to_string()).collect(),
@@ -601,6 +1238,18 @@ mod tests {
     Identifier::try_from(id.to_string()).unwrap()
   }
 
+  /// A capability for the `main` window granting the given permissions.
+  fn main_window_capability(identifier: &str, permissions: Vec<PermissionEntry>) -> Capability {
+    Capability {
+      permissions,
+      ..super::super::capability::CapabilityBuilder::new()
+        .identifier(identifier)
+        .window("main")
+        .build()
+        .unwrap()
+    }
+  }
+
   #[test]
   fn resolves_permissions_from_other_plugins() {
     let acl = [
@@ -668,4 +1317,1156 @@ mod tests {
     assert_eq!(permissions[5].key, "http");
     assert_eq!(permissions[5].permission_name, "fetch-cancel");
   }
+
+  #[test]
+  fn resolves_permission_sets_from_default() {
+    let acl = [manifest(
+      "fs",
+      ["read", "exist", "write"],
+      Some(&["read-all"]),
+      [("read-all", &["read", "exist"])],
+    )]
+    .into();
+
+    let permissions = get_permissions(&id("fs:default"), &acl).unwrap();
+    assert_eq!(permissions.len(), 2);
+    assert_eq!(permissions[0].key, "fs");
+    assert_eq!(permissions[0].permission_name, "read");
+    assert_eq!(permissions[1].key, "fs");
+    assert_eq!(permissions[1].permission_name, "exist");
+  }
+
+  #[test]
+  fn expands_default_permission_in_declaration_order() {
+    let acl = [
+      manifest(
+        "fs",
+        ["read", "exist", "write", "rm"],
+        Some(&["write", "read-all", "read", "http:default", "rm", "exist"]),
+        [("read-all", &["read", "exist"])],
+      ),
+      manifest("http", ["fetch"], Some(&["fetch"]), []),
+    ]
+    .into();
+
+    let permissions = get_permissions(&id("fs:default"), &acl).unwrap();
+    let names = permissions
+      .iter()
+      .map(|p| format!("{}:{}", p.key, p.permission_name))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      names,
+      ["fs:write", "fs:read", "fs:exist", "http:fetch", "fs:rm"]
+    );
+  }
+
+  #[cfg(feature = "build")]
+  #[test]
+  fn resolution_is_reproducible() {
+    use super::{Resolved, Target};
+    use quote::ToTokens;
+
+    let acl = [
+      manifest(
+        "fs",
+        ["read", "exist", "write"],
+        Some(&["read-all", "write", "read", "read-all"]),
+        [("read-all", &["read", "exist"])],
+      ),
+      manifest("http", ["fetch"], Some(&["fetch", "fs:default"]), []),
+    ]
+    .into();
+
+    let capabilities = || {
+      [(
+        "main".to_string(),
+        main_window_capability(
+          "main",
+          vec![
+            PermissionEntry::PermissionRef(id("http:default")),
+            PermissionEntry::PermissionRef(id("fs:default")),
+          ],
+        ),
+      )]
+      .into()
+    };
+
+    let tokens = Resolved::resolve(&acl, capabilities(), Target::current())
+      .unwrap()
+      .to_token_stream()
+      .to_string();
+    for _ in 0..10 {
+      let resolved = Resolved::resolve(&acl, capabilities(), Target::current()).unwrap();
+      assert_eq!(resolved.to_token_stream().to_string(), tokens);
+    }
+  }
+
+  #[cfg(debug_assertions)]
+  #[test]
+  fn records_denying_source() {
+    let permission = |identifier: &str, commands: Commands| {
+      (
+        identifier.to_string(),
+        Permission {
+          identifier: identifier.to_string(),
+          commands,
+          ..Default::default()
+        },
+      )
+    };
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [
+          permission(
+            "allow-read",
+            Commands {
+              allow: vec!["read".into()],
+              deny: Vec::new(),
+            },
+          ),
+          permission(
+            "deny-read",
+            Commands {
+              allow: Vec::new(),
+              deny: vec!["read".into()],
+            },
+          ),
+        ]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capability = |identifier: &str, permission: &str| {
+      (
+        identifier.to_string(),
+        main_window_capability(
+          identifier,
+          vec![PermissionEntry::PermissionRef(id(permission))],
+        ),
+      )
+    };
+    let capabilities = [
+      capability("reader", "fs:allow-read"),
+      capability("locked-down", "fs:deny-read"),
+    ]
+    .into();
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::current()).unwrap();
+
+    let denied_by = resolved.denied_by("plugin:fs|read");
+    assert_eq!(denied_by.len(), 1);
+    assert_eq!(denied_by[0].capability, "locked-down");
+    assert_eq!(denied_by[0].permission, "deny-read");
+
+    assert!(resolved.denied_by("plugin:fs|write").is_empty());
+  }
+
+  #[test]
+  fn filters_permissions_by_profile() {
+    use super::{Commands, Profile, Resolved, Target};
+
+    let permission = |identifier: &str, profiles: Option<Vec<Profile>>| {
+      (
+        identifier.to_string(),
+        Permission {
+          identifier: identifier.to_string(),
+          commands: Commands {
+            allow: vec![identifier.to_string()],
+            deny: Vec::new(),
+          },
+          profiles,
+          ..Default::default()
+        },
+      )
+    };
+    let acl = [(
+      "webview".to_string(),
+      Manifest {
+        permissions: [
+          permission("devtools", Some(vec![Profile::Debug])),
+          permission("print", None),
+        ]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capabilities = || {
+      [(
+        "main".to_string(),
+        main_window_capability(
+          "main",
+          vec![
+            PermissionEntry::PermissionRef(id("webview:devtools")),
+            PermissionEntry::PermissionRef(id("webview:print")),
+          ],
+        ),
+      )]
+      .into()
+    };
+
+    let debug =
+      Resolved::resolve_for_profile(&acl, capabilities(), Target::current(), Profile::Debug)
+        .unwrap();
+    assert!(debug
+      .allowed_commands
+      .contains_key("plugin:webview|devtools"));
+    assert!(debug.allowed_commands.contains_key("plugin:webview|print"));
+
+    let release =
+      Resolved::resolve_for_profile(&acl, capabilities(), Target::current(), Profile::Release)
+        .unwrap();
+    assert!(!release
+      .allowed_commands
+      .contains_key("plugin:webview|devtools"));
+    assert!(release
+      .allowed_commands
+      .contains_key("plugin:webview|print"));
+  }
+
+  #[test]
+  fn filters_permissions_by_execution_context() {
+    use super::{Commands, ExecutionContext, Resolved, Target};
+    use crate::acl::{capability::CapabilityRemote, ExecutionContextKind};
+
+    let permission = |identifier: &str, execution_context: Option<ExecutionContextKind>| {
+      (
+        identifier.to_string(),
+        Permission {
+          identifier: identifier.to_string(),
+          commands: Commands {
+            allow: vec![identifier.to_string()],
+            deny: Vec::new(),
+          },
+          execution_context,
+          ..Default::default()
+        },
+      )
+    };
+    let acl = [(
+      "webview".to_string(),
+      Manifest {
+        permissions: [
+          permission("remote-only", Some(ExecutionContextKind::Remote)),
+          permission("print", None),
+        ]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capabilities = [(
+      "main".to_string(),
+      Capability {
+        remote: Some(CapabilityRemote {
+          urls: vec!["https://tauri.app".into()],
+          runtime_urls: Vec::new(),
+        }),
+        ..main_window_capability(
+          "main",
+          vec![
+            PermissionEntry::PermissionRef(id("webview:remote-only")),
+            PermissionEntry::PermissionRef(id("webview:print")),
+          ],
+        )
+      },
+    )]
+    .into();
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::current()).unwrap();
+    let contexts = |command: &str| {
+      resolved.allowed_commands[command]
+        .iter()
+        .map(|c| c.context.clone())
+        .collect::<Vec<_>>()
+    };
+
+    let remote = ExecutionContext::Remote {
+      url: "https://tauri.app".parse().unwrap(),
+    };
+    let remote_only = contexts("plugin:webview|remote-only");
+    assert_eq!(remote_only, vec![remote.clone()]);
+    assert!(!remote_only.contains(&ExecutionContext::Local));
+
+    let print = contexts("plugin:webview|print");
+    assert_eq!(print, vec![ExecutionContext::Local, remote]);
+  }
+
+  #[test]
+  fn digest_is_stable() {
+    use super::{Commands, Resolved, Scopes, Target, Value};
+    use crate::acl::capability::CapabilityRemote;
+
+    let acl = |deny_rm: bool| {
+      let permission = |identifier: &str, allow: &[&str], deny: &[&str]| {
+        (
+          identifier.to_string(),
+          Permission {
+            identifier: identifier.to_string(),
+            commands: Commands {
+              allow: allow.iter().map(|c| c.to_string()).collect(),
+              deny: deny.iter().map(|c| c.to_string()).collect(),
+            },
+            ..Default::default()
+          },
+        )
+      };
+      [(
+        "fs".to_string(),
+        Manifest {
+          permissions: [
+            permission("read", &["read"], &[]),
+            permission("write", &["write"], if deny_rm { &["rm"] } else { &[] }),
+          ]
+          .into(),
+          ..Default::default()
+        },
+      )]
+      .into()
+    };
+
+    let scoped = |identifier: &str, path: &str| PermissionEntry::ExtendedPermission {
+      identifier: id(identifier),
+      version: None,
+      scope: Scopes {
+        allow: Some(vec![Value::String(path.into())]),
+        deny: None,
+        include: Vec::new(),
+      },
+    };
+    let capabilities = |permissions: Vec<PermissionEntry>| {
+      [(
+        "main".to_string(),
+        main_window_capability("main", permissions),
+      )]
+      .into()
+    };
+
+    let digest = |deny_rm: bool, permissions: Vec<PermissionEntry>| {
+      Resolved::resolve(&acl(deny_rm), capabilities(permissions), Target::current())
+        .unwrap()
+        .digest()
+    };
+
+    let expected = digest(
+      false,
+      vec![
+        scoped("fs:read", "$HOME/*"),
+        scoped("fs:write", "$APPDATA/*"),
+      ],
+    );
+    assert_eq!(expected.len(), 64);
+    // the scope keys are assigned in declaration order, but the digest must not depend on them
+    assert_eq!(
+      digest(
+        false,
+        vec![
+          scoped("fs:write", "$APPDATA/*"),
+          scoped("fs:read", "$HOME/*")
+        ],
+      ),
+      expected
+    );
+    assert_ne!(
+      digest(
+        true,
+        vec![
+          scoped("fs:read", "$HOME/*"),
+          scoped("fs:write", "$APPDATA/*")
+        ],
+      ),
+      expected
+    );
+    assert_ne!(
+      digest(
+        false,
+        vec![
+          scoped("fs:read", "$APPDATA/*"),
+          scoped("fs:write", "$HOME/*")
+        ],
+      ),
+      expected
+    );
+
+    let remote_digest = |permissions: Vec<PermissionEntry>, platforms: Vec<Target>| {
+      let mut capability = main_window_capability("remote", permissions);
+      capability.remote = Some(CapabilityRemote {
+        urls: Vec::new(),
+        runtime_urls: vec!["https://*.tauri.app".into()],
+      });
+      capability.platforms = Some(platforms);
+      Resolved::resolve(
+        &acl(false),
+        [("remote".to_string(), capability)].into(),
+        Target::current(),
+      )
+      .unwrap()
+      .digest()
+    };
+    let platforms = vec![
+      Target::MacOS,
+      Target::Windows,
+      Target::Linux,
+      Target::Android,
+      Target::Ios,
+    ];
+    let expected = remote_digest(
+      vec![
+        PermissionEntry::PermissionRef(id("fs:read")),
+        PermissionEntry::PermissionRef(id("fs:write")),
+      ],
+      platforms.clone(),
+    );
+    // the runtime remote capabilities must not depend on the declaration order either
+    assert_eq!(
+      remote_digest(
+        vec![
+          PermissionEntry::PermissionRef(id("fs:write")),
+          PermissionEntry::PermissionRef(id("fs:read"))
+        ],
+        platforms.into_iter().rev().collect()
+      ),
+      expected
+    );
+  }
+
+  #[cfg(feature = "test-util")]
+  #[test]
+  fn snapshot_is_sorted() {
+    use super::{resolve_snapshot, Commands, Target};
+
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [(
+          "read".to_string(),
+          Permission {
+            identifier: "read".to_string(),
+            commands: Commands {
+              allow: vec!["read".into()],
+              deny: Vec::new(),
+            },
+            ..Default::default()
+          },
+        )]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+    let capability = |identifier: &str, windows: &[&str], remote: Option<&str>| {
+      (
+        identifier.to_string(),
+        Capability {
+          remote: remote.map(|url| super::super::capability::CapabilityRemote {
+            urls: vec![url.to_string()],
+            runtime_urls: Vec::new(),
+          }),
+          local: remote.is_none(),
+          windows: windows.iter().map(|w| w.to_string()).collect(),
+          ..main_window_capability(
+            identifier,
+            vec![PermissionEntry::PermissionRef(id("fs:read"))],
+          )
+        },
+      )
+    };
+    let capabilities = [
+      capability("remote", &["main"], Some("https://tauri.app")),
+      capability("secondary", &["secondary-*", "other"], None),
+      capability("main", &["main"], None),
+    ]
+    .into_iter()
+    .collect();
+
+    let snapshot = resolve_snapshot(&acl, capabilities, Target::current()).unwrap();
+    assert!(snapshot.denied_commands.is_empty());
+    assert_eq!(
+      serde_json::to_value(&snapshot.allowed_commands).unwrap(),
+      serde_json::json!({
+        "plugin:fs|read": [
+          { "context": "local", "windows": ["main"], "webviews": [], "scope_id": null },
+          { "context": "local", "windows": ["other", "secondary-*"], "webviews": [], "scope_id": null },
+          { "context": "remote: https://tauri.app", "windows": ["main"], "webviews": [], "scope_id": null },
+        ]
+      })
+    );
+  }
+
+  #[test]
+  fn checks_requested_permission_set_version() {
+    use std::num::NonZeroU64;
+
+    use super::{Error, Resolved, Target};
+
+    let mut acl: std::collections::BTreeMap<String, Manifest> = [manifest(
+      "fs",
+      ["read", "exist"],
+      Some(&["read-all"]),
+      [("read-all", &["read", "exist"])],
+    )]
+    .into();
+    acl
+      .get_mut("fs")
+      .unwrap()
+      .permission_sets
+      .get_mut("read-all")
+      .unwrap()
+      .version = NonZeroU64::new(2);
+
+    let capabilities = |permission: &str, version: u64| {
+      [(
+        "main".to_string(),
+        main_window_capability(
+          "main",
+          vec![PermissionEntry::ExtendedPermission {
+            identifier: id(permission),
+            version: NonZeroU64::new(version),
+            scope: Default::default(),
+          }],
+        ),
+      )]
+      .into()
+    };
+
+    assert!(Resolved::resolve(&acl, capabilities("fs:read-all", 2), Target::current()).is_ok());
+
+    assert!(matches!(
+      Resolved::resolve(&acl, capabilities("fs:read-all", 1), Target::current()),
+      Err(Error::PermissionVersionMismatch { permission, requested, found })
+        if permission == "fs:read-all" && requested.get() == 1 && found == NonZeroU64::new(2)
+    ));
+
+    assert!(matches!(
+      Resolved::resolve(&acl, capabilities("fs:default", 1), Target::current()),
+      Err(Error::PermissionVersionMismatch { found: None, .. })
+    ));
+  }
+
+  #[test]
+  fn lints_permissions_excluded_on_target() {
+    use super::super::TargetFilter;
+    use super::{Resolved, Target};
+
+    let mut acl: std::collections::BTreeMap<String, Manifest> = [manifest(
+      "fs",
+      ["read", "exist"],
+      None,
+      [
+        ("windows-only", &["read"]),
+        ("read-all", &["read", "exist"]),
+      ],
+    )]
+    .into();
+    let read = acl
+      .get_mut("fs")
+      .unwrap()
+      .permissions
+      .get_mut("read")
+      .unwrap();
+    read.commands.allow = vec!["read".into()];
+    read.platforms = Some(TargetFilter::Only(vec![Target::Windows]));
+
+    let capabilities = [(
+      "main".to_string(),
+      main_window_capability(
+        "main",
+        ["fs:read", "fs:windows-only", "fs:read-all", "fs:exist"]
+          .into_iter()
+          .map(|p| PermissionEntry::PermissionRef(id(p)))
+          .collect(),
+      ),
+    )]
+    .into();
+
+    assert_eq!(
+      Resolved::lint(&acl, &capabilities, Target::Linux).unwrap(),
+      vec![
+        "permission `fs:read` of capability `main` grants nothing on linux: all of its permissions are restricted to other platforms".to_string(),
+        "permission `fs:windows-only` of capability `main` grants nothing on linux: all of its permissions are restricted to other platforms".to_string(),
+      ]
+    );
+    assert!(Resolved::lint(&acl, &capabilities, Target::Windows)
+      .unwrap()
+      .is_empty());
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::Linux).unwrap();
+    assert!(!resolved.allowed_commands.contains_key("plugin:fs|read"));
+  }
+
+  #[test]
+  fn lints_allows_shadowed_by_deny() {
+    let mut acl: std::collections::BTreeMap<String, Manifest> = [manifest(
+      "fs",
+      [
+        "allow-read",
+        "allow-write",
+        "allow-all",
+        "deny-read",
+        "deny-all",
+      ],
+      None,
+      [],
+    )]
+    .into();
+    let fs = acl.get_mut("fs").unwrap();
+    for (permission, allow, deny) in [
+      ("allow-read", &["read"][..], &[][..]),
+      ("allow-write", &["write"], &[]),
+      ("allow-all", &["read", "write"], &[]),
+      ("deny-read", &[], &["read"]),
+      ("deny-all", &[], &["read", "write"]),
+    ] {
+      let permission = fs.permissions.get_mut(permission).unwrap();
+      permission.commands.allow = allow.iter().map(|c| c.to_string()).collect();
+      permission.commands.deny = deny.iter().map(|c| c.to_string()).collect();
+    }
+
+    let capability = |identifier: &str, window: &str, permissions: &[&str]| {
+      (
+        identifier.to_string(),
+        Capability {
+          windows: vec![window.into()],
+          ..main_window_capability(
+            identifier,
+            permissions
+              .iter()
+              .map(|p| PermissionEntry::PermissionRef(id(p)))
+              .collect(),
+          )
+        },
+      )
+    };
+
+    let capabilities = [
+      capability("main", "main", &["fs:allow-read", "fs:deny-all"]),
+      capability("other", "other", &["fs:allow-write"]),
+      capability("narrow-deny", "main-*", &["fs:allow-all", "fs:deny-read"]),
+      capability("all", "*", &["fs:allow-write"]),
+    ]
+    .into();
+
+    assert_eq!(
+      Resolved::lint(&acl, &capabilities, Target::Linux).unwrap(),
+      vec![
+        "permission `fs:allow-write` of capability `all` is shadowed by `fs:deny-all` of capability `main`: all the commands it allows (write) are denied, and a deny always takes precedence over an allow no matter how specific the allow is".to_string(),
+        "permission `fs:allow-read` of capability `main` is shadowed by `fs:deny-all` of capability `main`: all the commands it allows (read) are denied, and a deny always takes precedence over an allow no matter how specific the allow is".to_string(),
+      ]
+    );
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::Linux).unwrap();
+    assert!(resolved.denied_commands.contains_key("plugin:fs|read"));
+  }
+
+  #[test]
+  fn reports_capabilities_skipped_on_target() {
+    use super::{Resolved, Target};
+
+    let capability = |identifier: &str, platforms: Option<Vec<Target>>| {
+      (
+        identifier.to_string(),
+        Capability {
+          platforms,
+          ..main_window_capability(
+            identifier,
+            vec![PermissionEntry::PermissionRef(id("fs:allow-read"))],
+          )
+        },
+      )
+    };
+    let capabilities = [
+      capability(
+        "desktop",
+        Some(vec![Target::MacOS, Target::Windows, Target::Linux]),
+      ),
+      capability("mobile", Some(vec![Target::Android, Target::Ios])),
+      capability("main", None),
+    ]
+    .into();
+
+    assert_eq!(
+      Resolved::skipped_capabilities(&capabilities, Target::Android),
+      vec!["capability `desktop` is skipped on android: it only applies to macOS, windows, linux"]
+    );
+    assert_eq!(
+      Resolved::skipped_capabilities(&capabilities, Target::Linux),
+      vec!["capability `mobile` is skipped on linux: it only applies to android, iOS"]
+    );
+  }
+
+  #[test]
+  fn lints_empty_capability() {
+    use super::{Resolved, Target};
+
+    let acl: std::collections::BTreeMap<String, Manifest> =
+      [manifest("fs", ["read"], None, [])].into();
+
+    let capabilities =
+      |permissions: Vec<PermissionEntry>| -> std::collections::BTreeMap<String, Capability> {
+        [(
+          "main".to_string(),
+          main_window_capability("main", permissions),
+        )]
+        .into()
+      };
+
+    let empty = capabilities(Vec::new());
+    assert!(Resolved::resolve(&acl, empty.clone(), Target::current()).is_ok());
+    assert_eq!(
+      Resolved::lint(&acl, &empty, Target::current()).unwrap(),
+      vec!["capability `main` grants nothing: it does not reference any permission"]
+    );
+
+    // capabilities that do not apply to the target are not reported
+    let mut inactive = empty;
+    inactive.get_mut("main").unwrap().platforms = Some(vec![Target::Windows]);
+    assert!(Resolved::lint(&acl, &inactive, Target::Linux)
+      .unwrap()
+      .is_empty());
+
+    let populated = capabilities(vec![PermissionEntry::PermissionRef(id("fs:read"))]);
+    assert!(Resolved::lint(&acl, &populated, Target::current())
+      .unwrap()
+      .is_empty());
+  }
+
+  #[test]
+  fn resolves_required_permissions() {
+    use super::{Commands, Error, Resolved, Target};
+
+    let permission = |command: &str, requires: &[&str]| {
+      (
+        format!("allow-{command}"),
+        Permission {
+          identifier: format!("allow-{command}"),
+          commands: Commands {
+            allow: vec![command.into()],
+            deny: Vec::new(),
+          },
+          requires: requires.iter().map(|r| r.to_string()).collect(),
+          ..Default::default()
+        },
+      )
+    };
+    // fs:allow-write -> fs:allow-read -> path:allow-resolve
+    let acl = [
+      (
+        "fs".to_string(),
+        Manifest {
+          permissions: [
+            permission("write", &["allow-read"]),
+            permission("read", &["path:allow-resolve"]),
+          ]
+          .into(),
+          ..Default::default()
+        },
+      ),
+      (
+        "path".to_string(),
+        Manifest {
+          permissions: [permission("resolve", &[])].into(),
+          ..Default::default()
+        },
+      ),
+    ]
+    .into();
+
+    let capabilities = |permissions: &[&str], include_required_permissions: bool| {
+      [(
+        "main".to_string(),
+        Capability {
+          include_required_permissions,
+          ..main_window_capability(
+            "main",
+            permissions
+              .iter()
+              .map(|p| PermissionEntry::PermissionRef(id(p)))
+              .collect(),
+          )
+        },
+      )]
+      .into()
+    };
+
+    assert!(matches!(
+      Resolved::resolve(&acl, capabilities(&["fs:allow-write"], false), Target::current()),
+      Err(Error::MissingRequiredPermission { capability, permission, required })
+        if capability == "main" && permission == "fs:allow-write" && required == "fs:allow-read"
+    ));
+    assert!(matches!(
+      Resolved::resolve(
+        &acl,
+        capabilities(&["fs:allow-write", "fs:allow-read"], false),
+        Target::current()
+      ),
+      Err(Error::MissingRequiredPermission { permission, required, .. })
+        if permission == "fs:allow-read" && required == "path:allow-resolve"
+    ));
+    assert!(Resolved::resolve(
+      &acl,
+      capabilities(
+        &["fs:allow-write", "fs:allow-read", "path:allow-resolve"],
+        false
+      ),
+      Target::current()
+    )
+    .is_ok());
+
+    let resolved = Resolved::resolve(
+      &acl,
+      capabilities(&["fs:allow-write"], true),
+      Target::current(),
+    )
+    .unwrap();
+    for command in ["plugin:fs|write", "plugin:fs|read", "plugin:path|resolve"] {
+      assert!(resolved.allowed_commands.contains_key(command), "{command}");
+    }
+  }
+
+  #[test]
+  fn resolves_all_commands_wildcard() {
+    use super::{Commands, Resolved, Target};
+
+    let permission = |identifier: &str, allow: &[&str], deny: &[&str]| {
+      (
+        identifier.to_string(),
+        Permission {
+          identifier: identifier.to_string(),
+          commands: Commands {
+            allow: allow.iter().map(|c| c.to_string()).collect(),
+            deny: deny.iter().map(|c| c.to_string()).collect(),
+          },
+          ..Default::default()
+        },
+      )
+    };
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [
+          permission("allow-read", &["read"], &[]),
+          permission("allow-write", &["write"], &[]),
+          permission("deny-remove", &[], &["remove"]),
+          permission("allow-all-but-remove", &["*"], &["remove"]),
+        ]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capabilities = [(
+      "main".to_string(),
+      main_window_capability(
+        "main",
+        vec![PermissionEntry::PermissionRef(id(
+          "fs:allow-all-but-remove",
+        ))],
+      ),
+    )]
+    .into();
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::current()).unwrap();
+    assert_eq!(
+      resolved.allowed_commands.keys().collect::<Vec<_>>(),
+      ["plugin:fs|read", "plugin:fs|remove", "plugin:fs|write"]
+    );
+    assert!(!resolved.allowed_commands.contains_key("plugin:fs|*"));
+    // the deny list still applies, and a deny takes precedence over the allow
+    assert_eq!(
+      resolved.denied_commands.keys().collect::<Vec<_>>(),
+      ["plugin:fs|remove"]
+    );
+  }
+
+  #[test]
+  fn resolves_deny_all_commands_wildcard() {
+    use super::{Commands, Resolved, Target};
+
+    let permission = |identifier: &str, allow: &[&str], deny: &[&str]| {
+      (
+        identifier.to_string(),
+        Permission {
+          identifier: identifier.to_string(),
+          commands: Commands {
+            allow: allow.iter().map(|c| c.to_string()).collect(),
+            deny: deny.iter().map(|c| c.to_string()).collect(),
+          },
+          ..Default::default()
+        },
+      )
+    };
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [
+          permission("allow-read", &["read"], &[]),
+          permission("allow-write", &["write"], &[]),
+          permission("deny-all", &[], &["*"]),
+        ]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capabilities = [(
+      "main".to_string(),
+      main_window_capability(
+        "main",
+        vec![
+          PermissionEntry::PermissionRef(id("fs:allow-read")),
+          PermissionEntry::PermissionRef(id("fs:deny-all")),
+        ],
+      ),
+    )]
+    .into();
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::current()).unwrap();
+    assert_eq!(
+      resolved.denied_commands.keys().collect::<Vec<_>>(),
+      ["plugin:fs|read", "plugin:fs|write"]
+    );
+    assert!(!resolved.denied_commands.contains_key("plugin:fs|*"));
+    // the specific allow is overridden by the plugin-wide deny
+    assert!(resolved.allowed_commands.contains_key("plugin:fs|read"));
+    assert!(resolved.denied_commands.contains_key("plugin:fs|read"));
+  }
+
+  #[test]
+  fn checks_allowed_commands() {
+    use super::{CommandOrigin, Commands, Resolved, Target};
+    use crate::acl::capability::CapabilityRemote;
+
+    let permission = |identifier: &str, allow: &[&str], deny: &[&str]| {
+      (
+        identifier.to_string(),
+        Permission {
+          identifier: identifier.to_string(),
+          commands: Commands {
+            allow: allow.iter().map(|c| c.to_string()).collect(),
+            deny: deny.iter().map(|c| c.to_string()).collect(),
+          },
+          ..Default::default()
+        },
+      )
+    };
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [
+          permission("allow-read", &["read"], &[]),
+          permission("allow-write", &["write"], &[]),
+          permission("deny-write", &[], &["write"]),
+        ]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capability = |identifier: &str, remote: Option<&str>, permissions: &[&str]| {
+      (
+        identifier.to_string(),
+        Capability {
+          remote: remote.map(|url| CapabilityRemote {
+            urls: vec![url.to_string()],
+            ..Default::default()
+          }),
+          local: remote.is_none(),
+          ..main_window_capability(
+            identifier,
+            permissions
+              .iter()
+              .map(|p| PermissionEntry::PermissionRef(id(p)))
+              .collect(),
+          )
+        },
+      )
+    };
+    let capabilities = [
+      capability("main", None, &["fs:allow-read", "fs:allow-write"]),
+      capability(
+        "docs",
+        Some("https://tauri.app"),
+        &["fs:allow-read", "fs:deny-write"],
+      ),
+    ]
+    .into();
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::current()).unwrap();
+    let remote_url = "https://tauri.app".parse().unwrap();
+    let remote = CommandOrigin::Remote(&remote_url);
+
+    // local allow
+    assert!(resolved.is_allowed("plugin:fs|read", CommandOrigin::Local, "main", "main"));
+    assert!(resolved.is_allowed("plugin:fs|write", CommandOrigin::Local, "main", "main"));
+    assert!(resolved.is_allowed("plugin:fs|read", remote, "main", "main"));
+
+    // remote deny
+    assert!(!resolved.is_allowed("plugin:fs|write", remote, "main", "main"));
+    let other_url = "https://example.com".parse().unwrap();
+    assert!(!resolved.is_allowed(
+      "plugin:fs|read",
+      CommandOrigin::Remote(&other_url),
+      "main",
+      "main"
+    ));
+
+    // window mismatch
+    assert!(!resolved.is_allowed(
+      "plugin:fs|read",
+      CommandOrigin::Local,
+      "settings",
+      "settings"
+    ));
+    assert!(!resolved.is_allowed("plugin:fs|remove", CommandOrigin::Local, "main", "main"));
+  }
+
+  #[test]
+  fn diffs_allowlist() {
+    use super::{AllowlistDiff, Commands, Resolved, Target};
+
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [(
+          "allow-read-write".to_string(),
+          Permission {
+            identifier: "allow-read-write".to_string(),
+            commands: Commands {
+              allow: vec!["read".into(), "write".into()],
+              deny: Vec::new(),
+            },
+            ..Default::default()
+          },
+        )]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capabilities = [(
+      "main".to_string(),
+      main_window_capability(
+        "main",
+        vec![PermissionEntry::PermissionRef(id("fs:allow-read-write"))],
+      ),
+    )]
+    .into();
+
+    let resolved = Resolved::resolve(&acl, capabilities, Target::current()).unwrap();
+
+    let diff = resolved.diff_allowlist(["plugin:fs|read"]);
+    assert!(!diff.is_empty());
+    assert_eq!(
+      diff,
+      AllowlistDiff {
+        unexpected: ["plugin:fs|write".to_string()].into(),
+        missing: Default::default(),
+      }
+    );
+
+    let diff = resolved.diff_allowlist(["plugin:fs|read", "plugin:fs|write", "plugin:fs|remove"]);
+    assert_eq!(
+      diff,
+      AllowlistDiff {
+        unexpected: Default::default(),
+        missing: ["plugin:fs|remove".to_string()].into(),
+      }
+    );
+
+    assert!(resolved
+      .diff_allowlist(["plugin:fs|write", "plugin:fs|read"])
+      .is_empty());
+  }
+
+  #[test]
+  fn substitutes_capability_variables_in_scopes() {
+    use super::{Commands, Error, Resolved, Scopes, Target};
+    use crate::acl::Value;
+
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [(
+          "allow-read-logs".to_string(),
+          Permission {
+            identifier: "allow-read-logs".to_string(),
+            commands: Commands {
+              allow: vec!["read".into()],
+              deny: Vec::new(),
+            },
+            scope: Scopes {
+              allow: Some(vec![
+                serde_json::json!({ "path": "{app_data}/logs/*" }).into()
+              ]),
+              deny: None,
+              include: Vec::new(),
+            },
+            ..Default::default()
+          },
+        )]
+        .into(),
+        ..Default::default()
+      },
+    )]
+    .into();
+
+    let capabilities = |variables: &[(&str, &str)]| {
+      [(
+        "main".to_string(),
+        Capability {
+          variables: variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect(),
+          ..main_window_capability(
+            "main",
+            vec![PermissionEntry::PermissionRef(id("fs:allow-read-logs"))],
+          )
+        },
+      )]
+      .into()
+    };
+
+    let resolved = Resolved::resolve(
+      &acl,
+      capabilities(&[("app_data", "$APPDATA")]),
+      Target::current(),
+    )
+    .unwrap();
+    let scope_id = resolved.allowed_commands["plugin:fs|read"][0]
+      .scope_id
+      .unwrap();
+    assert_eq!(
+      resolved.command_scope[&scope_id].allow,
+      vec![Value::from(
+        serde_json::json!({ "path": "$APPDATA/logs/*" })
+      )]
+    );
+
+    assert!(matches!(
+      Resolved::resolve(&acl, capabilities(&[("app_cache", "$APPCACHE")]), Target::current()),
+      Err(Error::UndefinedScopeVariable { variable }) if variable == "app_data"
+    ));
+
+    // without variables the scopes are kept as is
+    let resolved = Resolved::resolve(&acl, capabilities(&[]), Target::current()).unwrap();
+    let scope_id = resolved.allowed_commands["plugin:fs|read"][0]
+      .scope_id
+      .unwrap();
+    assert_eq!(
+      resolved.command_scope[&scope_id].allow,
+      vec![Value::from(
+        serde_json::json!({ "path": "{app_data}/logs/*" })
+      )]
+    );
+  }
 }
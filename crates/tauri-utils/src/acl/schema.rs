@@ -343,3 +343,262 @@ pub fn generate_permissions_schema<P: AsRef<Path>>(
 
   Ok(())
 }
+
+/// File name of the TypeScript definitions generated for a plugin, see [`generate_typescript_definitions`].
+pub const TYPESCRIPT_DEFINITIONS_FILE_NAME: &str = "permissions.d.ts";
+
+/// Generate TypeScript definitions for the permissions and the global scope of the given plugin.
+///
+/// The output declares:
+///
+/// - `Permission`: a union of every permission identifier of the plugin.
+/// - `ScopeEntry`: the shape of an `allow` or `deny` scope entry, converted from
+///   [`Manifest#structfield.global_scope_schema`], or `unknown` if the plugin does not define it.
+///   The definitions referenced by the schema are exported as well.
+/// - `PermissionEntry`: an item of the `permissions` list of a capability.
+///
+/// Only the JSON schema keywords that map to a TypeScript type are converted, other schemas become `unknown`.
+pub fn generate_typescript_definitions(plugin: &str, manifest: &Manifest) -> String {
+  let mut out = format!(
+    "// This file was generated from the permissions of the `{plugin}` plugin, do not edit it manually.\n"
+  );
+
+  match &manifest.global_scope_schema {
+    Some(schema) => {
+      out.push('\n');
+      out.push_str(&ts_doc_comment(
+        schema
+          .get("description")
+          .and_then(serde_json::Value::as_str)
+          .unwrap_or("An entry of the `allow` or `deny` scope of a permission."),
+        "",
+      ));
+      out.push_str(&format!(
+        "export type ScopeEntry = {}\n",
+        ts_type(schema, "")
+      ));
+
+      for key in ["definitions", "$defs"] {
+        let Some(definitions) = schema.get(key).and_then(serde_json::Value::as_object) else {
+          continue;
+        };
+        for (name, definition) in definitions {
+          out.push('\n');
+          if let Some(description) = definition
+            .get("description")
+            .and_then(serde_json::Value::as_str)
+          {
+            out.push_str(&ts_doc_comment(description, ""));
+          }
+          out.push_str(&format!(
+            "export type {name} = {}\n",
+            ts_type(definition, "")
+          ));
+        }
+      }
+    }
+    None => {
+      out.push_str("\n/** An entry of the `allow` or `deny` scope of a permission. */\n");
+      out.push_str("export type ScopeEntry = unknown\n");
+    }
+  }
+
+  out.push_str(&format!(
+    "\n/** A permission identifier of the `{plugin}` plugin. */\nexport type Permission ="
+  ));
+  let identifiers = manifest.gen_possible_permission_schemas(Some(plugin));
+  if identifiers.is_empty() {
+    out.push_str(" never\n");
+  } else {
+    out.push('\n');
+    for schema in identifiers {
+      let Schema::Object(schema) = schema else {
+        continue;
+      };
+      if let Some(description) = schema.metadata.and_then(|m| m.description) {
+        out.push_str(&ts_doc_comment(&description, "  "));
+      }
+      if let Some(identifier) = schema.const_value {
+        out.push_str(&format!("  | {identifier}\n"));
+      }
+    }
+  }
+
+  out.push_str("\n/** An item of the `permissions` list of a capability. */\n");
+  out.push_str("export type PermissionEntry =\n");
+  out.push_str("  | Permission\n");
+  out.push_str("  | {\n");
+  out.push_str("      identifier: Permission\n");
+  out.push_str("      allow?: ScopeEntry[]\n");
+  out.push_str("      deny?: ScopeEntry[]\n");
+  out.push_str("    }\n");
+
+  out
+}
+
+/// Converts a JSON schema to the matching TypeScript type, `indent` being the indentation of the line it starts on.
+fn ts_type(schema: &serde_json::Value, indent: &str) -> String {
+  use serde_json::Value;
+
+  let Some(schema) = schema.as_object() else {
+    // `true` accepts any value and `false` none
+    return if schema == &Value::Bool(false) {
+      "never"
+    } else {
+      "unknown"
+    }
+    .into();
+  };
+
+  if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+    return reference.rsplit('/').next().unwrap_or(reference).into();
+  }
+  if let Some(value) = schema.get("const") {
+    return value.to_string();
+  }
+  if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+    return values
+      .iter()
+      .map(Value::to_string)
+      .collect::<Vec<_>>()
+      .join(" | ");
+  }
+  for (keyword, separator) in [("anyOf", " | "), ("oneOf", " | "), ("allOf", " & ")] {
+    if let Some(schemas) = schema.get(keyword).and_then(Value::as_array) {
+      return schemas
+        .iter()
+        .map(|s| ts_parenthesize(ts_type(s, indent)))
+        .collect::<Vec<_>>()
+        .join(separator);
+    }
+  }
+
+  let types = match schema.get("type") {
+    Some(Value::String(ty)) => vec![ty.as_str()],
+    Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+    _ if schema.contains_key("properties") => vec!["object"],
+    _ => return "unknown".into(),
+  };
+
+  types
+    .into_iter()
+    .map(|ty| match ty {
+      "string" => "string".into(),
+      "number" | "integer" => "number".into(),
+      "boolean" => "boolean".into(),
+      "null" => "null".into(),
+      "array" => format!(
+        "{}[]",
+        ts_parenthesize(ts_type(
+          schema.get("items").unwrap_or(&Value::Bool(true)),
+          indent
+        ))
+      ),
+      "object" => ts_object_type(schema, indent),
+      _ => "unknown".into(),
+    })
+    .collect::<Vec<_>>()
+    .join(" | ")
+}
+
+fn ts_object_type(schema: &serde_json::Map<String, serde_json::Value>, indent: &str) -> String {
+  use serde_json::Value;
+
+  let additional_properties = match schema.get("additionalProperties") {
+    None | Some(Value::Bool(true)) => Some("unknown".to_string()),
+    Some(Value::Bool(false)) => None,
+    Some(additional) => Some(ts_type(additional, indent)),
+  };
+
+  let Some(properties) = schema
+    .get("properties")
+    .and_then(Value::as_object)
+    .filter(|p| !p.is_empty())
+  else {
+    return format!(
+      "Record<string, {}>",
+      additional_properties.as_deref().unwrap_or("never")
+    );
+  };
+
+  let required = schema
+    .get("required")
+    .and_then(Value::as_array)
+    .map(|r| r.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+    .unwrap_or_default();
+
+  let property_indent = format!("{indent}  ");
+  let mut out = "{\n".to_string();
+  for (name, property) in properties {
+    if let Some(description) = property.get("description").and_then(Value::as_str) {
+      out.push_str(&ts_doc_comment(description, &property_indent));
+    }
+    let is_identifier = name.chars().enumerate().all(|(i, c)| {
+      c == '_' || c == '$' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+    });
+    out.push_str(&format!(
+      "{property_indent}{}{}: {}\n",
+      if is_identifier && !name.is_empty() {
+        name.clone()
+      } else {
+        Value::String(name.clone()).to_string()
+      },
+      if required.contains(&name.as_str()) {
+        ""
+      } else {
+        "?"
+      },
+      ts_type(property, &property_indent)
+    ));
+  }
+  if let Some(additional_properties) = additional_properties.filter(|ty| ty != "unknown") {
+    out.push_str(&format!(
+      "{property_indent}[key: string]: {additional_properties}\n"
+    ));
+  }
+  out.push_str(indent);
+  out.push('}');
+  out
+}
+
+/// Wraps a union or intersection type in parentheses so it can be used as an operand.
+fn ts_parenthesize(ty: String) -> String {
+  let mut depth = 0;
+  let mut in_string = false;
+  let mut chars = ty.chars();
+  while let Some(c) = chars.next() {
+    match c {
+      '"' => in_string = !in_string,
+      '\\' if in_string => {
+        chars.next();
+      }
+      '{' | '[' | '(' | '<' if !in_string => depth += 1,
+      '}' | ']' | ')' | '>' if !in_string => depth -= 1,
+      '|' | '&' if !in_string && depth == 0 => return format!("({ty})"),
+      _ => {}
+    }
+  }
+  ty
+}
+
+fn ts_doc_comment(description: &str, indent: &str) -> String {
+  let lines = description.trim().lines().collect::<Vec<_>>();
+  if lines.is_empty() {
+    return String::new();
+  }
+  if let [line] = lines.as_slice() {
+    return format!("{indent}/** {} */\n", line.replace("*/", "*\\/"));
+  }
+
+  let mut out = format!("{indent}/**\n");
+  for line in lines {
+    let line = line.trim_end().replace("*/", "*\\/");
+    if line.is_empty() {
+      out.push_str(&format!("{indent} *\n"));
+    } else {
+      out.push_str(&format!("{indent} * {line}\n"));
+    }
+  }
+  out.push_str(&format!("{indent} */\n"));
+  out
+}
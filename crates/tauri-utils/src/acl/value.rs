@@ -60,6 +60,171 @@ pub enum Value {
   Map(BTreeMap<String, Value>),
 }
 
+impl Value {
+  /// Checks whether `input` satisfies this value when it is used as a scope constraint.
+  ///
+  /// - A [`Value::List`] is a "one of" constraint: the input must match at least one of its items,
+  ///   e.g. `["read", "write"]` only allows `"read"` or `"write"`.
+  /// - A [`Value::Map`] requires the input to be a map where each entry matches the constraint of the same key.
+  ///   A missing entry is treated as [`Value::Null`] and keys that are not listed in the constraint are not restricted.
+  /// - Any other value must be equal to the input.
+  pub fn matches(&self, input: &Value) -> bool {
+    match self {
+      Value::List(allowed) => allowed.iter().any(|value| value.matches(input)),
+      Value::Map(constraints) => match input {
+        Value::Map(input) => constraints
+          .iter()
+          .all(|(key, constraint)| constraint.matches(input.get(key).unwrap_or(&Value::Null))),
+        _ => false,
+      },
+      _ => self == input,
+    }
+  }
+
+  /// Checks whether the JSON Pointer `pointer` (e.g. `/config/secrets/token`) satisfies this value
+  /// when it is used as a pointer scope entry.
+  ///
+  /// - A [`Value::String`] is a JSON Pointer pattern where a `*` segment matches any single segment
+  ///   and a trailing `**` segment matches any number of remaining segments,
+  ///   e.g. `/config/secrets/*` matches `/config/secrets/token` but not `/config/secrets/token/value`.
+  /// - A [`Value::List`] matches if any of its items matches.
+  /// - Any other value never matches.
+  ///
+  /// Segments are compared after unescaping `~1` to `/` and `~0` to `~`.
+  pub fn matches_pointer(&self, pointer: &str) -> bool {
+    match self {
+      Value::String(pattern) => pointer_matches(pattern, pointer),
+      Value::List(patterns) => patterns
+        .iter()
+        .any(|pattern| pattern.matches_pointer(pointer)),
+      _ => false,
+    }
+  }
+
+  /// Replaces the `{name}` placeholders in the strings of this value with the given variables,
+  /// see [`Capability::variables`](super::capability::Capability#structfield.variables).
+  ///
+  /// Placeholder names start with a letter or `_` and only contain alphanumeric characters, `_` and `-`,
+  /// so other braces such as the `{png,jpg}` glob alternatives are kept as is. Use `{{` for a literal `{`.
+  /// Map keys are not substituted.
+  ///
+  /// Returns [`super::Error::UndefinedScopeVariable`] if a placeholder has no matching variable.
+  pub fn substitute_variables(
+    &self,
+    variables: &BTreeMap<String, String>,
+  ) -> Result<Value, super::Error> {
+    Ok(match self {
+      Value::String(template) => Value::String(substitute(template, variables)?),
+      Value::List(values) => Value::List(
+        values
+          .iter()
+          .map(|value| value.substitute_variables(variables))
+          .collect::<Result<_, _>>()?,
+      ),
+      Value::Map(values) => Value::Map(
+        values
+          .iter()
+          .map(|(key, value)| Ok((key.clone(), value.substitute_variables(variables)?)))
+          .collect::<Result<_, super::Error>>()?,
+      ),
+      _ => self.clone(),
+    })
+  }
+}
+
+fn is_variable_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars
+    .next()
+    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn substitute(
+  template: &str,
+  variables: &BTreeMap<String, String>,
+) -> Result<String, super::Error> {
+  let mut substituted = String::with_capacity(template.len());
+  let mut rest = template;
+
+  while let Some(start) = rest.find('{') {
+    substituted.push_str(&rest[..start]);
+    let after = &rest[start + 1..];
+
+    if let Some(after) = after.strip_prefix('{') {
+      substituted.push('{');
+      rest = after;
+      continue;
+    }
+
+    match after
+      .find('}')
+      .map(|end| &after[..end])
+      .filter(|name| is_variable_name(name))
+    {
+      Some(name) => {
+        let value = variables
+          .get(name)
+          .ok_or_else(|| super::Error::UndefinedScopeVariable {
+            variable: name.to_string(),
+          })?;
+        substituted.push_str(value);
+        rest = &after[name.len() + 1..];
+      }
+      None => {
+        substituted.push('{');
+        rest = after;
+      }
+    }
+  }
+
+  substituted.push_str(rest);
+  Ok(substituted)
+}
+
+/// Checks whether the JSON Pointer `pointer` is allowed by the given pointer scope entries.
+///
+/// The pointer must match at least one of the `allowed` entries and none of the `denied` entries,
+/// so a deny always takes precedence over an allow. See [`Value::matches_pointer`] for the pattern syntax.
+pub fn is_pointer_allowed(pointer: &str, allowed: &[Value], denied: &[Value]) -> bool {
+  !denied.iter().any(|value| value.matches_pointer(pointer))
+    && allowed.iter().any(|value| value.matches_pointer(pointer))
+}
+
+/// Splits a JSON Pointer into its unescaped reference tokens, or `None` if it is not a valid pointer.
+fn pointer_segments(pointer: &str) -> Option<Vec<String>> {
+  if pointer.is_empty() {
+    return Some(Vec::new());
+  }
+
+  pointer.strip_prefix('/').map(|pointer| {
+    pointer
+      .split('/')
+      .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+      .collect()
+  })
+}
+
+fn pointer_matches(pattern: &str, pointer: &str) -> bool {
+  let (Some(pattern), Some(pointer)) = (pointer_segments(pattern), pointer_segments(pointer))
+  else {
+    return false;
+  };
+
+  let mut pointer = pointer.iter();
+  for (index, segment) in pattern.iter().enumerate() {
+    if segment == "**" && index == pattern.len() - 1 {
+      return true;
+    }
+    match pointer.next() {
+      Some(requested) if segment == "*" || segment == requested => {}
+      _ => return false,
+    }
+  }
+
+  pointer.next().is_none()
+}
+
 impl From<Value> for serde_json::Value {
   fn from(value: Value) -> Self {
     match value {
@@ -199,3 +364,95 @@ mod build {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{is_pointer_allowed, Value};
+  use crate::acl::Error;
+
+  #[test]
+  fn matches_one_of() {
+    let scope: Value = serde_json::json!({ "mode": ["read", "write"] }).into();
+
+    assert!(scope.matches(&serde_json::json!({ "mode": "read" }).into()));
+    assert!(scope.matches(&serde_json::json!({ "mode": "write", "path": "a.txt" }).into()));
+    assert!(!scope.matches(&serde_json::json!({ "mode": "execute" }).into()));
+    assert!(!scope.matches(&serde_json::json!({}).into()));
+    assert!(!scope.matches(&Value::String("read".into())));
+  }
+
+  #[test]
+  fn matches_exact_value() {
+    let scope: Value = serde_json::json!({ "path": "$APPDATA", "recursive": true }).into();
+
+    assert!(scope.matches(&serde_json::json!({ "path": "$APPDATA", "recursive": true }).into()));
+    assert!(!scope.matches(&serde_json::json!({ "path": "$HOME", "recursive": true }).into()));
+    assert!(!scope.matches(&serde_json::json!({ "path": "$APPDATA" }).into()));
+  }
+
+  #[test]
+  fn pointer_wildcard_segment() {
+    let scope = Value::String("/config/secrets/*".into());
+
+    assert!(scope.matches_pointer("/config/secrets/token"));
+    assert!(scope.matches_pointer("/config/secrets/api~1key"));
+    assert!(!scope.matches_pointer("/config/secrets"));
+    assert!(!scope.matches_pointer("/config/secrets/token/value"));
+    assert!(!scope.matches_pointer("/config/public/token"));
+
+    let scope = Value::String("/config/**".into());
+    assert!(scope.matches_pointer("/config"));
+    assert!(scope.matches_pointer("/config/secrets/token/value"));
+    assert!(!scope.matches_pointer("/data"));
+  }
+
+  #[test]
+  fn pointer_deny_overrides_allow() {
+    let allowed = [Value::String("/config/**".into())];
+    let denied = [Value::List(vec![
+      Value::String("/config/secrets/*".into()),
+      Value::String("/config/internal".into()),
+    ])];
+
+    assert!(is_pointer_allowed("/config/theme", &allowed, &denied));
+    assert!(is_pointer_allowed("/config/secrets", &allowed, &denied));
+    assert!(!is_pointer_allowed(
+      "/config/secrets/token",
+      &allowed,
+      &denied
+    ));
+    assert!(!is_pointer_allowed("/config/internal", &allowed, &denied));
+    assert!(!is_pointer_allowed("/data", &allowed, &denied));
+  }
+
+  #[test]
+  fn substitutes_variables() {
+    let variables = [
+      (
+        "app_data".to_string(),
+        "/home/user/.local/share/app".to_string(),
+      ),
+      ("env".to_string(), "prod".to_string()),
+    ]
+    .into();
+
+    let scope: Value = serde_json::json!({
+      "path": "{app_data}/logs/{env}-*.{log,txt}",
+      "tags": ["{env}", "{{env}", 1],
+    })
+    .into();
+    assert_eq!(
+      scope.substitute_variables(&variables).unwrap(),
+      serde_json::json!({
+        "path": "/home/user/.local/share/app/logs/prod-*.{log,txt}",
+        "tags": ["prod", "{env}", 1],
+      })
+      .into()
+    );
+
+    assert!(matches!(
+      Value::String("{app_cache}/*".into()).substitute_variables(&variables),
+      Err(Error::UndefinedScopeVariable { variable }) if variable == "app_cache"
+    ));
+  }
+}
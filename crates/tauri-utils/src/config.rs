@@ -69,6 +69,18 @@ pub enum WebviewUrl {
   App(PathBuf),
   /// A custom protocol url, for example, `doom://index.html`
   CustomProtocol(Url),
+  /// HTML content to load directly, for example an error page.
+  ///
+  /// The page is loaded with an opaque origin, so it is not treated as app content
+  /// and cannot be set from the configuration file.
+  #[cfg_attr(feature = "schema", schemars(skip))]
+  Html(String),
+  /// No page is loaded until the webview is navigated, for example when the initial URL
+  /// is read from the settings after the webview is created.
+  ///
+  /// The webview shows a blank page meanwhile and cannot be set from the configuration file.
+  #[cfg_attr(feature = "schema", schemars(skip))]
+  Lazy,
 }
 
 impl<'de> Deserialize<'de> for WebviewUrl {
@@ -101,6 +113,8 @@ impl fmt::Display for WebviewUrl {
     match self {
       Self::External(url) | Self::CustomProtocol(url) => write!(f, "{url}"),
       Self::App(path) => write!(f, "{}", path.display()),
+      Self::Html(html) => write!(f, "{html}"),
+      Self::Lazy => write!(f, "about:blank"),
     }
   }
 }
@@ -1566,7 +1580,7 @@ pub struct WindowConfig {
   pub window_classname: Option<String>,
   /// The initial window theme. Defaults to the system theme. Only implemented on Windows and macOS 10.14+.
   pub theme: Option<crate::Theme>,
-  /// The style of the macOS title bar.
+  /// The style of the window title bar. `Hidden` is supported on all desktop platforms, the other styles only on macOS.
   #[serde(default, alias = "title-bar-style")]
   pub title_bar_style: TitleBarStyle,
   /// If `true`, sets the window title to be hidden on macOS.
@@ -2312,7 +2326,7 @@ pub struct SecurityConfig {
 #[serde(untagged)]
 pub enum CapabilityEntry {
   /// An inlined capability.
-  Inlined(Capability),
+  Inlined(Box<Capability>),
   /// Reference to a capability identifier.
   Reference(String),
 }
@@ -2324,7 +2338,11 @@ impl<'de> Deserialize<'de> for CapabilityEntry {
   {
     UntaggedEnumVisitor::new()
       .string(|string| Ok(Self::Reference(string.to_owned())))
-      .map(|map| map.deserialize::<Capability>().map(Self::Inlined))
+      .map(|map| {
+        map
+          .deserialize::<Capability>()
+          .map(|capability| Self::Inlined(Box::new(capability)))
+      })
       .deserialize(deserializer)
   }
 }
@@ -2854,6 +2872,11 @@ mod build {
           let url = url_lit(url);
           quote! { #prefix::CustomProtocol(#url) }
         }
+        Self::Html(html) => {
+          let html = str_lit(html);
+          quote! { #prefix::Html(#html) }
+        }
+        Self::Lazy => quote! { #prefix::Lazy },
       })
     }
   }
@@ -2901,6 +2924,7 @@ mod build {
         Self::Visible => quote! { #prefix::Visible },
         Self::Transparent => quote! { #prefix::Transparent },
         Self::Overlay => quote! { #prefix::Overlay },
+        Self::Hidden => quote! { #prefix::Hidden },
       })
     }
   }
@@ -3267,7 +3291,7 @@ mod build {
 
       tokens.append_all(match self {
         Self::Inlined(capability) => {
-          quote! { #prefix::Inlined(#capability) }
+          quote! { #prefix::Inlined(::std::boxed::Box::new(#capability)) }
         }
         Self::Reference(id) => {
           let id = str_lit(id);
@@ -3562,4 +3586,23 @@ mod test {
     assert_eq!(Color(0, 0, 0, 255), "#000000ff".parse().unwrap());
     assert_eq!(Color(0, 255, 0, 255), "#00ff00ff".parse().unwrap());
   }
+
+  #[test]
+  fn parse_title_bar_style() {
+    use crate::TitleBarStyle;
+
+    for (value, style) in [
+      ("visible", TitleBarStyle::Visible),
+      ("Overlay", TitleBarStyle::Overlay),
+      ("hidden", TitleBarStyle::Hidden),
+      ("Hidden", TitleBarStyle::Hidden),
+    ] {
+      let parsed: TitleBarStyle = serde_json::from_value(serde_json::json!(value)).unwrap();
+      assert_eq!(parsed, style);
+    }
+    assert_eq!(
+      serde_json::to_value(TitleBarStyle::Hidden).unwrap(),
+      serde_json::json!("Hidden")
+    );
+  }
 }
@@ -166,6 +166,22 @@ pub fn inject_csp(document: &NodeRef, csp: &str) {
   });
 }
 
+/// Injects a `<base>` element to the HTML so relative URLs are resolved against the given URL.
+pub fn inject_base_url(document: &NodeRef, url: &str) {
+  with_head(document, |head| {
+    head.prepend(NodeRef::new_element(
+      QualName::new(None, ns!(html), LocalName::from("base")),
+      vec![(
+        ExpandedName::new(ns!(), LocalName::from("href")),
+        Attribute {
+          prefix: None,
+          value: url.into(),
+        },
+      )],
+    ));
+  });
+}
+
 fn create_csp_meta_tag(csp: &str) -> NodeRef {
   NodeRef::new_element(
     QualName::new(None, ns!(html), LocalName::from("meta")),
@@ -310,4 +326,15 @@ mod tests {
       );
     }
   }
+
+  #[test]
+  fn base_url() {
+    let document =
+      kuchiki::parse_html().one("<html><head><title>Error</title></head></html>".to_string());
+    super::inject_base_url(&document, "https://tauri.app/");
+    assert_eq!(
+      document.to_string(),
+      r#"<html><head><base href="https://tauri.app/"><title>Error</title></head><body></body></html>"#
+    );
+  }
 }
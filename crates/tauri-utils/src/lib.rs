@@ -154,7 +154,12 @@ mod window_effects {
 
 pub use window_effects::{WindowEffect, WindowEffectState};
 
-/// How the window title bar should be displayed on macOS.
+/// How the window title bar should be displayed.
+///
+/// ## Platform-specific
+///
+/// - **Windows / Linux:** Only [`TitleBarStyle::Hidden`] is supported, the other styles show the system title bar.
+/// - **iOS / Android:** Unsupported.
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[non_exhaustive]
@@ -172,6 +177,17 @@ pub enum TitleBarStyle {
   /// - You need to define a custom drag region to make your window draggable, however due to a limitation you can't drag the window when it's not in focus <https://github.com/tauri-apps/tauri/issues/4316>.
   /// - The color of the window title depends on the system theme.
   Overlay,
+  /// Removes the title bar so the window's content, including a child webview at `(0, 0)`, fills the whole window.
+  ///
+  /// Use it to draw a custom title bar, which needs a custom drag region to make the window draggable.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS:** Same as [`TitleBarStyle::Overlay`] with the window title hidden, so the traffic lights remain.
+  ///   Their position can be changed with the window builder's `traffic_light_position`.
+  ///   The title is only hidden when the window is created with this style.
+  /// - **Windows / Linux:** Removes the window decorations.
+  Hidden,
 }
 
 impl Default for TitleBarStyle {
@@ -198,6 +214,7 @@ impl<'de> Deserialize<'de> for TitleBarStyle {
     Ok(match s.to_lowercase().as_str() {
       "transparent" => Self::Transparent,
       "overlay" => Self::Overlay,
+      "hidden" => Self::Hidden,
       _ => Self::Visible,
     })
   }
@@ -212,6 +229,7 @@ impl Display for TitleBarStyle {
         Self::Visible => "Visible",
         Self::Transparent => "Transparent",
         Self::Overlay => "Overlay",
+        Self::Hidden => "Hidden",
       }
     )
   }
@@ -138,7 +138,11 @@ const PLUGINS: &[(&str, &[(&str, bool)])] = &[
       ("reparent", false),
       ("clear_all_browsing_data", false),
       ("set_webview_background_color", false),
+      ("set_webview_user_agent", false),
+      ("emit_to_sibling", true),
       // internal
+      ("internal_focus_adjacent_webview", true),
+      ("internal_reveal", true),
       ("internal_toggle_devtools", true),
     ],
   ),
@@ -33,7 +33,7 @@ use tauri_macros::default_runtime;
 use tauri_runtime::EventLoopProxy;
 use tauri_runtime::{
   dpi::{PhysicalPosition, PhysicalSize},
-  window::DragDropEvent,
+  window::{DragDropEvent, FileDropEvent, MainFrameResponse},
   RuntimeInitArgs,
 };
 use tauri_utils::{assets::AssetsIter, PackageInfo};
@@ -132,6 +132,11 @@ pub enum WindowEvent {
   },
   /// An event associated with the drag and drop action.
   DragDrop(DragDropEvent),
+  /// Files are dragged over or dropped onto one of the webviews of the window.
+  ///
+  /// The event identifies the target webview, so a multiwebview window can tell its child webviews apart.
+  /// It is also emitted to the window as the `tauri://file-drop` event.
+  FileDrop(FileDropEvent),
   /// The system window theme has changed. Only delivered if the window [`theme`](`crate::window::WindowBuilder#method.theme`) is `None`.
   ///
   /// Applications might wish to react to this to change the theme of the content of the window when the system changes the window theme.
@@ -160,6 +165,7 @@ impl From<RuntimeWindowEvent> for WindowEvent {
         new_inner_size,
       },
       RuntimeWindowEvent::DragDrop(event) => Self::DragDrop(event),
+      RuntimeWindowEvent::FileDrop(event) => Self::FileDrop(event),
       RuntimeWindowEvent::ThemeChanged(theme) => Self::ThemeChanged(theme),
     }
   }
@@ -171,12 +177,26 @@ impl From<RuntimeWindowEvent> for WindowEvent {
 pub enum WebviewEvent {
   /// An event associated with the drag and drop action.
   DragDrop(DragDropEvent),
+  /// The number of matches found by the last [`crate::Webview::find`] search.
+  FindMatchCount(u32),
+  /// The favicon of the current page has changed. `None` if the page has no favicon.
+  FaviconChanged(Option<url::Url>),
+  /// The estimated load progress of the main frame navigation, from `0.0` to `1.0`.
+  ///
+  /// See [`crate::Webview::on_load_progress`] for platform differences.
+  LoadProgress(f64),
+  /// The main frame navigation received a response, see [`crate::Webview::on_main_frame_response`].
+  MainFrameResponse(MainFrameResponse),
 }
 
 impl From<RuntimeWebviewEvent> for WebviewEvent {
   fn from(event: RuntimeWebviewEvent) -> Self {
     match event {
       RuntimeWebviewEvent::DragDrop(e) => Self::DragDrop(e),
+      RuntimeWebviewEvent::FindMatchCount(count) => Self::FindMatchCount(count),
+      RuntimeWebviewEvent::FaviconChanged(url) => Self::FaviconChanged(url),
+      RuntimeWebviewEvent::LoadProgress(progress) => Self::LoadProgress(progress),
+      RuntimeWebviewEvent::MainFrameResponse(response) => Self::MainFrameResponse(response),
     }
   }
 }
@@ -160,6 +160,35 @@ pub enum Error {
   /// Bad `__TAURI_INVOKE_KEY__` value received in ipc message.
   #[error("bad __TAURI_INVOKE_KEY__ value received in ipc message")]
   InvokeKey,
+  /// A command was called more often than allowed by its permissions.
+  #[error("command {command} was called more than {max_calls_per_minute} times in a minute")]
+  CommandRateLimited {
+    /// The command name.
+    command: String,
+    /// The limit set by the permissions of the command.
+    max_calls_per_minute: u32,
+  },
+  /// The arguments of a command are not allowed by the scope of its permissions.
+  #[error("command {command} arguments not allowed by the ACL scope")]
+  CommandArgumentsNotAllowed {
+    /// The command name.
+    command: String,
+  },
+  /// A remote URL could not be added to a capability at runtime.
+  #[error("cannot add remote URL `{url}` to capability `{capability}`: {reason}")]
+  CapabilityRemoteUrl {
+    /// Capability identifier.
+    capability: String,
+    /// Remote URL pattern.
+    url: String,
+    /// Why the URL was rejected.
+    reason: String,
+  },
+  /// Remote debugging was requested in a release build without the `devtools` feature flag.
+  #[error(
+    "remote debugging of webview `{0}` requires a debug build or the `devtools` feature flag"
+  )]
+  RemoteDebuggingNotAllowed(String),
 }
 
 impl From<getrandom::Error> for Error {
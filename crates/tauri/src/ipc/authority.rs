@@ -2,21 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fmt::{Debug, Display};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use tauri_utils::acl::{
-  capability::{Capability, CapabilityFile, PermissionEntry},
+  capability::{Capability, CapabilityFile, CapabilityRemote, PermissionEntry},
   manifest::Manifest,
-  Value, APP_ACL_KEY,
+  Value, APP_ACL_KEY, ARGUMENTS_SCOPE_KEY,
 };
 use tauri_utils::acl::{
   resolved::{Resolved, ResolvedCommand, ResolvedScope, ScopeKey},
-  ExecutionContext, Scopes,
+  CommandOrigin, ExecutionContext, RemoteUrlPattern, Scopes,
 };
 use tauri_utils::platform::Target;
 
@@ -25,13 +26,15 @@ use url::Url;
 use crate::{ipc::InvokeError, sealed::ManagerBase, Runtime};
 use crate::{AppHandle, Manager, StateManager, Webview};
 
-use super::{CommandArg, CommandItem};
+use super::{CommandArg, CommandItem, InvokeBody};
 
 /// The runtime authority used to authorize IPC execution based on the Access Control List.
 pub struct RuntimeAuthority {
   acl: BTreeMap<String, crate::utils::acl::manifest::Manifest>,
-  allowed_commands: BTreeMap<String, Vec<ResolvedCommand>>,
-  denied_commands: BTreeMap<String, Vec<ResolvedCommand>>,
+  /// The resolved commands and runtime remote capabilities, its scopes are moved to the [`ScopeManager`].
+  resolved: Resolved,
+  /// The time of the recent calls of rate limited commands, by command and execution context.
+  rate_limits: HashMap<(String, String), VecDeque<Instant>>,
   pub(crate) scope_manager: ScopeManager,
 }
 
@@ -57,12 +60,13 @@ impl Display for Origin {
 
 impl Origin {
   fn matches(&self, context: &ExecutionContext) -> bool {
-    match (self, context) {
-      (Self::Local, ExecutionContext::Local) => true,
-      (Self::Remote { url }, ExecutionContext::Remote { url: url_pattern }) => {
-        url_pattern.test(url)
-      }
-      _ => false,
+    context.matches(self.command_origin())
+  }
+
+  fn command_origin(&self) -> CommandOrigin<'_> {
+    match self {
+      Self::Local => CommandOrigin::Local,
+      Self::Remote { url } => CommandOrigin::Remote(url),
     }
   }
 }
@@ -94,6 +98,9 @@ impl CapabilityBuilder {
       webviews: Vec::new(),
       permissions: Vec::new(),
       platforms: None,
+      enabled_on_features: Vec::new(),
+      include_required_permissions: false,
+      variables: Default::default(),
     })
   }
 
@@ -185,12 +192,17 @@ impl CapabilityBuilder {
     let scope = Scopes {
       allow: Some(allowed_scope),
       deny: Some(denied_scope),
+      include: Vec::new(),
     };
 
     self
       .0
       .permissions
-      .push(PermissionEntry::ExtendedPermission { identifier, scope });
+      .push(PermissionEntry::ExtendedPermission {
+        identifier,
+        version: None,
+        scope,
+      });
     self
   }
 
@@ -221,25 +233,26 @@ impl CapabilityBuilder {
 
 impl RuntimeCapability for CapabilityBuilder {
   fn build(self) -> CapabilityFile {
-    CapabilityFile::Capability(self.0)
+    CapabilityFile::Capability(Box::new(self.0))
   }
 }
 
 impl RuntimeAuthority {
   #[doc(hidden)]
-  pub fn new(acl: BTreeMap<String, Manifest>, resolved_acl: Resolved) -> Self {
-    let command_cache = resolved_acl
-      .command_scope
+  pub fn new(acl: BTreeMap<String, Manifest>, mut resolved_acl: Resolved) -> Self {
+    let command_scope = std::mem::take(&mut resolved_acl.command_scope);
+    let global_scope = std::mem::take(&mut resolved_acl.global_scope);
+    let command_cache = command_scope
       .keys()
       .map(|key| (*key, StateManager::new()))
       .collect();
     Self {
       acl,
-      allowed_commands: resolved_acl.allowed_commands,
-      denied_commands: resolved_acl.denied_commands,
+      resolved: resolved_acl,
+      rate_limits: HashMap::new(),
       scope_manager: ScopeManager {
-        command_scope: resolved_acl.command_scope,
-        global_scope: resolved_acl.global_scope,
+        command_scope,
+        global_scope,
         command_cache,
         global_scope_cache: StateManager::new(),
       },
@@ -252,7 +265,7 @@ impl RuntimeAuthority {
 
   #[doc(hidden)]
   pub fn __allow_command(&mut self, command: String, context: ExecutionContext) {
-    self.allowed_commands.insert(
+    self.resolved.allowed_commands.insert(
       command,
       vec![ResolvedCommand {
         context,
@@ -267,7 +280,7 @@ impl RuntimeAuthority {
     let mut capabilities = BTreeMap::new();
     match capability.build() {
       CapabilityFile::Capability(c) => {
-        capabilities.insert(c.identifier.clone(), c);
+        capabilities.insert(c.identifier.clone(), *c);
       }
 
       CapabilityFile::List(capabilities_list)
@@ -299,9 +312,14 @@ impl RuntimeAuthority {
       self.scope_manager.global_scope_cache = StateManager::new();
     }
 
+    self
+      .resolved
+      .runtime_remote_capabilities
+      .extend(resolved.runtime_remote_capabilities);
+
     // denied commands
     for (cmd_key, resolved_cmds) in resolved.denied_commands {
-      let entry = self.denied_commands.entry(cmd_key).or_default();
+      let entry = self.resolved.denied_commands.entry(cmd_key).or_default();
       entry.extend(resolved_cmds);
     }
 
@@ -329,13 +347,68 @@ impl RuntimeAuthority {
         }
       }
 
-      let entry = self.allowed_commands.entry(cmd_key).or_default();
+      let entry = self.resolved.allowed_commands.entry(cmd_key).or_default();
       entry.extend(resolved_cmds);
     }
 
     Ok(())
   }
 
+  /// Allows the given remote URL pattern to use the permissions of a capability.
+  ///
+  /// The capability must declare the URLs that can be added at runtime in its `remote > runtimeUrls` configuration,
+  /// and the given pattern must match one of them, so the capability cannot be broadened beyond what was declared.
+  pub fn add_remote_url(&mut self, capability: &str, url: &str) -> crate::Result<()> {
+    let error = |reason: &str| crate::Error::CapabilityRemoteUrl {
+      capability: capability.to_string(),
+      url: url.to_string(),
+      reason: reason.to_string(),
+    };
+
+    let pattern = url
+      .parse::<RemoteUrlPattern>()
+      .map_err(|e| error(&format!("invalid URL pattern: {e}")))?;
+
+    let declared = self
+      .resolved
+      .runtime_remote_capabilities
+      .get(capability)
+      .ok_or_else(|| {
+        error("the capability does not declare remote URLs that can be added at runtime")
+      })?;
+    let remote = declared.remote.as_ref().unwrap();
+
+    if !remote.allows_runtime_url(&pattern) {
+      return Err(error(
+        "the URL does not match any of the capability runtime URLs",
+      ));
+    }
+    if remote.urls.iter().any(|u| u == url) {
+      return Ok(());
+    }
+
+    let identifier = declared.identifier.clone();
+    let mut capability = declared.clone();
+    capability.local = false;
+    capability.remote = Some(CapabilityRemote {
+      urls: vec![url.to_string()],
+      runtime_urls: Vec::new(),
+    });
+    self.add_capability(CapabilityBuilder(capability))?;
+
+    // keep track of the added URL so adding it again is a no-op
+    if let Some(remote) = self
+      .resolved
+      .runtime_remote_capabilities
+      .get_mut(&identifier)
+      .and_then(|c| c.remote.as_mut())
+    {
+      remote.urls.push(url.to_string());
+    }
+
+    Ok(())
+  }
+
   #[cfg(debug_assertions)]
   pub(crate) fn resolve_access_message(
     &self,
@@ -442,15 +515,20 @@ impl RuntimeAuthority {
       format!("{key}.{command_name}")
     };
 
-    if let Some(resolved) = self.denied_commands.get(&command) {
+    if let Some(resolved) = self
+      .resolved
+      .denied_commands
+      .get(&command)
+      .filter(|_| self.resolved.is_denied(&command, origin.command_origin()))
+    {
       format!(
-        "{command_pretty_name} explicitly denied on origin {origin}\n\nreferenced by: {}",
+        "{command_pretty_name} explicitly denied on origin {origin}\n\ndenied by: {}",
         print_references(resolved)
       )
     } else {
-      let command_matches = self.allowed_commands.get(&command);
+      let command_matches = self.resolved.allowed_commands.get(&command);
 
-      if let Some(resolved) = self.allowed_commands.get(&command) {
+      if let Some(resolved) = self.resolved.allowed_commands.get(&command) {
         let resolved_matching_origin = resolved
           .iter()
           .filter(|cmd| origin.matches(&cmd.context))
@@ -547,6 +625,17 @@ impl RuntimeAuthority {
     }
   }
 
+  /// Checks whether the command can be called from the given origin by the given window and webview,
+  /// see [`Resolved::is_allowed`].
+  ///
+  /// Permissions only apply to the execution context of the capability that granted them,
+  /// so a command allowed by a remote capability is not allowed for the local app origin.
+  pub fn is_allowed(&self, command: &str, window: &str, webview: &str, origin: &Origin) -> bool {
+    self
+      .resolved
+      .is_allowed(command, origin.command_origin(), window, webview)
+  }
+
   /// Checks if the given IPC execution is allowed and returns the [`ResolvedCommand`] if it is.
   pub fn resolve_access(
     &self,
@@ -555,31 +644,128 @@ impl RuntimeAuthority {
     webview: &str,
     origin: &Origin,
   ) -> Option<Vec<ResolvedCommand>> {
-    if self
-      .denied_commands
-      .get(command)
-      .map(|resolved| resolved.iter().any(|cmd| origin.matches(&cmd.context)))
-      .is_some()
+    if !self.is_allowed(command, window, webview, origin) {
+      return None;
+    }
+    self.resolved.allowed_commands.get(command).map(|resolved| {
+      resolved
+        .iter()
+        .filter(|cmd| cmd.matches(origin.command_origin(), window, webview))
+        .cloned()
+        .collect()
+    })
+  }
+
+  /// Records a call to the given command and checks it against the rate limit of its resolved permissions,
+  /// see [`tauri_utils::acl::Permission#structfield.max_calls_per_minute`].
+  ///
+  /// Calls are counted per execution context: the local app and each remote origin have their own budget.
+  /// When the command is allowed by several permissions, the least restrictive limit applies.
+  pub(crate) fn check_rate_limit(
+    &mut self,
+    command: &str,
+    origin: &Origin,
+    resolved: &[ResolvedCommand],
+  ) -> crate::Result<()> {
+    // a permission without a limit allows unlimited calls
+    let Some(max_calls_per_minute) = resolved
+      .iter()
+      .map(|cmd| cmd.max_calls_per_minute)
+      .collect::<Option<Vec<_>>>()
+      .and_then(|limits| limits.into_iter().max())
+    else {
+      return Ok(());
+    };
+
+    let context = match origin {
+      Origin::Local => "local".to_string(),
+      Origin::Remote { url } => url.origin().ascii_serialization(),
+    };
+    let calls = self
+      .rate_limits
+      .entry((command.to_string(), context))
+      .or_default();
+
+    let now = Instant::now();
+    while calls
+      .front()
+      .is_some_and(|call| now.duration_since(*call) >= Duration::from_secs(60))
     {
-      None
-    } else {
-      self.allowed_commands.get(command).and_then(|resolved| {
-        let resolved_cmds = resolved
-          .iter()
-          .filter(|cmd| {
-            origin.matches(&cmd.context)
-              && (cmd.webviews.iter().any(|w| w.matches(webview))
-                || cmd.windows.iter().any(|w| w.matches(window)))
-          })
-          .cloned()
-          .collect::<Vec<_>>();
-        if resolved_cmds.is_empty() {
-          None
-        } else {
-          Some(resolved_cmds)
-        }
-      })
+      calls.pop_front();
     }
+
+    if calls.len() >= max_calls_per_minute.get() as usize {
+      return Err(crate::Error::CommandRateLimited {
+        command: command.to_string(),
+        max_calls_per_minute: max_calls_per_minute.get(),
+      });
+    }
+    calls.push_back(now);
+
+    Ok(())
+  }
+
+  /// Checks the arguments of a command against the constraints of its resolved scopes,
+  /// see [`tauri_utils::acl::ARGUMENTS_SCOPE_KEY`].
+  ///
+  /// The arguments are rejected when they match a denied constraint,
+  /// or when every permission allowing the command constrains them and none of the constraints match.
+  pub(crate) fn check_argument_scope(
+    &self,
+    command: &str,
+    resolved: &[ResolvedCommand],
+    body: &InvokeBody,
+  ) -> crate::Result<()> {
+    fn constraint(value: &Value) -> Option<&Value> {
+      match value {
+        Value::Map(map) => map.get(ARGUMENTS_SCOPE_KEY),
+        _ => None,
+      }
+    }
+
+    let mut allowed = Vec::new();
+    let mut denied = Vec::new();
+    let mut unconstrained = resolved.is_empty();
+    for cmd in resolved {
+      let scope = cmd
+        .scope_id
+        .and_then(|id| self.scope_manager.command_scope.get(&id));
+      let allow = scope
+        .map(|scope| {
+          scope
+            .allow
+            .iter()
+            .filter_map(constraint)
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      // a permission that does not constrain the arguments allows any of them
+      if allow.is_empty() {
+        unconstrained = true;
+      }
+      allowed.extend(allow);
+      if let Some(scope) = scope {
+        denied.extend(scope.deny.iter().filter_map(constraint));
+      }
+    }
+
+    if unconstrained && denied.is_empty() {
+      return Ok(());
+    }
+
+    let args = match body {
+      InvokeBody::Json(json) => Value::from(json.clone()),
+      InvokeBody::Raw(_) => Value::Null,
+    };
+    if denied.iter().any(|c| c.matches(&args))
+      || (!unconstrained && !allowed.iter().any(|c| c.matches(&args)))
+    {
+      return Err(crate::Error::CommandArgumentsNotAllowed {
+        command: command.to_string(),
+      });
+    }
+
+    Ok(())
   }
 }
 
@@ -847,6 +1033,18 @@ pub trait ScopeObjectMatch: ScopeObject {
   fn matches(&self, input: &Self::Input) -> bool;
 }
 
+/// Scopes declared as raw [`Value`]s match an input when it satisfies the constraint, see [`Value::matches`].
+///
+/// This allows commands to enforce enumerated scope values such as `{ "mode": ["read", "write"] }`
+/// without defining a dedicated scope type.
+impl ScopeObjectMatch for Value {
+  type Input = Value;
+
+  fn matches(&self, input: &Value) -> bool {
+    Value::matches(self, input)
+  }
+}
+
 impl ScopeManager {
   pub(crate) fn get_global_scope_typed<R: Runtime, T: ScopeObject>(
     &self,
@@ -929,9 +1127,14 @@ impl ScopeManager {
 #[cfg(test)]
 mod tests {
   use glob::Pattern;
-  use tauri_utils::acl::{
-    resolved::{Resolved, ResolvedCommand},
-    ExecutionContext,
+  use tauri_utils::{
+    acl::{
+      capability::{Capability, CapabilityRemote, PermissionEntry},
+      manifest::Manifest,
+      resolved::{Resolved, ResolvedCommand},
+      Commands, ExecutionContext, Permission,
+    },
+    platform::Target,
   };
 
   use crate::ipc::Origin;
@@ -1115,6 +1318,175 @@ mod tests {
       .is_none());
   }
 
+  #[test]
+  fn remote_capability_not_allowed_locally() {
+    let command = "my-command";
+    let window = "main";
+    let webview = "main";
+    let url = "https://tauri.app";
+
+    let allowed_commands = [(
+      command.to_string(),
+      vec![ResolvedCommand {
+        windows: vec![Pattern::new(window).unwrap()],
+        context: ExecutionContext::Remote {
+          url: url.parse().unwrap(),
+        },
+        ..Default::default()
+      }],
+    )]
+    .into_iter()
+    .collect();
+
+    let authority = RuntimeAuthority::new(
+      Default::default(),
+      Resolved {
+        allowed_commands,
+        ..Default::default()
+      },
+    );
+
+    let remote = Origin::Remote {
+      url: url.parse().unwrap(),
+    };
+    assert!(authority.is_allowed(command, window, webview, &remote));
+    assert!(!authority.is_allowed(command, window, webview, &Origin::Local));
+    // the capability is not linked to other windows
+    assert!(!authority.is_allowed(command, "settings", "settings", &remote));
+    assert!(authority
+      .resolve_access(command, window, webview, &Origin::Local)
+      .is_none());
+  }
+
+  #[test]
+  fn remote_deny_does_not_apply_locally() {
+    let command = "my-command";
+    let window = "main";
+    let webview = "main";
+    let url = "https://tauri.app";
+    let windows = vec![Pattern::new(window).unwrap()];
+
+    let allowed_commands = [(
+      command.to_string(),
+      vec![ResolvedCommand {
+        windows: windows.clone(),
+        ..Default::default()
+      }],
+    )]
+    .into_iter()
+    .collect();
+    let denied_commands = [(
+      command.to_string(),
+      vec![ResolvedCommand {
+        windows,
+        context: ExecutionContext::Remote {
+          url: url.parse().unwrap(),
+        },
+        ..Default::default()
+      }],
+    )]
+    .into_iter()
+    .collect();
+
+    let authority = RuntimeAuthority::new(
+      Default::default(),
+      Resolved {
+        allowed_commands,
+        denied_commands,
+        ..Default::default()
+      },
+    );
+
+    assert!(authority.is_allowed(command, window, webview, &Origin::Local));
+    assert!(authority
+      .resolve_access(command, window, webview, &Origin::Local)
+      .is_some());
+    assert!(!authority.is_allowed(
+      command,
+      window,
+      webview,
+      &Origin::Remote {
+        url: url.parse().unwrap()
+      }
+    ));
+  }
+
+  #[test]
+  fn add_remote_url_within_runtime_urls() {
+    let permission = Permission {
+      identifier: "allow-ping".into(),
+      commands: Commands {
+        allow: vec!["ping".into()],
+        deny: Vec::new(),
+      },
+      ..Default::default()
+    };
+    let acl = [(
+      "ping".to_string(),
+      Manifest {
+        default_permission: None,
+        permissions: [(permission.identifier.clone(), permission)].into(),
+        permission_sets: Default::default(),
+        global_scope_schema: None,
+      },
+    )]
+    .into();
+    let capability = Capability {
+      identifier: "tenants".into(),
+      description: String::new(),
+      remote: Some(CapabilityRemote {
+        urls: Vec::new(),
+        runtime_urls: vec!["https://*.tauri.app".into()],
+      }),
+      local: false,
+      windows: vec!["main".into()],
+      webviews: Vec::new(),
+      permissions: vec![PermissionEntry::PermissionRef(
+        "ping:allow-ping".to_string().try_into().unwrap(),
+      )],
+      platforms: None,
+      enabled_on_features: Vec::new(),
+      include_required_permissions: false,
+      variables: Default::default(),
+    };
+    let resolved = Resolved::resolve(
+      &acl,
+      [(capability.identifier.clone(), capability)].into(),
+      Target::current(),
+    )
+    .unwrap();
+    let mut authority = RuntimeAuthority::new(acl, resolved);
+
+    let command = "plugin:ping|ping";
+    let origin = Origin::Remote {
+      url: "https://acme.tauri.app".parse().unwrap(),
+    };
+    assert!(!authority.is_allowed(command, "main", "main", &origin));
+
+    assert!(matches!(
+      authority.add_remote_url("tenants", "https://tauri.app.evil.com"),
+      Err(crate::Error::CapabilityRemoteUrl { .. })
+    ));
+    assert!(matches!(
+      authority.add_remote_url("unknown", "https://acme.tauri.app"),
+      Err(crate::Error::CapabilityRemoteUrl { .. })
+    ));
+
+    authority
+      .add_remote_url("tenants", "https://acme.tauri.app")
+      .unwrap();
+    assert!(authority.is_allowed(command, "main", "main", &origin));
+    assert!(!authority.is_allowed(command, "main", "main", &Origin::Local));
+    assert!(!authority.is_allowed(
+      command,
+      "main",
+      "main",
+      &Origin::Remote {
+        url: "https://other.tauri.app".parse().unwrap()
+      }
+    ));
+  }
+
   #[test]
   fn denied_command_takes_precendence() {
     let command = "my-command";
@@ -1302,4 +1674,116 @@ mod tests {
       "myplugin.my-command-webview-window not allowed on window \"main-*\", webview \"webview-*\", URL: http://localhost:123/\n\nallowed on: [windows: \"main-*\", webviews: \"webview-*\", URL: local], [windows: \"main-*\", webviews: \"webview-*\", URL: http://localhost:8080]\n\nreferenced by: capability: maincap, permission: allow-command || capability: maincap, permission: allow-command"
     );
   }
+
+  #[test]
+  fn rate_limits_commands_per_origin() {
+    use std::num::NonZeroU32;
+
+    let command = "my-command";
+    let limited = ResolvedCommand {
+      max_calls_per_minute: NonZeroU32::new(2),
+      ..Default::default()
+    };
+    let mut authority = RuntimeAuthority::new(Default::default(), Resolved::default());
+
+    let local = Origin::Local;
+    for _ in 0..2 {
+      assert!(authority
+        .check_rate_limit(command, &local, &[limited.clone()])
+        .is_ok());
+    }
+    assert!(matches!(
+      authority.check_rate_limit(command, &local, &[limited.clone()]),
+      Err(crate::Error::CommandRateLimited {
+        max_calls_per_minute: 2,
+        ..
+      })
+    ));
+
+    // each execution context has its own budget
+    let remote = Origin::Remote {
+      url: "https://tauri.app/page".parse().unwrap(),
+    };
+    assert!(authority
+      .check_rate_limit(command, &remote, &[limited.clone()])
+      .is_ok());
+    // and so does each command
+    assert!(authority
+      .check_rate_limit("other-command", &local, &[limited.clone()])
+      .is_ok());
+    // a permission without limit allows unlimited calls
+    assert!(authority
+      .check_rate_limit(command, &local, &[limited, ResolvedCommand::default()])
+      .is_ok());
+  }
+
+  #[test]
+  fn argument_scope_one_of() {
+    use crate::ipc::InvokeBody;
+    use tauri_utils::acl::resolved::ResolvedScope;
+
+    let command = "my-command";
+    let mode_scope = |mode: serde_json::Value| -> tauri_utils::acl::Value {
+      serde_json::json!({ "$args": { "mode": mode } }).into()
+    };
+    let resolved = Resolved {
+      command_scope: [
+        (
+          1,
+          ResolvedScope {
+            allow: vec![mode_scope(serde_json::json!(["read", "write"]))],
+            deny: Vec::new(),
+          },
+        ),
+        (
+          2,
+          ResolvedScope {
+            allow: Vec::new(),
+            deny: vec![mode_scope(serde_json::json!("write"))],
+          },
+        ),
+      ]
+      .into(),
+      ..Default::default()
+    };
+    let authority = RuntimeAuthority::new(Default::default(), resolved);
+
+    let constrained = ResolvedCommand {
+      scope_id: Some(1),
+      ..Default::default()
+    };
+    let denied = ResolvedCommand {
+      scope_id: Some(2),
+      ..Default::default()
+    };
+    let args = |mode: &str| InvokeBody::Json(serde_json::json!({ "mode": mode }));
+
+    assert!(authority
+      .check_argument_scope(command, &[constrained.clone()], &args("read"))
+      .is_ok());
+    assert!(matches!(
+      authority.check_argument_scope(command, &[constrained.clone()], &args("execute")),
+      Err(crate::Error::CommandArgumentsNotAllowed { .. })
+    ));
+    assert!(authority
+      .check_argument_scope(
+        command,
+        &[constrained.clone()],
+        &InvokeBody::Raw(Vec::new())
+      )
+      .is_err());
+
+    // a permission that does not constrain the arguments allows any of them
+    assert!(authority
+      .check_argument_scope(
+        command,
+        &[constrained.clone(), ResolvedCommand::default()],
+        &args("execute")
+      )
+      .is_ok());
+    // but denied arguments are always rejected
+    assert!(authority
+      .check_argument_scope(command, &[constrained, denied], &args("write"))
+      .is_err());
+  }
 }
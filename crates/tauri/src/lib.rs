@@ -13,6 +13,7 @@
 //! - **wry** *(enabled by default)*: Enables the [wry](https://github.com/tauri-apps/wry) runtime. Only disable it if you want a custom runtime.
 //! - **common-controls-v6** *(enabled by default)*: Enables [Common Controls v6](https://learn.microsoft.com/en-us/windows/win32/controls/common-control-versions) support on Windows, mainly for the predefined `about` menu item.
 //! - **unstable**: Enables unstable features. Be careful, it might introduce breaking changes in future minor releases.
+//! - **webview-tabs**: Enables the [`webview::tabs`] module to manage child webviews as tabs. Implies **unstable**.
 //! - **tracing**: Enables [`tracing`](https://docs.rs/tracing/latest/tracing) for window startup, plugins, `Window::eval`, events, IPC, updater and custom protocol request handlers.
 //! - **test**: Enables the [`mod@test`] module exposing unit test helpers.
 //! - **objc-exception**: Wrap each msg_send! in a @try/@catch and panics if an exception is caught, preventing Objective-C from unwinding into Rust.
@@ -218,7 +219,7 @@ pub use {
   self::runtime::{
     dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Pixel, Position, Size},
     webview::WebviewAttributes,
-    window::{CursorIcon, DragDropEvent, WindowSizeConstraints},
+    window::{CursorIcon, DragDropEvent, FileDropEvent, MainFrameResponse, WindowSizeConstraints},
     DeviceEventFilter, Rect, UserAttentionType,
   },
   self::state::{State, StateManager},
@@ -812,6 +813,32 @@ pub trait Manager<R: Runtime>: sealed::ManagerBase<R> {
       .unwrap()
       .add_capability(capability)
   }
+
+  /// Allows the given remote URL pattern to use the permissions of a capability at runtime,
+  /// for instance when the allowed origins are fetched from a configuration server.
+  ///
+  /// The capability must declare the patterns that can be added at runtime in its `remote > runtimeUrls` configuration.
+  /// Returns [`Error::CapabilityRemoteUrl`] if the capability does not declare them or the URL does not match any of them.
+  ///
+  /// # Examples
+  /// ```
+  /// use tauri::Manager;
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     // the `tenants` capability declares `"remote": { "urls": [], "runtimeUrls": ["https://*.tauri.app"] }`
+  ///     app.add_capability_remote_url("tenants", "https://acme.tauri.app")?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  fn add_capability_remote_url(&self, capability: &str, url: &str) -> Result<()> {
+    self
+      .manager()
+      .runtime_authority
+      .lock()
+      .unwrap()
+      .add_remote_url(capability, url)
+  }
 }
 
 /// Listen to events.
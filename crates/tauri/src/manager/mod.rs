@@ -282,6 +282,14 @@ impl<R: Runtime> AppManager<R> {
       },
       webview: webview::WebviewManager {
         webviews: Mutex::default(),
+        tab_indices: Mutex::default(),
+        document_start_scripts: Mutex::default(),
+        document_titles: Mutex::default(),
+        pending_reveals: Mutex::default(),
+        remote_debugging_ports: Mutex::default(),
+        background_throttling: Mutex::default(),
+        loading_webviews: Mutex::default(),
+        loading_state_listeners: Mutex::default(),
         invoke_handler,
         on_page_load,
         uri_scheme_protocols: Mutex::new(uri_scheme_protocols),
@@ -376,6 +384,18 @@ impl<R: Runtime> AppManager<R> {
     }
   }
 
+  /// Injects the base URL and the CSP to HTML content that is loaded directly on a webview.
+  pub(crate) fn prepare_html(&self, html: String, base_url: Option<&Url>) -> String {
+    let document = tauri_utils::html::parse(html);
+    if let Some(base_url) = base_url {
+      tauri_utils::html::inject_base_url(&document, base_url.as_str());
+    }
+    if let Some(csp) = self.csp() {
+      tauri_utils::html::inject_csp(&document, &csp.to_string());
+    }
+    document.to_string()
+  }
+
   pub fn get_asset(
     &self,
     mut path: String,
@@ -641,6 +661,30 @@ impl<R: Runtime> AppManager<R> {
     }
   }
 
+  /// Emits an event to the given webview only, skipping the listeners of every other webview.
+  ///
+  /// The frontend listeners of the webview receive the event unless they target another label.
+  pub fn emit_to_webview<S: Serialize + Clone>(
+    &self,
+    webview: &Webview<R>,
+    event: &str,
+    payload: S,
+  ) -> crate::Result<()> {
+    assert_event_name_is_valid(event);
+
+    let emit_args = EmitArgs::new(event, payload)?;
+    let filter = |t: &EventTarget| match t {
+      EventTarget::AnyLabel { label } | EventTarget::Webview { label } => label == webview.label(),
+      _ => false,
+    };
+
+    let listeners = self.listeners();
+    listeners.emit_js_filter(std::iter::once(webview), event, &emit_args, Some(&filter))?;
+    listeners.emit_filter(emit_args, Some(filter))?;
+
+    Ok(())
+  }
+
   pub fn get_window(&self, label: &str) -> Option<Window<R>> {
     self.window.windows_lock().get(label).cloned()
   }
@@ -659,6 +703,54 @@ impl<R: Runtime> AppManager<R> {
     if let Some(window) = window {
       for webview in window.webviews() {
         self.webview.webviews_lock().remove(webview.label());
+        self
+          .webview
+          .tab_indices
+          .lock()
+          .unwrap()
+          .remove(webview.label());
+        self
+          .webview
+          .document_start_scripts
+          .lock()
+          .unwrap()
+          .remove(webview.label());
+        self
+          .webview
+          .document_titles
+          .lock()
+          .unwrap()
+          .remove(webview.label());
+        self
+          .webview
+          .pending_reveals
+          .lock()
+          .unwrap()
+          .remove(webview.label());
+        self
+          .webview
+          .remote_debugging_ports
+          .lock()
+          .unwrap()
+          .remove(webview.label());
+        self
+          .webview
+          .background_throttling
+          .lock()
+          .unwrap()
+          .remove(webview.label());
+        self
+          .webview
+          .loading_webviews
+          .lock()
+          .unwrap()
+          .remove(webview.label());
+        self
+          .webview
+          .loading_state_listeners
+          .lock()
+          .unwrap()
+          .remove(webview.label());
       }
     }
   }
@@ -666,6 +758,34 @@ impl<R: Runtime> AppManager<R> {
   #[cfg(desktop)]
   pub(crate) fn on_webview_close(&self, label: &str) {
     self.webview.webviews_lock().remove(label);
+    self.webview.tab_indices.lock().unwrap().remove(label);
+    self
+      .webview
+      .document_start_scripts
+      .lock()
+      .unwrap()
+      .remove(label);
+    self.webview.document_titles.lock().unwrap().remove(label);
+    self.webview.pending_reveals.lock().unwrap().remove(label);
+    self
+      .webview
+      .remote_debugging_ports
+      .lock()
+      .unwrap()
+      .remove(label);
+    self
+      .webview
+      .background_throttling
+      .lock()
+      .unwrap()
+      .remove(label);
+    self.webview.loading_webviews.lock().unwrap().remove(label);
+    self
+      .webview
+      .loading_state_listeners
+      .lock()
+      .unwrap()
+      .remove(label);
   }
 
   pub fn windows(&self) -> HashMap<String, Window<R>> {
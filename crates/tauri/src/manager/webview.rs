@@ -13,7 +13,7 @@ use std::{
 use serde::Serialize;
 use serialize_to_javascript::{default_template, DefaultTemplate, Template};
 use tauri_runtime::{
-  webview::{DetachedWebview, PendingWebview},
+  webview::{DetachedWebview, PageLoadEvent, PendingWebview},
   window::DragDropEvent,
 };
 use tauri_utils::config::WebviewUrl;
@@ -24,7 +24,7 @@ use crate::{
   ipc::InvokeHandler,
   pattern::PatternJavascript,
   sealed::ManagerBase,
-  webview::PageLoadPayload,
+  webview::{BackgroundThrottlePolicy, PageLoadPayload, RevealTrigger},
   Emitter, EventLoopMessage, EventTarget, Manager, Runtime, Scopes, UriSchemeContext, Webview,
   Window,
 };
@@ -57,6 +57,9 @@ pub(crate) struct IpcJavascript<'a> {
   pub(crate) isolation_origin: &'a str,
 }
 
+/// A listener registered with [`crate::webview::Webview::on_loading_state_changed`].
+pub(crate) type OnLoadingStateChanged = dyn Fn(bool) + Send + Sync + 'static;
+
 /// Uses a custom URI scheme handler to resolve file requests
 pub struct UriSchemeProtocol<R: Runtime> {
   /// Handler for protocol
@@ -67,6 +70,27 @@ pub struct UriSchemeProtocol<R: Runtime> {
 
 pub struct WebviewManager<R: Runtime> {
   pub webviews: Mutex<HashMap<String, Webview<R>>>,
+  /// The tab index of the webviews that declared one, see [`crate::webview::WebviewBuilder::tab_index`].
+  pub(crate) tab_indices: Mutex<HashMap<String, i32>>,
+  /// The named scripts evaluated when a webview starts loading a page,
+  /// see [`crate::webview::Webview::add_document_start_script`].
+  pub(crate) document_start_scripts: Mutex<HashMap<String, Vec<(String, String)>>>,
+  /// The document title last reported by each webview, see [`crate::webview::Webview::document_title`].
+  pub(crate) document_titles: Mutex<HashMap<String, String>>,
+  /// The webviews waiting to be shown and whether they should be focused then,
+  /// see [`crate::webview::WebviewBuilder::reveal_on`].
+  pub(crate) pending_reveals: Mutex<HashMap<String, (RevealTrigger, bool)>>,
+  /// The remote debugging port of the webviews that enabled it,
+  /// see [`crate::webview::WebviewBuilder::remote_debugging`].
+  pub(crate) remote_debugging_ports: Mutex<HashMap<String, u16>>,
+  /// How the webviews that set it run while hidden,
+  /// see [`crate::webview::Webview::set_background_throttling`].
+  pub(crate) background_throttling: Mutex<HashMap<String, BackgroundThrottlePolicy>>,
+  /// The webviews currently loading a page, see [`crate::webview::Webview::is_loading`].
+  pub(crate) loading_webviews: Mutex<HashSet<String>>,
+  /// The listeners of the loading state of each webview,
+  /// see [`crate::webview::Webview::on_loading_state_changed`].
+  pub(crate) loading_state_listeners: Mutex<HashMap<String, Vec<Arc<OnLoadingStateChanged>>>>,
   /// The JS message handler.
   pub invoke_handler: Box<InvokeHandler<R>>,
   /// The page load hook, invoked when the webview performs a navigation.
@@ -283,6 +307,16 @@ impl<R: Runtime> WebviewManager<R> {
         let payload = PageLoadPayload { url: &url, event };
 
         if let Some(w) = app_manager_.get_webview(&label) {
+          w.set_loading(event == PageLoadEvent::Started);
+
+          if event == PageLoadEvent::Started {
+            if let Err(e) = w.eval_document_start_scripts() {
+              log::error!("failed to evaluate document start scripts: {e}");
+            }
+          } else if let Err(e) = w.reveal(RevealTrigger::PageLoad) {
+            log::error!("failed to reveal webview: {e}");
+          }
+
           if let Some(on_page_load) = &app_manager_.webview.on_page_load {
             on_page_load(&w, &payload);
           }
@@ -299,6 +333,16 @@ impl<R: Runtime> WebviewManager<R> {
         }
       }));
 
+    let label = pending.label.clone();
+    let app_manager_ = manager.manager_owned();
+    pending
+      .document_title_changed_handler
+      .replace(Box::new(move |title| {
+        if let Some(w) = app_manager_.get_webview(&label) {
+          w.set_document_title(title);
+        }
+      }));
+
     #[cfg(feature = "protocol-asset")]
     if !registered_scheme_protocols.contains(&"asset".into()) {
       let asset_scope = app_manager
@@ -446,6 +490,11 @@ impl<R: Runtime> WebviewManager<R> {
       }
 
       WebviewUrl::CustomProtocol(url) => url.clone(),
+      WebviewUrl::Html(html) => {
+        pending.html = Some(app_manager.prepare_html(html.clone(), None));
+        "about:blank".parse().unwrap()
+      }
+      WebviewUrl::Lazy => "about:blank".parse().unwrap(),
       _ => unimplemented!(),
     };
 
@@ -645,6 +694,19 @@ impl<R: Runtime> WebviewManager<R> {
     self.webviews_lock().keys().cloned().collect()
   }
 
+  /// Records the remote debugging port of a created webview.
+  ///
+  /// Only WebView2 serves it, so it is not recorded on the other platforms.
+  pub(crate) fn set_remote_debugging_port(&self, label: &str, port: Option<u16>) {
+    if let (true, Some(port)) = (cfg!(windows), port) {
+      self
+        .remote_debugging_ports
+        .lock()
+        .unwrap()
+        .insert(label.to_string(), port);
+    }
+  }
+
   pub(crate) fn invoke_key(&self) -> &str {
     &self.invoke_key
   }
@@ -698,6 +760,10 @@ fn on_webview_event<R: Runtime>(webview: &Webview<R>, event: &WebviewEvent) -> c
       DragDropEvent::Leave => webview.emit_to_webview(DRAG_LEAVE_EVENT, ())?,
       _ => unimplemented!(),
     },
+    WebviewEvent::FindMatchCount(_)
+    | WebviewEvent::FaviconChanged(_)
+    | WebviewEvent::LoadProgress(_)
+    | WebviewEvent::MainFrameResponse(_) => {}
   }
 
   Ok(())
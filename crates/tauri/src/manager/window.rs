@@ -13,7 +13,7 @@ use serde::Serialize;
 use tauri_runtime::{
   dpi::{PhysicalPosition, PhysicalSize},
   window::WindowBuilder,
-  window::{DetachedWindow, DragDropEvent, PendingWindow},
+  window::{DetachedWindow, DragDropEvent, FileDropEvent, PendingWindow},
 };
 
 use crate::{
@@ -33,6 +33,7 @@ pub(crate) const DRAG_ENTER_EVENT: &str = "tauri://drag-enter";
 pub(crate) const DRAG_OVER_EVENT: &str = "tauri://drag-over";
 pub(crate) const DRAG_DROP_EVENT: &str = "tauri://drag-drop";
 pub(crate) const DRAG_LEAVE_EVENT: &str = "tauri://drag-leave";
+const FILE_DROP_EVENT: &str = "tauri://file-drop";
 
 pub struct WindowManager<R: Runtime> {
   pub windows: Mutex<HashMap<String, Window<R>>>,
@@ -124,7 +125,11 @@ impl<R: Runtime> WindowManager<R> {
 
 impl<R: Runtime> Window<R> {
   /// Emits event to [`EventTarget::Window`] and [`EventTarget::WebviewWindow`]
-  fn emit_to_window<S: Serialize + Clone>(&self, event: &str, payload: S) -> crate::Result<()> {
+  pub(crate) fn emit_to_window<S: Serialize + Clone>(
+    &self,
+    event: &str,
+    payload: S,
+  ) -> crate::Result<()> {
     let window_label = self.label();
     self.emit_filter(event, payload, |target| match target {
       EventTarget::Window { label } | EventTarget::WebviewWindow { label } => label == window_label,
@@ -150,6 +155,24 @@ pub(crate) struct DragDropPayload<'a> {
   pub position: &'a PhysicalPosition<f64>,
 }
 
+#[derive(Serialize, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum FileDropPayload<'a> {
+  Hover {
+    webview: &'a str,
+    paths: &'a Vec<PathBuf>,
+    position: &'a PhysicalPosition<f64>,
+  },
+  Drop {
+    webview: &'a str,
+    paths: &'a Vec<PathBuf>,
+    position: &'a PhysicalPosition<f64>,
+  },
+  Cancel {
+    webview: &'a str,
+  },
+}
+
 fn on_window_event<R: Runtime>(window: &Window<R>, event: &WindowEvent) -> crate::Result<()> {
   match event {
     WindowEvent::Resized(size) => window.emit_to_window(WINDOW_RESIZED_EVENT, size)?,
@@ -247,6 +270,32 @@ fn on_window_event<R: Runtime>(window: &Window<R>, event: &WindowEvent) -> crate
       }
       _ => unimplemented!(),
     },
+    WindowEvent::FileDrop(event) => {
+      let payload = match event {
+        FileDropEvent::Hovered {
+          webview,
+          paths,
+          position,
+        } => FileDropPayload::Hover {
+          webview,
+          paths,
+          position,
+        },
+        FileDropEvent::Dropped {
+          webview,
+          paths,
+          position,
+        } => FileDropPayload::Drop {
+          webview,
+          paths,
+          position,
+        },
+        FileDropEvent::Cancelled { webview } => FileDropPayload::Cancel { webview },
+        // phases added later are not emitted to the frontend
+        _ => return Ok(()),
+      };
+      window.emit_to_window(FILE_DROP_EVENT, payload)?
+    }
     WindowEvent::ThemeChanged(theme) => {
       window.emit_to_window(WINDOW_THEME_CHANGED, theme.to_string())?
     }
@@ -260,3 +309,39 @@ struct ScaleFactorChanged {
   scale_factor: f64,
   size: PhysicalSize<u32>,
 }
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use tauri_runtime::dpi::PhysicalPosition;
+
+  use super::FileDropPayload;
+
+  #[test]
+  fn file_drop_payload_identifies_webview() {
+    let paths = vec![PathBuf::from("/tmp/image.png")];
+    let position = PhysicalPosition::new(10., 20.);
+    assert_eq!(
+      serde_json::to_value(FileDropPayload::Drop {
+        webview: "content",
+        paths: &paths,
+        position: &position,
+      })
+      .unwrap(),
+      serde_json::json!({
+        "type": "drop",
+        "webview": "content",
+        "paths": ["/tmp/image.png"],
+        "position": { "x": 10.0, "y": 20.0 },
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(FileDropPayload::Cancel {
+        webview: "titlebar"
+      })
+      .unwrap(),
+      serde_json::json!({ "type": "cancel", "webview": "titlebar" })
+    );
+  }
+}
@@ -6,9 +6,9 @@
 #![allow(missing_docs)]
 
 use tauri_runtime::{
-  dpi::{PhysicalPosition, PhysicalSize, Position, Size},
+  dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Position, Size},
   monitor::Monitor,
-  webview::{DetachedWebview, PendingWebview},
+  webview::{DetachedWebview, FindOptions, PageLoadEvent, PendingWebview},
   window::{
     CursorIcon, DetachedWindow, DetachedWindowWebview, PendingWindow, RawWindow, WindowBuilder,
     WindowBuilderBase, WindowEvent, WindowId,
@@ -166,8 +166,25 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
         dispatcher: MockWebviewDispatcher {
           id,
           context: self.context.clone(),
-          url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
+          url: Arc::new(Mutex::new(pending.webview.as_ref().unwrap().url.clone())),
+          html: Arc::new(Mutex::new(pending.webview.as_ref().unwrap().html.clone())),
+          visible: Arc::new(Mutex::new(
+            pending.webview.as_ref().unwrap().webview_attributes.visible,
+          )),
+          bounds: Arc::new(Mutex::new(
+            pending
+              .webview
+              .as_ref()
+              .unwrap()
+              .webview_attributes
+              .bounds
+              .unwrap_or_default(),
+          )),
+          on_page_load: PageLoadHandler(Arc::new(Mutex::new(
+            pending.webview.unwrap().on_page_load_handler,
+          ))),
           last_evaluated_script: Default::default(),
+          suspended: Default::default(),
         },
       },
       use_https_scheme: false,
@@ -179,6 +196,9 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
       dispatcher: MockWindowDispatcher {
         id,
         context: self.context.clone(),
+        title: Default::default(),
+        buttons: Arc::new(Mutex::new(pending.window_builder.buttons)),
+        content_protected: Arc::new(Mutex::new(pending.window_builder.content_protected)),
       },
       webview,
     })
@@ -201,7 +221,14 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
         id,
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
+        suspended: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        html: Arc::new(Mutex::new(pending.html)),
+        visible: Arc::new(Mutex::new(pending.webview_attributes.visible)),
+        bounds: Arc::new(Mutex::new(
+          pending.webview_attributes.bounds.unwrap_or_default(),
+        )),
+        on_page_load: PageLoadHandler(Arc::new(Mutex::new(pending.on_page_load_handler))),
       },
     })
   }
@@ -237,15 +264,15 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
   }
 
   fn primary_monitor(&self) -> Option<Monitor> {
-    unimplemented!()
+    None
   }
 
   fn monitor_from_point(&self, x: f64, y: f64) -> Option<Monitor> {
-    unimplemented!()
+    None
   }
 
   fn available_monitors(&self) -> Vec<Monitor> {
-    unimplemented!()
+    Vec::new()
   }
 
   fn set_theme(&self, theme: Option<Theme>) {
@@ -287,38 +314,107 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
   }
 }
 
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct PageLoadHandler(Arc<Mutex<Option<Box<dyn Fn(Url, PageLoadEvent) + Send>>>>);
+
+impl fmt::Debug for PageLoadHandler {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("PageLoadHandler").finish()
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct MockWebviewDispatcher {
   id: u32,
   context: RuntimeContext,
   url: Arc<Mutex<String>>,
+  html: Arc<Mutex<Option<String>>>,
+  visible: Arc<Mutex<bool>>,
+  bounds: Arc<Mutex<tauri_runtime::Rect>>,
+  on_page_load: PageLoadHandler,
   last_evaluated_script: Arc<Mutex<Option<String>>>,
+  suspended: Arc<Mutex<bool>>,
 }
 
 impl MockWebviewDispatcher {
   pub fn last_evaluated_script(&self) -> Option<String> {
     self.last_evaluated_script.lock().unwrap().clone()
   }
+
+  pub fn html(&self) -> Option<String> {
+    self.html.lock().unwrap().clone()
+  }
+
+  pub fn is_visible(&self) -> bool {
+    *self.visible.lock().unwrap()
+  }
+
+  pub fn is_suspended(&self) -> bool {
+    *self.suspended.lock().unwrap()
+  }
 }
 
 #[derive(Debug, Clone)]
 pub struct MockWindowDispatcher {
   id: WindowId,
   context: RuntimeContext,
+  title: Arc<Mutex<String>>,
+  buttons: Arc<Mutex<WindowButtons>>,
+  content_protected: Arc<Mutex<bool>>,
+}
+
+impl MockWindowDispatcher {
+  pub fn is_content_protected(&self) -> bool {
+    *self.content_protected.lock().unwrap()
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct WindowButtons {
+  maximizable: bool,
+  minimizable: bool,
+  closable: bool,
+}
+
+impl Default for WindowButtons {
+  fn default() -> Self {
+    Self {
+      maximizable: true,
+      minimizable: true,
+      closable: true,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
-pub struct MockWindowBuilder {}
+pub struct MockWindowBuilder {
+  buttons: WindowButtons,
+  content_protected: bool,
+  pub(crate) app_id: Option<String>,
+}
 
 impl WindowBuilderBase for MockWindowBuilder {}
 
 impl WindowBuilder for MockWindowBuilder {
   fn new() -> Self {
-    Self {}
+    Self {
+      buttons: Default::default(),
+      content_protected: false,
+      app_id: None,
+    }
   }
 
   fn with_config(config: &WindowConfig) -> Self {
-    Self {}
+    Self {
+      buttons: WindowButtons {
+        maximizable: config.maximizable,
+        minimizable: config.minimizable,
+        closable: config.closable,
+      },
+      content_protected: config.content_protected,
+      app_id: None,
+    }
   }
 
   fn center(self) -> Self {
@@ -352,15 +448,18 @@ impl WindowBuilder for MockWindowBuilder {
     self
   }
 
-  fn maximizable(self, resizable: bool) -> Self {
+  fn maximizable(mut self, maximizable: bool) -> Self {
+    self.buttons.maximizable = maximizable;
     self
   }
 
-  fn minimizable(self, resizable: bool) -> Self {
+  fn minimizable(mut self, minimizable: bool) -> Self {
+    self.buttons.minimizable = minimizable;
     self
   }
 
-  fn closable(self, resizable: bool) -> Self {
+  fn closable(mut self, closable: bool) -> Self {
+    self.buttons.closable = closable;
     self
   }
 
@@ -409,7 +508,8 @@ impl WindowBuilder for MockWindowBuilder {
     self
   }
 
-  fn content_protected(self, protected: bool) -> Self {
+  fn content_protected(mut self, protected: bool) -> Self {
+    self.content_protected = protected;
     self
   }
 
@@ -425,6 +525,11 @@ impl WindowBuilder for MockWindowBuilder {
     self
   }
 
+  fn app_id<S: Into<String>>(mut self, app_id: S) -> Self {
+    self.app_id.replace(app_id.into());
+    self
+  }
+
   fn shadow(self, enable: bool) -> Self {
     self
   }
@@ -460,11 +565,15 @@ impl WindowBuilder for MockWindowBuilder {
     self
   }
 
-  #[cfg(target_os = "macos")]
   fn title_bar_style(self, style: TitleBarStyle) -> Self {
     self
   }
 
+  #[cfg(target_os = "macos")]
+  fn traffic_light_position(self, x: f64, y: f64) -> Self {
+    self
+  }
+
   #[cfg(target_os = "macos")]
   fn hidden_title(self, transparent: bool) -> Self {
     self
@@ -539,22 +648,41 @@ impl<T: UserEvent> WebviewDispatch<T> for MockWebviewDispatcher {
   }
 
   fn bounds(&self) -> Result<tauri_runtime::Rect> {
-    Ok(tauri_runtime::Rect::default())
+    Ok(*self.bounds.lock().unwrap())
   }
 
   fn position(&self) -> Result<PhysicalPosition<i32>> {
-    Ok(PhysicalPosition { x: 0, y: 0 })
+    // the mock windows have a scale factor of 1
+    Ok(self.bounds.lock().unwrap().position.to_physical(1.0))
   }
 
   fn size(&self) -> Result<PhysicalSize<u32>> {
-    Ok(PhysicalSize {
-      width: 0,
-      height: 0,
-    })
+    Ok(self.bounds.lock().unwrap().size.to_physical(1.0))
+  }
+
+  fn favicon(&self) -> Result<Option<Url>> {
+    Ok(None)
   }
 
   fn navigate(&self, url: Url) -> Result<()> {
-    *self.url.lock().unwrap() = url.to_string();
+    // hosts under the reserved `.invalid` TLD fail to load, the webview engines still report the finished load
+    let fails = url
+      .host_str()
+      .is_some_and(|host| host.ends_with(".invalid"));
+    if !fails {
+      *self.url.lock().unwrap() = url.to_string();
+      self.html.lock().unwrap().take();
+    }
+    if let Some(handler) = &*self.on_page_load.0.lock().unwrap() {
+      handler(url.clone(), PageLoadEvent::Started);
+      handler(url, PageLoadEvent::Finished);
+    }
+    Ok(())
+  }
+
+  fn load_html(&self, html: String) -> Result<()> {
+    *self.url.lock().unwrap() = "about:blank".to_string();
+    self.html.lock().unwrap().replace(html);
     Ok(())
   }
 
@@ -567,14 +695,17 @@ impl<T: UserEvent> WebviewDispatch<T> for MockWebviewDispatcher {
   }
 
   fn set_bounds(&self, bounds: tauri_runtime::Rect) -> Result<()> {
+    *self.bounds.lock().unwrap() = bounds;
     Ok(())
   }
 
-  fn set_size(&self, _size: Size) -> Result<()> {
+  fn set_size(&self, size: Size) -> Result<()> {
+    self.bounds.lock().unwrap().size = size;
     Ok(())
   }
 
-  fn set_position(&self, _position: Position) -> Result<()> {
+  fn set_position(&self, position: Position) -> Result<()> {
+    self.bounds.lock().unwrap().position = position;
     Ok(())
   }
 
@@ -590,15 +721,59 @@ impl<T: UserEvent> WebviewDispatch<T> for MockWebviewDispatcher {
     Ok(())
   }
 
+  fn set_size_constraints(
+    &self,
+    min: Option<LogicalSize<f64>>,
+    max: Option<LogicalSize<f64>>,
+  ) -> Result<()> {
+    Ok(())
+  }
+
   fn clear_all_browsing_data(&self) -> Result<()> {
     Ok(())
   }
 
+  fn set_user_agent(&self, user_agent: &str) -> Result<()> {
+    Ok(())
+  }
+
+  fn set_accept_language(&self, language: &str) -> Result<()> {
+    tauri_runtime::webview::validate_language_tag(language)
+  }
+
+  fn set_spellcheck(&self, _enabled: bool) -> Result<()> {
+    Ok(())
+  }
+
+  fn set_autofill(&self, _enabled: bool) -> Result<()> {
+    Ok(())
+  }
+
+  fn set_suspended(&self, suspended: bool) -> Result<()> {
+    // visible webviews cannot be suspended
+    *self.suspended.lock().unwrap() = suspended && !self.is_visible();
+    Ok(())
+  }
+
+  fn find(&self, _query: &str, _options: FindOptions) -> Result<()> {
+    Ok(())
+  }
+
+  fn find_next(&self) -> Result<()> {
+    Ok(())
+  }
+
+  fn stop_find(&self) -> Result<()> {
+    Ok(())
+  }
+
   fn hide(&self) -> Result<()> {
+    *self.visible.lock().unwrap() = false;
     Ok(())
   }
 
   fn show(&self) -> Result<()> {
+    *self.visible.lock().unwrap() = true;
     Ok(())
   }
 
@@ -671,15 +846,15 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn is_maximizable(&self) -> Result<bool> {
-    Ok(true)
+    Ok(self.buttons.lock().unwrap().maximizable)
   }
 
   fn is_minimizable(&self) -> Result<bool> {
-    Ok(true)
+    Ok(self.buttons.lock().unwrap().minimizable)
   }
 
   fn is_closable(&self) -> Result<bool> {
-    Ok(true)
+    Ok(self.buttons.lock().unwrap().closable)
   }
 
   fn is_visible(&self) -> Result<bool> {
@@ -687,7 +862,7 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn title(&self) -> Result<String> {
-    Ok(String::new())
+    Ok(self.title.lock().unwrap().clone())
   }
 
   fn current_monitor(&self) -> Result<Option<Monitor>> {
@@ -796,8 +971,25 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
         dispatcher: MockWebviewDispatcher {
           id,
           context: self.context.clone(),
-          url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
+          url: Arc::new(Mutex::new(pending.webview.as_ref().unwrap().url.clone())),
+          html: Arc::new(Mutex::new(pending.webview.as_ref().unwrap().html.clone())),
+          visible: Arc::new(Mutex::new(
+            pending.webview.as_ref().unwrap().webview_attributes.visible,
+          )),
+          bounds: Arc::new(Mutex::new(
+            pending
+              .webview
+              .as_ref()
+              .unwrap()
+              .webview_attributes
+              .bounds
+              .unwrap_or_default(),
+          )),
+          on_page_load: PageLoadHandler(Arc::new(Mutex::new(
+            pending.webview.unwrap().on_page_load_handler,
+          ))),
           last_evaluated_script: Default::default(),
+          suspended: Default::default(),
         },
       },
       use_https_scheme: false,
@@ -809,6 +1001,9 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
       dispatcher: MockWindowDispatcher {
         id,
         context: self.context.clone(),
+        title: Default::default(),
+        buttons: Arc::new(Mutex::new(pending.window_builder.buttons)),
+        content_protected: Arc::new(Mutex::new(pending.window_builder.content_protected)),
       },
       webview,
     })
@@ -830,7 +1025,14 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
         id,
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
+        suspended: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        html: Arc::new(Mutex::new(pending.html)),
+        visible: Arc::new(Mutex::new(pending.webview_attributes.visible)),
+        bounds: Arc::new(Mutex::new(
+          pending.webview_attributes.bounds.unwrap_or_default(),
+        )),
+        on_page_load: PageLoadHandler(Arc::new(Mutex::new(pending.on_page_load_handler))),
       },
     })
   }
@@ -840,18 +1042,22 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn set_maximizable(&self, maximizable: bool) -> Result<()> {
+    self.buttons.lock().unwrap().maximizable = maximizable;
     Ok(())
   }
 
   fn set_minimizable(&self, minimizable: bool) -> Result<()> {
+    self.buttons.lock().unwrap().minimizable = minimizable;
     Ok(())
   }
 
   fn set_closable(&self, closable: bool) -> Result<()> {
+    self.buttons.lock().unwrap().closable = closable;
     Ok(())
   }
 
   fn set_title<S: Into<String>>(&self, title: S) -> Result<()> {
+    *self.title.lock().unwrap() = title.into();
     Ok(())
   }
 
@@ -910,6 +1116,7 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn set_content_protected(&self, protected: bool) -> Result<()> {
+    *self.content_protected.lock().unwrap() = protected;
     Ok(())
   }
 
@@ -1107,8 +1314,25 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
         dispatcher: MockWebviewDispatcher {
           id,
           context: self.context.clone(),
-          url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
+          url: Arc::new(Mutex::new(pending.webview.as_ref().unwrap().url.clone())),
+          html: Arc::new(Mutex::new(pending.webview.as_ref().unwrap().html.clone())),
+          visible: Arc::new(Mutex::new(
+            pending.webview.as_ref().unwrap().webview_attributes.visible,
+          )),
+          bounds: Arc::new(Mutex::new(
+            pending
+              .webview
+              .as_ref()
+              .unwrap()
+              .webview_attributes
+              .bounds
+              .unwrap_or_default(),
+          )),
+          on_page_load: PageLoadHandler(Arc::new(Mutex::new(
+            pending.webview.unwrap().on_page_load_handler,
+          ))),
           last_evaluated_script: Default::default(),
+          suspended: Default::default(),
         },
       },
       use_https_scheme: false,
@@ -1120,6 +1344,9 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
       dispatcher: MockWindowDispatcher {
         id,
         context: self.context.clone(),
+        title: Default::default(),
+        buttons: Arc::new(Mutex::new(pending.window_builder.buttons)),
+        content_protected: Arc::new(Mutex::new(pending.window_builder.content_protected)),
       },
       webview,
     })
@@ -1142,21 +1369,28 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
         id,
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
+        suspended: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        html: Arc::new(Mutex::new(pending.html)),
+        visible: Arc::new(Mutex::new(pending.webview_attributes.visible)),
+        bounds: Arc::new(Mutex::new(
+          pending.webview_attributes.bounds.unwrap_or_default(),
+        )),
+        on_page_load: PageLoadHandler(Arc::new(Mutex::new(pending.on_page_load_handler))),
       },
     })
   }
 
   fn primary_monitor(&self) -> Option<Monitor> {
-    unimplemented!()
+    None
   }
 
   fn monitor_from_point(&self, x: f64, y: f64) -> Option<Monitor> {
-    unimplemented!()
+    None
   }
 
   fn available_monitors(&self) -> Vec<Monitor> {
-    unimplemented!()
+    Vec::new()
   }
 
   fn set_theme(&self, theme: Option<Theme>) {
@@ -206,6 +206,15 @@ mod desktop_commands {
   );
   setter!(clear_all_browsing_data, clear_all_browsing_data);
 
+  #[command(root = "crate")]
+  pub async fn set_webview_user_agent<R: Runtime>(
+    webview: Webview<R>,
+    label: Option<String>,
+    value: String,
+  ) -> crate::Result<()> {
+    get_webview(webview, label)?.set_user_agent(&value)
+  }
+
   #[command(root = "crate")]
   pub async fn reparent<R: Runtime>(
     webview: crate::Webview<R>,
@@ -219,6 +228,32 @@ mod desktop_commands {
     Ok(())
   }
 
+  #[command(root = "crate")]
+  pub async fn emit_to_sibling<R: Runtime>(
+    webview: crate::Webview<R>,
+    label: Option<String>,
+    target: String,
+    event: crate::event::plugin::EventName,
+    payload: Option<serde_json::Value>,
+  ) -> crate::Result<()> {
+    get_webview(webview, label)?.emit_to_sibling(&target, &event, payload)
+  }
+
+  #[command(root = "crate")]
+  pub async fn internal_focus_adjacent_webview<R: Runtime>(
+    webview: crate::Webview<R>,
+    backwards: bool,
+  ) -> crate::Result<()> {
+    webview
+      .window()
+      .focus_adjacent_webview(webview.label(), backwards)
+  }
+
+  #[command(root = "crate")]
+  pub async fn internal_reveal<R: Runtime>(webview: crate::Webview<R>) -> crate::Result<()> {
+    webview.reveal(crate::webview::RevealTrigger::FirstPaint)
+  }
+
   #[cfg(any(debug_assertions, feature = "devtools"))]
   #[command(root = "crate")]
   pub async fn internal_toggle_devtools<R: Runtime>(
@@ -294,6 +329,10 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
             desktop_commands::print,
             desktop_commands::reparent,
             desktop_commands::clear_all_browsing_data,
+            desktop_commands::set_webview_user_agent,
+            desktop_commands::emit_to_sibling,
+            desktop_commands::internal_focus_adjacent_webview,
+            desktop_commands::internal_reveal,
             #[cfg(any(debug_assertions, feature = "devtools"))]
             desktop_commands::internal_toggle_devtools,
           ]);
@@ -0,0 +1,25 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+;(function () {
+  // only the top level document decides when the webview is revealed
+  if (window.top !== window) {
+    return
+  }
+
+  function reveal() {
+    // the callback of the first frame runs before it is painted, wait for the next one
+    requestAnimationFrame(function () {
+      requestAnimationFrame(function () {
+        window.__TAURI_INTERNALS__.invoke('plugin:webview|internal_reveal')
+      })
+    })
+  }
+
+  if (document.readyState === 'loading') {
+    document.addEventListener('DOMContentLoaded', reveal)
+  } else {
+    reveal()
+  }
+})()
@@ -0,0 +1,59 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+;(function () {
+  const FOCUSABLE_SELECTOR = [
+    'a[href]',
+    'area[href]',
+    'button:not([disabled])',
+    'input:not([disabled]):not([type="hidden"])',
+    'select:not([disabled])',
+    'textarea:not([disabled])',
+    'iframe',
+    '[contenteditable]:not([contenteditable="false"])',
+    '[tabindex]:not([tabindex="-1"])'
+  ].join(',')
+
+  function focusableElements() {
+    return Array.from(document.querySelectorAll(FOCUSABLE_SELECTOR)).filter(
+      (element) => element.getClientRects().length > 0
+    )
+  }
+
+  // called when the focus enters this webview from another child webview of the window
+  Object.defineProperty(window.__TAURI_INTERNALS__, 'focusEdge', {
+    value: (last) => {
+      const elements = focusableElements()
+      const element = last ? elements[elements.length - 1] : elements[0]
+      if (element) {
+        element.focus()
+      }
+    }
+  })
+
+  window.addEventListener('keydown', (event) => {
+    if (
+      event.key !== 'Tab' ||
+      event.defaultPrevented ||
+      event.ctrlKey ||
+      event.altKey ||
+      event.metaKey
+    ) {
+      return
+    }
+
+    // only leave the webview when the focus is on the last (or first, with Shift) focusable element
+    const elements = focusableElements()
+    const edge = event.shiftKey ? elements[0] : elements[elements.length - 1]
+    if (elements.length > 0 && document.activeElement !== edge) {
+      return
+    }
+
+    event.preventDefault()
+    window.__TAURI_INTERNALS__.invoke(
+      'plugin:webview|internal_focus_adjacent_webview',
+      { backwards: event.shiftKey }
+    )
+  })
+})()
@@ -0,0 +1,155 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! A tab-like layout of child webviews in a single window.
+
+use std::sync::{Arc, Mutex};
+
+use crate::{
+  runtime::dpi::{Position, Size},
+  webview::WebviewBuilder,
+  Runtime, Webview, Window,
+};
+
+struct TabsState<R: Runtime> {
+  tabs: Vec<Webview<R>>,
+  active: Option<String>,
+}
+
+/// Manages a set of child webviews sharing the same area of a window, where only the active one is visible.
+///
+/// This is the building block of browser-like apps: each tab is a regular child webview
+/// created with [`Window::add_child`], and switching tabs shows the selected webview and hides the others.
+///
+/// Cloning the tabs returns a handle to the same set of webviews.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tauri::{
+///   webview::{tabs::WebviewTabs, WebviewBuilder},
+///   LogicalPosition, LogicalSize, WebviewUrl,
+/// };
+///
+/// tauri::Builder::default()
+///   .setup(|app| {
+///     let window = tauri::window::WindowBuilder::new(app, "main").build()?;
+///     let tabs = WebviewTabs::new(
+///       &window,
+///       LogicalPosition::new(0., 0.),
+///       LogicalSize::new(800., 600.),
+///     );
+///     tabs.add(WebviewBuilder::new("home", WebviewUrl::App(Default::default())).auto_resize())?;
+///     tabs.add(
+///       WebviewBuilder::new("docs", WebviewUrl::External("https://tauri.app".parse().unwrap()))
+///         .auto_resize(),
+///     )?;
+///     tabs.select("home")?;
+///     Ok(())
+///   });
+/// ```
+#[derive(Clone)]
+pub struct WebviewTabs<R: Runtime> {
+  window: Window<R>,
+  position: Position,
+  size: Size,
+  state: Arc<Mutex<TabsState<R>>>,
+}
+
+impl<R: Runtime> WebviewTabs<R> {
+  /// Creates an empty set of tabs covering the given area of the window.
+  pub fn new<P: Into<Position>, S: Into<Size>>(window: &Window<R>, position: P, size: S) -> Self {
+    Self {
+      window: window.clone(),
+      position: position.into(),
+      size: size.into(),
+      state: Arc::new(Mutex::new(TabsState {
+        tabs: Vec::new(),
+        active: None,
+      })),
+    }
+  }
+
+  /// Creates a new tab from the given webview builder and makes it the active tab.
+  pub fn add(&self, webview_builder: WebviewBuilder<R>) -> crate::Result<Webview<R>> {
+    let webview = self
+      .window
+      .add_child(webview_builder, self.position, self.size)?;
+    self.state.lock().unwrap().tabs.push(webview.clone());
+    self.select(webview.label())?;
+    Ok(webview)
+  }
+
+  /// Shows and focuses the tab with the given webview label, hiding the other tabs.
+  ///
+  /// Returns [`crate::Error::WebviewNotFound`] if there is no tab with the given label.
+  pub fn select(&self, label: &str) -> crate::Result<()> {
+    let tabs = {
+      let mut state = self.state.lock().unwrap();
+      if !state.tabs.iter().any(|tab| tab.label() == label) {
+        return Err(crate::Error::WebviewNotFound);
+      }
+      state.active.replace(label.to_string());
+      state.tabs.clone()
+    };
+
+    for tab in tabs {
+      if tab.label() == label {
+        tab.show()?;
+        tab.set_focus()?;
+      } else {
+        tab.hide()?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Closes the tab with the given webview label.
+  ///
+  /// If it was the active tab, the tab next to it becomes active.
+  /// Returns [`crate::Error::WebviewNotFound`] if there is no tab with the given label.
+  pub fn close(&self, label: &str) -> crate::Result<()> {
+    let (webview, next) = {
+      let mut state = self.state.lock().unwrap();
+      let index = state
+        .tabs
+        .iter()
+        .position(|tab| tab.label() == label)
+        .ok_or(crate::Error::WebviewNotFound)?;
+      let webview = state.tabs.remove(index);
+
+      let next = if state.active.as_deref() == Some(label) {
+        state.active = None;
+        state
+          .tabs
+          .get(index)
+          .or_else(|| state.tabs.last())
+          .map(|tab| tab.label().to_string())
+      } else {
+        None
+      };
+      (webview, next)
+    };
+
+    webview.close()?;
+    if let Some(next) = next {
+      self.select(&next)?;
+    }
+
+    Ok(())
+  }
+
+  /// The webview of the active tab.
+  pub fn active(&self) -> Option<Webview<R>> {
+    let state = self.state.lock().unwrap();
+    let active = state.active.as_deref()?;
+    state.tabs.iter().find(|tab| tab.label() == active).cloned()
+  }
+
+  /// The webviews of all tabs, in the order they were added.
+  pub fn tabs(&self) -> Vec<Webview<R>> {
+    self.state.lock().unwrap().tabs.clone()
+  }
+}
@@ -13,7 +13,7 @@ use std::{
 use crate::{
   event::EventTarget,
   ipc::ScopeObject,
-  runtime::dpi::{PhysicalPosition, PhysicalSize},
+  runtime::dpi::{LogicalPosition, PhysicalPosition, PhysicalSize},
   window::Monitor,
   Emitter, Listener, ResourceTable, Window,
 };
@@ -35,11 +35,12 @@ use tauri_utils::{
 use url::Url;
 
 use crate::{
+  app::{UriSchemeContext, UriSchemeResponder},
   ipc::{CommandArg, CommandItem, InvokeError, OwnedInvokeResponder},
   manager::AppManager,
   sealed::{ManagerBase, RuntimeOrDispatch},
-  webview::PageLoadPayload,
   webview::WebviewBuilder,
+  webview::{FindOptions, NewWindowDecision, NewWindowRequest, PageLoadPayload},
   window::WindowBuilder,
   AppHandle, Event, EventId, Manager, Runtime, Webview, WindowEvent,
 };
@@ -351,6 +352,74 @@ tauri::Builder::default()
     self
   }
 
+  /// Defines a closure to be executed when the page requests a new window,
+  /// e.g. when a link with `target=_blank` is clicked or `window.open` is called.
+  ///
+  /// See [`WebviewBuilder::on_new_window`] for more information.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::{
+  ///   utils::config::WebviewUrl,
+  ///   webview::{NewWindowDecision, WebviewWindowBuilder},
+  /// };
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     let webview_window = WebviewWindowBuilder::new(app, "core", WebviewUrl::App("index.html".into()))
+  ///       .on_new_window(|_request| NewWindowDecision::Navigate)
+  ///       .build()?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn on_new_window<F: Fn(NewWindowRequest) -> NewWindowDecision + Send + Sync + 'static>(
+    mut self,
+    f: F,
+  ) -> Self {
+    self.webview_builder = self.webview_builder.on_new_window(f);
+    self
+  }
+
+  /// Registers a URI scheme protocol that is only available to this webview window.
+  ///
+  /// See [`WebviewBuilder::register_uri_scheme_protocol`] for more information.
+  #[must_use]
+  pub fn register_uri_scheme_protocol<
+    N: Into<String>,
+    T: Into<Cow<'static, [u8]>>,
+    H: Fn(UriSchemeContext<'_, R>, http::Request<Vec<u8>>) -> http::Response<T>
+      + Send
+      + Sync
+      + 'static,
+  >(
+    mut self,
+    uri_scheme: N,
+    protocol: H,
+  ) -> Self {
+    self.webview_builder = self
+      .webview_builder
+      .register_uri_scheme_protocol(uri_scheme, protocol);
+    self
+  }
+
+  /// Similar to [`Self::register_uri_scheme_protocol`] but with an asynchronous responder.
+  ///
+  /// See [`WebviewBuilder::register_asynchronous_uri_scheme_protocol`] for more information.
+  #[must_use]
+  pub fn register_asynchronous_uri_scheme_protocol<
+    N: Into<String>,
+    H: Fn(UriSchemeContext<'_, R>, http::Request<Vec<u8>>, UriSchemeResponder) + Send + Sync + 'static,
+  >(
+    mut self,
+    uri_scheme: N,
+    protocol: H,
+  ) -> Self {
+    self.webview_builder = self
+      .webview_builder
+      .register_asynchronous_uri_scheme_protocol(uri_scheme, protocol);
+    self
+  }
+
   /// Creates a new window.
   pub fn build(self) -> crate::Result<WebviewWindow<R>> {
     let (window, webview) = self.window_builder.with_webview(self.webview_builder)?;
@@ -438,6 +507,7 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
   ///
   /// ## Platform-specific
   ///
+  /// - **macOS:** Disables the yellow traffic light, which stays visible.
   /// - **Linux / iOS / Android:** Unsupported.
   #[must_use]
   pub fn minimizable(mut self, minimizable: bool) -> Self {
@@ -447,8 +517,13 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
 
   /// Whether the window's native close button is enabled or not.
   ///
+  /// When a child webview draws a custom titlebar, disable the native buttons and hide the native titlebar
+  /// with `decorations(false)` or `title_bar_style`; `data-tauri-drag-region` elements of the child webview
+  /// keep dragging the window since dragging does not depend on the native buttons.
+  ///
   /// ## Platform-specific
   ///
+  /// - **macOS:** Disables the red traffic light, which stays visible.
   /// - **Linux:** "GTK+ will do its best to convince the window manager not to show a close button.
   ///   Depending on the system, this function may not have any effect when called on a window that is already visible"
   /// - **iOS / Android:** Unsupported.
@@ -548,6 +623,10 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
   }
 
   /// Prevents the window contents from being captured by other apps.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / iOS / Android:** Unsupported, the window can be captured.
   #[must_use]
   pub fn content_protected(mut self, protected: bool) -> Self {
     self.window_builder = self.window_builder.content_protected(protected);
@@ -578,6 +657,21 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
     self
   }
 
+  /// Sets the identifier the taskbar and the window manager use to group the window with other windows,
+  /// for instance to group the windows of a multiwebview app that do not share the app identifier.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** Sets the [AppUserModelID](https://learn.microsoft.com/en-us/windows/win32/shell/appids) of the window.
+  /// - **Linux:** Sets the `WM_CLASS` of the window, which is only used on X11.
+  ///   On Wayland all windows share the application ID, see the `app > enableGTKAppId` configuration.
+  /// - **macOS / Android / iOS:** Unsupported.
+  #[must_use]
+  pub fn app_id<S: Into<String>>(mut self, app_id: S) -> Self {
+    self.window_builder = self.window_builder.app_id(app_id);
+    self
+  }
+
   /// Sets whether or not the window has shadow.
   ///
   /// ## Platform-specific
@@ -700,13 +794,28 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
   }
 
   /// Sets the [`crate::TitleBarStyle`].
-  #[cfg(target_os = "macos")]
+  ///
+  /// Use [`crate::TitleBarStyle::Hidden`] to let a child webview at `(0, 0)` render over the title bar area.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / Linux:** Only [`crate::TitleBarStyle::Hidden`] is supported, which removes the window decorations.
   #[must_use]
   pub fn title_bar_style(mut self, style: crate::TitleBarStyle) -> Self {
     self.window_builder = self.window_builder.title_bar_style(style);
     self
   }
 
+  /// Sets the position of the traffic light buttons, relative to the top left corner of the window.
+  ///
+  /// Useful to align them with a custom title bar drawn with [`crate::TitleBarStyle::Hidden`] or [`crate::TitleBarStyle::Overlay`].
+  #[cfg(target_os = "macos")]
+  #[must_use]
+  pub fn traffic_light_position(mut self, x: f64, y: f64) -> Self {
+    self.window_builder = self.window_builder.traffic_light_position(x, y);
+    self
+  }
+
   /// Hide the window title.
   #[cfg(target_os = "macos")]
   #[must_use]
@@ -793,6 +902,71 @@ impl<R: Runtime, M: Manager<R>> WebviewWindowBuilder<'_, R, M> {
     self
   }
 
+  /// Sets the language requested by the webview, e.g. `fr` or `pt-BR`,
+  /// with the `Accept-Language` header and `navigator.language`.
+  ///
+  /// The header is sent when loading the initial URL and on [`WebviewWindow::navigate`] calls,
+  /// while `navigator.language` is set on every page.
+  /// An invalid BCP 47 language tag makes the webview creation fail.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Android**: Unsupported, the language follows the app locale. The webview creation fails with [`tauri_runtime::Error::Unsupported`].
+  #[must_use]
+  pub fn accept_language(mut self, language: &str) -> Self {
+    self.webview_builder = self.webview_builder.accept_language(language);
+    self
+  }
+
+  /// Whether the spell checker checks the editable elements of the pages, such as text inputs.
+  ///
+  /// Elements that set their own `spellcheck` attribute are not affected. Uses the platform default if not set.
+  #[must_use]
+  pub fn spellcheck(mut self, enabled: bool) -> Self {
+    self.webview_builder = self.webview_builder.spellcheck(enabled);
+    self
+  }
+
+  /// Whether the webview suggests and saves form entries and passwords.
+  ///
+  /// Uses the platform default if not set.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Linux / macOS / iOS / Android**: Unsupported. The webview creation fails with [`tauri_runtime::Error::Unsupported`].
+  #[must_use]
+  pub fn autofill(mut self, enabled: bool) -> Self {
+    self.webview_builder = self.webview_builder.autofill(enabled);
+    self
+  }
+
+  /// Serves the Chrome DevTools Protocol of the webview on the given local port,
+  /// so tools like Playwright can attach to its content, see [`WebviewWindow::remote_debugging_url`].
+  ///
+  /// **This must not be enabled in release builds**: any local process can then control the webview.
+  /// Building the window fails with [`crate::Error::RemoteDebuggingNotAllowed`] in release builds
+  /// unless the `devtools` feature flag is enabled.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows**: Webviews sharing a data directory share the browser process, which only serves one port
+  ///   and fails to create webviews with different browser arguments,
+  ///   so use a dedicated [`Self::data_directory`] for the debugged webview.
+  /// - **macOS / Linux / iOS / Android**: Unsupported, ignored. Use the devtools of the platform instead.
+  #[must_use]
+  pub fn remote_debugging(mut self, port: u16) -> Self {
+    self.webview_builder = self.webview_builder.remote_debugging(port);
+    self
+  }
+
+  /// The HTML content shown by a webview created with [`WebviewUrl::Lazy`] until it is navigated,
+  /// see [`WebviewUrl::Html`]. Ignored for the other URLs.
+  #[must_use]
+  pub fn placeholder<H: Into<String>>(mut self, html: H) -> Self {
+    self.webview_builder = self.webview_builder.placeholder(html);
+    self
+  }
+
   /// Set additional arguments for the webview.
   ///
   /// ## Platform-specific
@@ -862,6 +1036,15 @@ impl<R: Runtime, M: Manager<R>> WebviewWindowBuilder<'_, R, M> {
     self
   }
 
+  /// Routes all network requests of the webview through the given proxy.
+  ///
+  /// See [`WebviewBuilder::proxy`](crate::webview::WebviewBuilder::proxy) for the platform support.
+  #[must_use]
+  pub fn proxy(mut self, proxy: crate::webview::ProxyConfig) -> Self {
+    self.webview_builder = self.webview_builder.proxy(proxy);
+    self
+  }
+
   /// Whether the window should be transparent. If this is true, writing colors
   /// with alpha values different than `1.0` will produce a transparent window.
   #[cfg(any(not(target_os = "macos"), feature = "macos-private-api"))]
@@ -1072,6 +1255,13 @@ impl<R: Runtime> WebviewWindow<R> {
     self.window.on_window_event(f);
   }
 
+  /// Registers a listener for the system theme changes of this window.
+  ///
+  /// Platforms that report no theme preference are mapped to [`crate::Theme::Light`].
+  pub fn on_theme_changed<F: Fn(crate::Theme) + Send + 'static>(&self, f: F) {
+    self.window.on_theme_changed(f);
+  }
+
   /// Resolves the given command scope for this webview on the currently loaded URL.
   ///
   /// If the command is not allowed, returns None.
@@ -1241,6 +1431,11 @@ impl<R: Runtime> WebviewWindow<R> {
     self.window.outer_position()
   }
 
+  /// Returns the position of the top-left hand corner of the window relative to the top-left hand corner of the desktop, in logical pixels.
+  pub fn logical_outer_position(&self) -> crate::Result<LogicalPosition<f64>> {
+    self.window.logical_outer_position()
+  }
+
   /// Returns the physical size of the window's client area.
   ///
   /// The client area is the content of the window, excluding the title bar and borders.
@@ -1363,6 +1558,20 @@ impl<R: Runtime> WebviewWindow<R> {
     self.window.ns_view()
   }
 
+  /// Returns the raw platform handle of this window, see [`Window::raw_window_handle`].
+  #[cfg(feature = "unstable")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+  pub fn raw_window_handle(&self) -> crate::Result<raw_window_handle::RawWindowHandle> {
+    self.window.raw_window_handle()
+  }
+
+  /// Returns the raw handle of the display this window is on, see [`Window::raw_display_handle`].
+  #[cfg(feature = "unstable")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+  pub fn raw_display_handle(&self) -> crate::Result<raw_window_handle::RawDisplayHandle> {
+    self.window.raw_display_handle()
+  }
+
   /// Returns the native handle that is used by this window.
   #[cfg(windows)]
   pub fn hwnd(&self) -> crate::Result<HWND> {
@@ -1433,15 +1642,19 @@ impl<R: Runtime> WebviewWindow<R> {
 
   /// Requests user attention to the window, this has no effect if the application
   /// is already focused. How requesting for user attention manifests is platform dependent,
-  /// see `UserAttentionType` for details.
+  /// see [`UserAttentionType`] for details.
   ///
-  /// Providing `None` will unset the request for user attention. Unsetting the request for
-  /// user attention might not be done automatically by the WM when the window receives input.
+  /// This is useful to signal activity in a background webview of the window, such as a finished download.
+  ///
+  /// Providing `None` cancels a pending request for user attention.
+  /// A pending request is also cancelled when the window gets focused.
   ///
   /// ## Platform-specific
   ///
-  /// - **macOS:** `None` has no effect.
-  /// - **Linux:** Urgency levels have the same effect.
+  /// - **macOS:** Bounces the dock icon, `None` has no effect since the bouncing stops when the application is focused.
+  /// - **Windows:** Flashes the taskbar button, and the window itself for [`UserAttentionType::Critical`].
+  /// - **Linux:** Sets the urgency hint of the window, both urgency levels have the same effect.
+  /// - **iOS / Android:** Unsupported.
   pub fn request_user_attention(
     &self,
     request_type: Option<UserAttentionType>,
@@ -1611,7 +1824,9 @@ impl<R: Runtime> WebviewWindow<R> {
       .set_visible_on_all_workspaces(visible_on_all_workspaces)
   }
 
-  /// Prevents the window contents from being captured by other apps.
+  /// Prevents the window contents from being captured by screenshots and screen recordings.
+  ///
+  /// See [`crate::Window::set_content_protected`] for the platform support.
   pub fn set_content_protected(&self, protected: bool) -> crate::Result<()> {
     self.window.set_content_protected(protected)
   }
@@ -1644,6 +1859,11 @@ impl<R: Runtime> WebviewWindow<R> {
     self.window.set_position(position)
   }
 
+  /// Sets the position of the top-left hand corner of the window relative to the top-left hand corner of the desktop, in logical pixels.
+  pub fn set_outer_position(&self, position: LogicalPosition<f64>) -> crate::Result<()> {
+    self.window.set_outer_position(position)
+  }
+
   /// Determines if this window should be fullscreen.
   pub fn set_fullscreen(&self, fullscreen: bool) -> crate::Result<()> {
     self.window.set_fullscreen(fullscreen)
@@ -1769,7 +1989,13 @@ impl<R: Runtime> WebviewWindow<R> {
     self.window.set_progress_bar(progress_state)
   }
 
-  /// Sets the title bar style. **macOS only**.
+  /// Sets the title bar style.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS:** The window title is not hidden when switching to [`crate::TitleBarStyle::Hidden`].
+  /// - **Windows / Linux:** [`crate::TitleBarStyle::Hidden`] removes the window decorations, and switching from it to another style restores them. The other styles have no effect otherwise.
+  /// - **iOS / Android:** Unsupported.
   pub fn set_title_bar_style(&self, style: tauri_utils::TitleBarStyle) -> crate::Result<()> {
     self.window.set_title_bar_style(style)
   }
@@ -1860,11 +2086,95 @@ impl<R: Runtime> WebviewWindow<R> {
     self.webview.url()
   }
 
+  /// Returns the title of the document loaded in the webview, as last reported by the page.
+  ///
+  /// See [`Webview::document_title`] for more information.
+  pub fn document_title(&self) -> Option<String> {
+    self.webview.document_title()
+  }
+
+  /// Registers a listener for the estimated load progress of the main frame navigation.
+  ///
+  /// The handler receives values from `0.0` to `1.0`. The progress is an estimate and might not increase linearly.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Windows / macOS / iOS / Android**: The webview does not report intermediate progress,
+  ///   so the handler is only called with `0.0` when the navigation starts and `1.0` when it finishes.
+  pub fn on_load_progress<F: Fn(f64) + Send + 'static>(&self, f: F) {
+    self.webview.on_load_progress(f)
+  }
+
+  /// Registers a listener for the HTTP response of the main frame navigations,
+  /// see [`Webview::on_main_frame_response`].
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **macOS / iOS / Android**: Unsupported, the handler is never called.
+  pub fn on_main_frame_response<F: Fn(&crate::MainFrameResponse) + Send + 'static>(&self, f: F) {
+    self.webview.on_main_frame_response(f)
+  }
+
+  /// Whether the webview is currently loading a page.
+  ///
+  /// The webview starts loading when a navigation starts, and stops when the navigation finishes,
+  /// either successfully or with an error.
+  pub fn is_loading(&self) -> crate::Result<bool> {
+    self.webview.is_loading()
+  }
+
+  /// Registers a listener that is called whenever the webview starts or stops loading a page,
+  /// with the new value of [`Self::is_loading`].
+  pub fn on_loading_state_changed<F: Fn(bool) + Send + Sync + 'static>(&self, f: F) {
+    self.webview.on_loading_state_changed(f)
+  }
+
+  /// The local port serving the remote debugging protocol of this webview,
+  /// see [`WebviewWindowBuilder::remote_debugging`].
+  pub fn remote_debugging_port(&self) -> Option<u16> {
+    self.webview.remote_debugging_port()
+  }
+
+  /// The URL that debugging tools such as Playwright's `connectOverCDP` use to attach to this webview,
+  /// see [`WebviewWindowBuilder::remote_debugging`].
+  pub fn remote_debugging_url(&self) -> Option<Url> {
+    self.webview.remote_debugging_url()
+  }
+
+  /// Returns the URL of the current page favicon, or `None` if the page does not declare one.
+  ///
+  /// The URL might be a `data:` URI if the page embeds its favicon.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Linux / macOS / iOS / Android**: Unsupported. Returns [`tauri_runtime::Error::Unsupported`].
+  pub fn favicon(&self) -> crate::Result<Option<Url>> {
+    self.webview.favicon()
+  }
+
+  /// Registers a listener that is called whenever the favicon of the current page changes.
+  ///
+  /// The handler receives `None` when the new page has no favicon.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Linux / macOS / iOS / Android**: Unsupported. The handler is never called.
+  pub fn on_favicon_changed<F: Fn(Option<Url>) + Send + 'static>(&self, f: F) {
+    self.webview.on_favicon_changed(f)
+  }
+
   /// Navigates the webview to the defined url.
   pub fn navigate(&mut self, url: Url) -> crate::Result<()> {
     self.webview.navigate(url)
   }
 
+  /// Replaces the current page with the given HTML content.
+  ///
+  /// See [`Webview::load_html`] for the origin and security semantics of the loaded content.
+  pub fn load_html(&self, html: &str, base_url: Option<Url>) -> crate::Result<()> {
+    self.webview.load_html(html, base_url)
+  }
+
   /// Handles this window receiving an [`crate::webview::InvokeRequest`].
   pub fn on_message(
     self,
@@ -1879,6 +2189,20 @@ impl<R: Runtime> WebviewWindow<R> {
     self.webview.eval(js)
   }
 
+  /// Registers a script that is evaluated every time this webview starts loading a page.
+  ///
+  /// See [`Webview::add_document_start_script`] for more information.
+  pub fn add_document_start_script(&self, name: impl Into<String>, script: impl Into<String>) {
+    self.webview.add_document_start_script(name, script)
+  }
+
+  /// Removes a script registered with [`Self::add_document_start_script`].
+  ///
+  /// Returns `false` if there is no script with the given name.
+  pub fn remove_document_start_script(&self, name: &str) -> bool {
+    self.webview.remove_document_start_script(name)
+  }
+
   /// Opens the developer tools window (Web Inspector).
   /// The devtools is only enabled on debug builds or with the `devtools` feature flag.
   ///
@@ -1983,6 +2307,73 @@ impl<R: Runtime> WebviewWindow<R> {
   pub fn clear_all_browsing_data(&self) -> crate::Result<()> {
     self.webview.clear_all_browsing_data()
   }
+
+  /// Sets the user agent of the webview window.
+  ///
+  /// The currently loaded page is not reloaded, so the new user agent only applies starting with the next navigation.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Android**: Unsupported, returns [`tauri_runtime::Error::Unsupported`].
+  pub fn set_user_agent(&self, user_agent: &str) -> crate::Result<()> {
+    self.webview.set_user_agent(user_agent)
+  }
+
+  /// Sets the language requested by the webview, e.g. `fr` or `pt-BR`,
+  /// with the `Accept-Language` header and `navigator.language`.
+  ///
+  /// See [`Webview::set_accept_language`] for details.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Android**: Unsupported, returns [`tauri_runtime::Error::Unsupported`].
+  pub fn set_accept_language(&self, language: &str) -> crate::Result<()> {
+    self.webview.set_accept_language(language)
+  }
+
+  /// Sets whether the spell checker checks the editable elements of the pages.
+  ///
+  /// See [`Webview::set_spellcheck`] for details.
+  pub fn set_spellcheck(&self, enabled: bool) -> crate::Result<()> {
+    self.webview.set_spellcheck(enabled)
+  }
+
+  /// Sets whether the webview suggests and saves form entries and passwords.
+  ///
+  /// See [`Webview::set_autofill`] for details.
+  pub fn set_autofill(&self, enabled: bool) -> crate::Result<()> {
+    self.webview.set_autofill(enabled)
+  }
+
+  /// Searches the page for the given query, selecting the first match.
+  ///
+  /// The number of matches is reported through [`crate::WebviewEvent::FindMatchCount`].
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Linux**: All matches are always highlighted, regardless of [`FindOptions::highlight_all`].
+  /// - **Windows / macOS / iOS / Android**: Unsupported, returns [`tauri_runtime::Error::Unsupported`].
+  pub fn find(&self, query: &str, options: FindOptions) -> crate::Result<()> {
+    self.webview.find(query, options)
+  }
+
+  /// Selects the next match of the current search started with [`Self::find`].
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Windows / macOS / iOS / Android**: Unsupported, returns [`tauri_runtime::Error::Unsupported`].
+  pub fn find_next(&self) -> crate::Result<()> {
+    self.webview.find_next()
+  }
+
+  /// Finishes the current search, clearing its highlights.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Windows / macOS / iOS / Android**: Unsupported, returns [`tauri_runtime::Error::Unsupported`].
+  pub fn stop_find(&self) -> crate::Result<()> {
+    self.webview.stop_find()
+  }
 }
 
 impl<R: Runtime> Listener<R> for WebviewWindow<R> {
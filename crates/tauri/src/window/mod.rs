@@ -5,15 +5,20 @@
 //! The Tauri window types and functions.
 
 pub(crate) mod plugin;
+#[cfg(any(test, all(desktop, feature = "unstable")))]
+mod splitter;
 
 use tauri_runtime::{
-  dpi::{PhysicalPosition, PhysicalSize},
+  dpi::{LogicalPosition, PhysicalPosition, PhysicalSize},
   webview::PendingWebview,
 };
 pub use tauri_utils::{config::Color, WindowEffect as Effect, WindowEffectState as EffectState};
 
 #[cfg(desktop)]
 pub use crate::runtime::ProgressBarStatus;
+#[cfg(any(test, all(desktop, feature = "unstable")))]
+#[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "unstable"))))]
+pub use splitter::{Splitter, SplitterMovedPayload, SplitterOrientation, SPLITTER_MOVED_EVENT};
 
 use crate::{
   app::AppHandle,
@@ -324,9 +329,14 @@ tauri::Builder::default()
     webview: WebviewBuilder<R>,
   ) -> crate::Result<(Window<R>, Webview<R>)> {
     let pending_webview = webview.into_pending_webview(self.manager, &self.label)?;
+    let remote_debugging_port = pending_webview.webview_attributes.remote_debugging_port;
     let window = self.build_internal(Some(pending_webview))?;
 
     let webview = window.webviews().first().unwrap().clone();
+    window
+      .manager()
+      .webview
+      .set_remote_debugging_port(webview.label(), remote_debugging_port);
 
     Ok((window, webview))
   }
@@ -497,6 +507,7 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
   ///
   /// ## Platform-specific
   ///
+  /// - **macOS:** Disables the yellow traffic light, which stays visible.
   /// - **Linux / iOS / Android:** Unsupported.
   #[must_use]
   pub fn minimizable(mut self, minimizable: bool) -> Self {
@@ -506,8 +517,13 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
 
   /// Whether the window's native close button is enabled or not.
   ///
+  /// When a child webview draws a custom titlebar, disable the native buttons and hide the native titlebar
+  /// with `decorations(false)` or `title_bar_style`; `data-tauri-drag-region` elements of the child webview
+  /// keep dragging the window since dragging does not depend on the native buttons.
+  ///
   /// ## Platform-specific
   ///
+  /// - **macOS:** Disables the red traffic light, which stays visible.
   /// - **Linux:** "GTK+ will do its best to convince the window manager not to show a close button.
   ///   Depending on the system, this function may not have any effect when called on a window that is already visible"
   /// - **iOS / Android:** Unsupported.
@@ -622,6 +638,10 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
   }
 
   /// Prevents the window contents from being captured by other apps.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / iOS / Android:** Unsupported, the window can be captured.
   #[must_use]
   pub fn content_protected(mut self, protected: bool) -> Self {
     self.window_builder = self.window_builder.content_protected(protected);
@@ -652,6 +672,21 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
     self
   }
 
+  /// Sets the identifier the taskbar and the window manager use to group the window with other windows,
+  /// for instance to group the windows of a multiwebview app that do not share the app identifier.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** Sets the [AppUserModelID](https://learn.microsoft.com/en-us/windows/win32/shell/appids) of the window.
+  /// - **Linux:** Sets the `WM_CLASS` of the window, which is only used on X11.
+  ///   On Wayland all windows share the application ID, see the `app > enableGTKAppId` configuration.
+  /// - **macOS / Android / iOS:** Unsupported.
+  #[must_use]
+  pub fn app_id<S: Into<String>>(mut self, app_id: S) -> Self {
+    self.window_builder = self.window_builder.app_id(app_id);
+    self
+  }
+
   /// Sets whether or not the window has shadow.
   ///
   /// ## Platform-specific
@@ -804,13 +839,28 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
   }
 
   /// Sets the [`crate::TitleBarStyle`].
-  #[cfg(target_os = "macos")]
+  ///
+  /// Use [`crate::TitleBarStyle::Hidden`] to let a child webview at `(0, 0)` render over the title bar area.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / Linux:** Only [`crate::TitleBarStyle::Hidden`] is supported, which removes the window decorations.
   #[must_use]
   pub fn title_bar_style(mut self, style: crate::TitleBarStyle) -> Self {
     self.window_builder = self.window_builder.title_bar_style(style);
     self
   }
 
+  /// Sets the position of the traffic light buttons, relative to the top left corner of the window.
+  ///
+  /// Useful to align them with a custom title bar drawn with [`crate::TitleBarStyle::Hidden`] or [`crate::TitleBarStyle::Overlay`].
+  #[cfg(target_os = "macos")]
+  #[must_use]
+  pub fn traffic_light_position(mut self, x: f64, y: f64) -> Self {
+    self.window_builder = self.window_builder.traffic_light_position(x, y);
+    self
+  }
+
   /// Hide the window title.
   #[cfg(target_os = "macos")]
   #[must_use]
@@ -1022,6 +1072,73 @@ impl<R: Runtime> Window<R> {
     rx.recv().unwrap()
   }
 
+  /// Moves a child webview of another window into this window, at the given position and size.
+  ///
+  /// The native webview is moved rather than recreated, so its page is not reloaded
+  /// and it keeps its session state and data directory, even if they differ from the ones
+  /// used by the other webviews of this window. The adopted webview is focused once it has been moved.
+  ///
+  /// Returns [`crate::Error::WebviewLabelAlreadyExists`] if the webview is already a child of this window.
+  #[cfg(any(test, all(desktop, feature = "unstable")))]
+  #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "unstable"))))]
+  pub fn adopt_child<P: Into<Position>, S: Into<Size>>(
+    &self,
+    webview: Webview<R>,
+    position: P,
+    size: S,
+  ) -> crate::Result<Webview<R>> {
+    if webview.window_label() == self.label() {
+      return Err(crate::Error::WebviewLabelAlreadyExists(
+        webview.label().to_string(),
+      ));
+    }
+
+    webview.reparent(self)?;
+    webview.set_position(position)?;
+    webview.set_size(size)?;
+    webview.set_focus()?;
+
+    Ok(webview)
+  }
+
+  /// Executes a closure on the platform webview handle of the child webview with the given label.
+  ///
+  /// See [`Webview::with_webview`] for details. The closure runs on the main (UI) thread, and
+  /// the same rules apply to the handles it receives.
+  ///
+  /// Returns [`crate::Error::WebviewNotFound`] if this window has no webview with the given label.
+  #[cfg(all(feature = "wry", feature = "unstable"))]
+  #[cfg_attr(docsrs, doc(cfg(all(feature = "wry", feature = "unstable"))))]
+  pub fn with_webview<F: FnOnce(crate::webview::PlatformWebview) + Send + 'static>(
+    &self,
+    label: &str,
+    f: F,
+  ) -> crate::Result<()> {
+    self
+      .webviews()
+      .into_iter()
+      .find(|w| w.label() == label)
+      .ok_or(crate::Error::WebviewNotFound)?
+      .with_webview(f)
+  }
+
+  /// Adds a [`Splitter`] between two adjacent child webviews of this window,
+  /// creating the child webview of its draggable divider, see [`Splitter::divider`].
+  ///
+  /// Moving the splitter resizes both webviews within their size constraints
+  /// and emits the [`SPLITTER_MOVED_EVENT`] to this window with the new split ratio.
+  ///
+  /// Returns [`crate::Error::WebviewNotFound`] if one of the webviews is not a child of this window.
+  #[cfg(any(test, all(desktop, feature = "unstable")))]
+  #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "unstable"))))]
+  pub fn add_splitter<A: Into<String>, B: Into<String>>(
+    &self,
+    (first, second): (A, B),
+    orientation: SplitterOrientation,
+  ) -> crate::Result<Splitter<R>> {
+    Splitter::new(self.clone(), first.into(), second.into(), orientation)
+  }
+
   /// List of webviews associated with this window.
   pub fn webviews(&self) -> Vec<Webview<R>> {
     self
@@ -1034,6 +1151,42 @@ impl<R: Runtime> Window<R> {
       .collect()
   }
 
+  /// Moves the keyboard focus from the given child webview to the next one in tab order,
+  /// or the previous one if `backwards` is set, see [`crate::webview::WebviewBuilder::tab_index`].
+  #[cfg(desktop)]
+  pub(crate) fn focus_adjacent_webview(&self, from: &str, backwards: bool) -> crate::Result<()> {
+    let tab_indices = self.manager.webview.tab_indices.lock().unwrap().clone();
+    let mut webviews: Vec<_> = self
+      .webviews()
+      .into_iter()
+      .filter_map(|webview| {
+        tab_indices
+          .get(webview.label())
+          .map(|tab_index| (*tab_index, webview))
+      })
+      .collect();
+    webviews.sort_by(|(a, a_webview), (b, b_webview)| {
+      a.cmp(b)
+        .then_with(|| a_webview.label().cmp(b_webview.label()))
+    });
+    let webviews: Vec<_> = webviews.into_iter().map(|(_, webview)| webview).collect();
+
+    let Some(index) = webviews.iter().position(|webview| webview.label() == from) else {
+      return Ok(());
+    };
+    let next = if backwards {
+      (index + webviews.len() - 1) % webviews.len()
+    } else {
+      (index + 1) % webviews.len()
+    };
+
+    let webview = &webviews[next];
+    webview.set_focus()?;
+    webview.eval(&format!(
+      "window.__TAURI_INTERNALS__.focusEdge?.({backwards})"
+    ))
+  }
+
   pub(crate) fn is_webview_window(&self) -> bool {
     self.webviews().iter().all(|w| w.label() == self.label())
   }
@@ -1059,6 +1212,18 @@ impl<R: Runtime> Window<R> {
       .dispatcher
       .on_window_event(move |event| f(&event.clone().into()));
   }
+
+  /// Registers a listener for the system theme changes of this window.
+  ///
+  /// Use [`Self::theme`] to read the current value.
+  /// Platforms that report no theme preference are mapped to [`Theme::Light`].
+  pub fn on_theme_changed<F: Fn(Theme) + Send + 'static>(&self, f: F) {
+    self.on_window_event(move |event| {
+      if let WindowEvent::ThemeChanged(theme) = event {
+        f(*theme);
+      }
+    });
+  }
 }
 
 /// Menu APIs
@@ -1345,10 +1510,22 @@ impl<R: Runtime> Window<R> {
   }
 
   /// Returns the position of the top-left hand corner of the window relative to the top-left hand corner of the desktop.
+  ///
+  /// On multi-monitor setups the desktop spans all displays,
+  /// so a window on a display placed to the left of or above the primary display has negative coordinates.
   pub fn outer_position(&self) -> crate::Result<PhysicalPosition<i32>> {
     self.window.dispatcher.outer_position().map_err(Into::into)
   }
 
+  /// Returns the [`Self::outer_position`] converted to logical pixels using the window's scale factor.
+  ///
+  /// This is the same coordinate space used by [`Self::set_outer_position`],
+  /// which makes it suitable to arrange multiple windows precisely.
+  pub fn logical_outer_position(&self) -> crate::Result<LogicalPosition<f64>> {
+    let scale_factor = self.scale_factor()?;
+    Ok(self.outer_position()?.to_logical(scale_factor))
+  }
+
   /// Returns the physical size of the window's client area.
   ///
   /// The client area is the content of the window, excluding the title bar and borders.
@@ -1515,6 +1692,38 @@ impl<R: Runtime> Window<R> {
       })
   }
 
+  /// Returns the raw platform handle of this window,
+  /// e.g. to render a native overlay or an OpenGL surface next to its webviews.
+  ///
+  /// The handle is a plain copy of the native pointers, it does not keep the window alive:
+  /// it becomes invalid once the window is closed, so stop using it on [`WindowEvent::Destroyed`].
+  /// The handle is not [`Send`], and most platforms only allow native window calls on the main thread,
+  /// see [`crate::AppHandle::run_on_main_thread`].
+  ///
+  /// Prefer the [`raw_window_handle::HasWindowHandle`] implementation when the handle is only needed
+  /// while borrowing the window.
+  #[cfg(feature = "unstable")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+  pub fn raw_window_handle(&self) -> crate::Result<raw_window_handle::RawWindowHandle> {
+    self
+      .window
+      .dispatcher
+      .window_handle()
+      .map(|handle| handle.as_raw())
+      .map_err(Into::into)
+  }
+
+  /// Returns the raw handle of the display this window is on, to be used with [`Self::raw_window_handle`].
+  ///
+  /// The same validity and thread-safety rules as [`Self::raw_window_handle`] apply.
+  #[cfg(feature = "unstable")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+  pub fn raw_display_handle(&self) -> crate::Result<raw_window_handle::RawDisplayHandle> {
+    raw_window_handle::HasDisplayHandle::display_handle(&self.app_handle)
+      .map(|handle| handle.as_raw())
+      .map_err(Into::into)
+  }
+
   /// Returns the native handle that is used by this window.
   #[cfg(windows)]
   pub fn hwnd(&self) -> crate::Result<HWND> {
@@ -1562,6 +1771,8 @@ impl<R: Runtime> Window<R> {
 
   /// Returns the current window theme.
   ///
+  /// Platforms that report no theme preference are mapped to [`Theme::Light`].
+  ///
   /// ## Platform-specific
   ///
   /// - **macOS**: Only supported on macOS 10.14+.
@@ -1596,15 +1807,19 @@ impl<R: Runtime> Window<R> {
 
   /// Requests user attention to the window, this has no effect if the application
   /// is already focused. How requesting for user attention manifests is platform dependent,
-  /// see `UserAttentionType` for details.
+  /// see [`UserAttentionType`] for details.
+  ///
+  /// This is useful to signal activity in a background webview of the window, such as a finished download.
   ///
-  /// Providing `None` will unset the request for user attention. Unsetting the request for
-  /// user attention might not be done automatically by the WM when the window receives input.
+  /// Providing `None` cancels a pending request for user attention.
+  /// A pending request is also cancelled when the window gets focused.
   ///
   /// ## Platform-specific
   ///
-  /// - **macOS:** `None` has no effect.
-  /// - **Linux:** Urgency levels have the same effect.
+  /// - **macOS:** Bounces the dock icon, `None` has no effect since the bouncing stops when the application is focused.
+  /// - **Windows:** Flashes the taskbar button, and the window itself for [`UserAttentionType::Critical`].
+  /// - **Linux:** Sets the urgency hint of the window, both urgency levels have the same effect.
+  /// - **iOS / Android:** Unsupported.
   pub fn request_user_attention(
     &self,
     request_type: Option<UserAttentionType>,
@@ -1678,6 +1893,25 @@ impl<R: Runtime> Window<R> {
       .map_err(Into::into)
   }
 
+  /// Sets this window's title to the document title of the given child webview,
+  /// for instance to reflect the active tab of a multiwebview window.
+  ///
+  /// The URL of the webview is used if its page has not reported a title, see [`Webview::document_title`].
+  /// Returns [`crate::Error::WebviewNotFound`] if the webview is not a child of this window.
+  #[cfg(desktop)]
+  pub fn set_title_from_child(&self, label: &str) -> crate::Result<()> {
+    let webview = self
+      .webviews()
+      .into_iter()
+      .find(|webview| webview.label() == label)
+      .ok_or(crate::Error::WebviewNotFound)?;
+    let title = match webview.document_title() {
+      Some(title) if !title.is_empty() => title,
+      _ => webview.url()?.to_string(),
+    };
+    self.set_title(&title)
+  }
+
   /// Enable or disable the window.
   pub fn set_enabled(&self, enabled: bool) -> crate::Result<()> {
     self
@@ -1805,6 +2039,8 @@ tauri::Builder::default()
   }
 
   /// Determines if this window should always be on top of other windows.
+  ///
+  /// The child webview that has the focus keeps it after the change.
   pub fn set_always_on_top(&self, always_on_top: bool) -> crate::Result<()> {
     self
       .window
@@ -1843,7 +2079,14 @@ tauri::Builder::default()
       .map_err(Into::into)
   }
 
-  /// Prevents the window contents from being captured by other apps.
+  /// Prevents the window contents, including its webviews, from being captured by screenshots and screen recordings.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** Excludes the window from capture with `WDA_EXCLUDEFROMCAPTURE` on Windows 10 2004 and above,
+  ///   older versions show a black window instead.
+  /// - **macOS:** Sets the window sharing type to `NSWindowSharingNone`.
+  /// - **Linux / iOS / Android:** Unsupported, returns [`tauri_runtime::Error::Unsupported`].
   pub fn set_content_protected(&self, protected: bool) -> crate::Result<()> {
     self
       .window
@@ -1852,6 +2095,20 @@ tauri::Builder::default()
       .map_err(Into::into)
   }
 
+  /// Renders the whole window, including its decorations and every child webview, into a PDF document.
+  ///
+  /// Child webviews that are still loading are waited for up to [`PrintToPdfOptions::timeout`].
+  /// Use [`Webview::print`](crate::webview::Webview::print) to print the contents of a single webview.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / macOS / Linux / iOS / Android:** Unsupported, returns [`tauri_runtime::Error::Unsupported`].
+  ///   The runtime cannot capture the window decorations nor composite its webviews yet.
+  pub fn print_to_pdf(&self, options: PrintToPdfOptions) -> crate::Result<Vec<u8>> {
+    let _ = options;
+    Err(tauri_runtime::Error::Unsupported.into())
+  }
+
   /// Resizes this window.
   pub fn set_size<S: Into<Size>>(&self, size: S) -> crate::Result<()> {
     self
@@ -1900,6 +2157,14 @@ tauri::Builder::default()
       .map_err(Into::into)
   }
 
+  /// Sets the position of the top-left hand corner of the window relative to the top-left hand corner of the desktop, in logical pixels.
+  ///
+  /// Negative coordinates are valid and place the window on a display located to the left of or above the primary display.
+  /// See [`Self::logical_outer_position`] to read the current value.
+  pub fn set_outer_position(&self, position: LogicalPosition<f64>) -> crate::Result<()> {
+    self.set_position(position)
+  }
+
   /// Determines if this window should be fullscreen.
   pub fn set_fullscreen(&self, fullscreen: bool) -> crate::Result<()> {
     self
@@ -2071,7 +2336,13 @@ tauri::Builder::default()
       .map_err(Into::into)
   }
 
-  /// Sets the title bar style. **macOS only**.
+  /// Sets the title bar style.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS:** The window title is not hidden when switching to [`crate::TitleBarStyle::Hidden`].
+  /// - **Windows / Linux:** [`crate::TitleBarStyle::Hidden`] removes the window decorations, and switching from it to another style restores them. The other styles have no effect otherwise.
+  /// - **iOS / Android:** Unsupported.
   pub fn set_title_bar_style(&self, style: tauri_utils::TitleBarStyle) -> crate::Result<()> {
     self
       .window
@@ -2124,6 +2395,17 @@ pub struct ProgressBarState {
   pub progress: Option<u64>,
 }
 
+/// Options for [`Window::print_to_pdf`].
+#[cfg(desktop)]
+#[derive(Debug, Clone, Default)]
+pub struct PrintToPdfOptions {
+  /// How long to wait for child webviews that are still loading, e.g. on an external URL.
+  ///
+  /// The export fails when a webview is still loading after the timeout.
+  /// When `None`, it fails right away if any webview is still loading.
+  pub timeout: Option<std::time::Duration>,
+}
+
 impl<R: Runtime> Listener<R> for Window<R> {
   /// Listen to an event on this window.
   ///
@@ -2360,4 +2642,205 @@ mod tests {
     crate::test_utils::assert_send::<super::Window>();
     crate::test_utils::assert_sync::<super::Window>();
   }
+
+  #[test]
+  fn monitor_from_runtime() {
+    use crate::{PhysicalPosition, PhysicalSize};
+
+    let monitor = super::Monitor::from(crate::runtime::monitor::Monitor {
+      name: Some("DP-1".into()),
+      size: PhysicalSize::new(2560, 1440),
+      position: PhysicalPosition::new(1920, 0),
+      scale_factor: 2.0,
+    });
+    assert_eq!(monitor.name().map(|n| n.as_str()), Some("DP-1"));
+    assert_eq!(*monitor.size(), PhysicalSize::new(2560, 1440));
+    assert_eq!(*monitor.position(), PhysicalPosition::new(1920, 0));
+    assert_eq!(monitor.scale_factor(), 2.0);
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn monitors_without_display() {
+    use crate::test::mock_app;
+
+    // the mock runtime behaves like a headless system with no monitor attached
+    let app = mock_app();
+    let window = super::WindowBuilder::new(&app, "main").build().unwrap();
+
+    assert!(window.current_monitor().unwrap().is_none());
+    assert!(window.primary_monitor().unwrap().is_none());
+    assert!(window.available_monitors().unwrap().is_empty());
+    assert!(app.primary_monitor().unwrap().is_none());
+    assert!(app.available_monitors().unwrap().is_empty());
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn adopt_child_from_other_window() {
+    use crate::{
+      test::mock_app, webview::WebviewBuilder, LogicalPosition, LogicalSize, WebviewUrl,
+    };
+
+    let app = mock_app();
+    let source = super::WindowBuilder::new(&app, "source").build().unwrap();
+    let destination = super::WindowBuilder::new(&app, "destination")
+      .build()
+      .unwrap();
+
+    let webview = source
+      .add_child(
+        WebviewBuilder::new(
+          "tab",
+          WebviewUrl::External("https://tauri.app".parse().unwrap()),
+        ),
+        LogicalPosition::new(0, 0),
+        LogicalSize::new(400, 400),
+      )
+      .unwrap();
+
+    let webview = destination
+      .adopt_child(
+        webview,
+        LogicalPosition::new(0, 40),
+        LogicalSize::new(400, 360),
+      )
+      .unwrap();
+
+    assert_eq!(webview.window().label(), "destination");
+    assert!(source.webviews().is_empty());
+    assert_eq!(destination.webviews().len(), 1);
+    // the page is kept
+    assert_eq!(webview.url().unwrap().as_str(), "https://tauri.app/");
+
+    assert!(matches!(
+      destination.adopt_child(webview, LogicalPosition::new(0, 0), LogicalSize::new(1, 1)),
+      Err(crate::Error::WebviewLabelAlreadyExists(label)) if label == "tab"
+    ));
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn set_title_from_child() {
+    use crate::{
+      test::mock_app, webview::WebviewBuilder, LogicalPosition, LogicalSize, WebviewUrl,
+    };
+
+    let app = mock_app();
+    let window = super::WindowBuilder::new(&app, "main").build().unwrap();
+    let add_child = |label: &str| {
+      window
+        .add_child(
+          WebviewBuilder::new(
+            label,
+            WebviewUrl::External("https://tauri.app".parse().unwrap()),
+          ),
+          LogicalPosition::new(0, 0),
+          LogicalSize::new(400, 400),
+        )
+        .unwrap()
+    };
+    let docs = add_child("docs");
+    let _blank = add_child("blank");
+
+    window.set_title("Browser").unwrap();
+    assert_eq!(window.title().unwrap(), "Browser");
+
+    // reported by the page script
+    docs.set_document_title("Tauri Docs".into());
+    window.set_title_from_child("docs").unwrap();
+    assert_eq!(window.title().unwrap(), "Tauri Docs");
+
+    window.set_title_from_child("blank").unwrap();
+    assert_eq!(window.title().unwrap(), "https://tauri.app/");
+
+    assert!(matches!(
+      window.set_title_from_child("unknown"),
+      Err(crate::Error::WebviewNotFound)
+    ));
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn native_buttons_with_custom_titlebar() {
+    use crate::{test::mock_app, webview::WebviewBuilder, LogicalPosition, LogicalSize};
+
+    let app = mock_app();
+    let window = super::WindowBuilder::new(&app, "main")
+      .decorations(false)
+      .minimizable(false)
+      .closable(false)
+      .build()
+      .unwrap();
+    assert!(!window.is_minimizable().unwrap());
+    assert!(!window.is_closable().unwrap());
+    assert!(window.is_maximizable().unwrap());
+
+    // the titlebar webview owns the controls
+    let titlebar = window
+      .add_child(
+        WebviewBuilder::new("titlebar", Default::default()),
+        LogicalPosition::new(0, 0),
+        LogicalSize::new(800, 30),
+      )
+      .unwrap();
+    titlebar.window().set_maximizable(false).unwrap();
+    assert!(!window.is_maximizable().unwrap());
+
+    window.set_closable(true).unwrap();
+    assert!(window.is_closable().unwrap());
+
+    let window = crate::WebviewWindowBuilder::new(&app, "other", Default::default())
+      .maximizable(false)
+      .build()
+      .unwrap();
+    assert!(!window.is_maximizable().unwrap());
+    assert!(window.is_minimizable().unwrap());
+    assert!(window.is_closable().unwrap());
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn app_id() {
+    let app = crate::test::mock_app();
+
+    let builder = super::WindowBuilder::new(&app, "main").app_id("com.tauri.editor");
+    assert_eq!(
+      builder.window_builder.app_id.as_deref(),
+      Some("com.tauri.editor")
+    );
+    builder.build().unwrap();
+
+    crate::WebviewWindowBuilder::new(&app, "other", Default::default())
+      .app_id("com.tauri.viewer")
+      .build()
+      .unwrap();
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn content_protection() {
+    let app = crate::test::mock_app();
+    let window = super::WindowBuilder::new(&app, "main")
+      .content_protected(true)
+      .build()
+      .unwrap();
+    assert!(window.window.dispatcher.is_content_protected());
+
+    window.set_content_protected(false).unwrap();
+    assert!(!window.window.dispatcher.is_content_protected());
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn print_to_pdf_unsupported() {
+    let app = crate::test::mock_app();
+    let window = super::WindowBuilder::new(&app, "main").build().unwrap();
+    assert!(matches!(
+      window.print_to_pdf(super::PrintToPdfOptions {
+        timeout: Some(std::time::Duration::from_secs(5)),
+      }),
+      Err(crate::Error::Runtime(tauri_runtime::Error::Unsupported))
+    ));
+  }
 }
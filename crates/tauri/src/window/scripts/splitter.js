@@ -0,0 +1,40 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+;(function () {
+  const horizontal = document.documentElement.dataset.orientation === 'horizontal'
+  // the divider moves under the pointer, so the screen coordinates are used to compute the movement
+  let last = null
+
+  document.addEventListener('pointerdown', (e) => {
+    if (e.button !== 0) {
+      return
+    }
+    document.documentElement.setPointerCapture(e.pointerId)
+    last = horizontal ? e.screenX : e.screenY
+  })
+
+  document.addEventListener('pointermove', (e) => {
+    if (last === null) {
+      return
+    }
+    const position = horizontal ? e.screenX : e.screenY
+    const delta = position - last
+    if (delta !== 0) {
+      last = position
+      fetch(
+        window.__TAURI_INTERNALS__.convertFileSrc(
+          String(delta),
+          'tauri-splitter'
+        )
+      )
+    }
+  })
+
+  function stop() {
+    last = null
+  }
+  document.addEventListener('pointerup', stop)
+  document.addEventListener('pointercancel', stop)
+})()
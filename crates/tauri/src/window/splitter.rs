@@ -0,0 +1,499 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Splitters resizing two child webviews of a window.
+
+use serde::Serialize;
+
+use crate::{
+  webview::WebviewBuilder, LogicalPosition, LogicalSize, Runtime, Webview, WebviewUrl, Window,
+};
+
+/// The event emitted to the window when a [`Splitter`] moves, with a [`SplitterMovedPayload`].
+pub const SPLITTER_MOVED_EVENT: &str = "tauri://splitter-moved";
+
+/// The thickness of the divider webview, in logical pixels.
+const DIVIDER_SIZE: f64 = 6.;
+
+/// The URI scheme the divider webview reports the pointer movements to.
+const DIVIDER_PROTOCOL: &str = "tauri-splitter";
+
+/// How the two webviews of a [`Splitter`] are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitterOrientation {
+  /// The webviews are side by side, the divider is a vertical line.
+  Horizontal,
+  /// The webviews are stacked, the divider is a horizontal line.
+  Vertical,
+}
+
+/// The payload of the [`SPLITTER_MOVED_EVENT`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitterMovedPayload {
+  /// The label of the first webview.
+  pub first: String,
+  /// The label of the second webview.
+  pub second: String,
+  /// The share of the split area taken by the first webview, between `0` and `1`.
+  pub ratio: f64,
+}
+
+/// A draggable divider between two adjacent child webviews of a window,
+/// created with [`Window::add_splitter`].
+///
+/// The divider is a thin child webview, see [`Self::divider`], centered on the space between the webviews.
+/// Dragging it resizes both webviews, keeping the space between them,
+/// and emits the [`SPLITTER_MOVED_EVENT`] to the window with the new ratio so it can be persisted.
+/// The size constraints of the webviews (see [`Webview::set_size_constraints`]) are enforced.
+///
+/// [`Self::set_ratio`] and [`Self::drag`] move the splitter programmatically, for instance to restore a persisted ratio.
+pub struct Splitter<R: Runtime> {
+  window: Window<R>,
+  first: String,
+  second: String,
+  divider: String,
+  orientation: SplitterOrientation,
+}
+
+impl<R: Runtime> Clone for Splitter<R> {
+  fn clone(&self) -> Self {
+    Self {
+      window: self.window.clone(),
+      first: self.first.clone(),
+      second: self.second.clone(),
+      divider: self.divider.clone(),
+      orientation: self.orientation,
+    }
+  }
+}
+
+impl<R: Runtime> std::fmt::Debug for Splitter<R> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Splitter")
+      .field("window", &self.window.label())
+      .field("first", &self.first)
+      .field("second", &self.second)
+      .field("divider", &self.divider)
+      .field("orientation", &self.orientation)
+      .finish()
+  }
+}
+
+/// The logical layout of a webview along the splitter axis.
+struct Span {
+  start: f64,
+  extent: f64,
+  cross_start: f64,
+  cross_extent: f64,
+}
+
+impl<R: Runtime> Splitter<R> {
+  pub(crate) fn new(
+    window: Window<R>,
+    first: String,
+    second: String,
+    orientation: SplitterOrientation,
+  ) -> crate::Result<Self> {
+    let splitter = Self {
+      window,
+      divider: format!("{first}:{second}:splitter"),
+      first,
+      second,
+      orientation,
+    };
+
+    let scale_factor = splitter.window.scale_factor()?;
+    let first = splitter.span(&splitter.webview(&splitter.first)?, scale_factor)?;
+    let second = splitter.span(&splitter.webview(&splitter.second)?, scale_factor)?;
+    let gap = (second.start - (first.start + first.extent)).max(0.);
+    let (position, size) = splitter.bounds(
+      &first,
+      divider_start(first.start + first.extent, gap),
+      DIVIDER_SIZE,
+    );
+
+    let handler = splitter.clone();
+    splitter.window.add_child(
+      WebviewBuilder::new(
+        &splitter.divider,
+        WebviewUrl::Html(divider_html(orientation)),
+      )
+      .focused(false)
+      .register_uri_scheme_protocol(DIVIDER_PROTOCOL, move |_, request| {
+        handler.on_divider_request(&request)
+      }),
+      position,
+      size,
+    )?;
+
+    Ok(splitter)
+  }
+
+  /// The label of the first webview, on the left or on top.
+  pub fn first(&self) -> &str {
+    &self.first
+  }
+
+  /// The label of the second webview, on the right or at the bottom.
+  pub fn second(&self) -> &str {
+    &self.second
+  }
+
+  /// The label of the divider webview the user drags to move the splitter.
+  pub fn divider(&self) -> &str {
+    &self.divider
+  }
+
+  /// The orientation of this splitter.
+  pub fn orientation(&self) -> SplitterOrientation {
+    self.orientation
+  }
+
+  /// The share of the split area currently taken by the first webview, between `0` and `1`.
+  pub fn ratio(&self) -> crate::Result<f64> {
+    let scale_factor = self.window.scale_factor()?;
+    let first = self.span(&self.webview(&self.first)?, scale_factor)?;
+    let second = self.span(&self.webview(&self.second)?, scale_factor)?;
+    let total = first.extent + second.extent;
+    Ok(if total > 0. { first.extent / total } else { 0. })
+  }
+
+  /// Moves the splitter so the first webview takes the given share of the split area.
+  ///
+  /// Returns the applied ratio, which differs from the requested one when it violates the size constraints of the webviews.
+  pub fn set_ratio(&self, ratio: f64) -> crate::Result<f64> {
+    let scale_factor = self.window.scale_factor()?;
+    let first_webview = self.webview(&self.first)?;
+    let second_webview = self.webview(&self.second)?;
+    let first = self.span(&first_webview, scale_factor)?;
+    let second = self.span(&second_webview, scale_factor)?;
+
+    let gap = (second.start - (first.start + first.extent)).max(0.);
+    let total = first.extent + second.extent;
+    let first_extent = split(
+      total,
+      ratio,
+      self.limits(&first_webview),
+      self.limits(&second_webview),
+    );
+    let second_extent = total - first_extent;
+
+    self.set_span(&first_webview, &first, first.start, first_extent)?;
+    self.set_span(
+      &second_webview,
+      &second,
+      first.start + first_extent + gap,
+      second_extent,
+    )?;
+    self.set_span(
+      &self.webview(&self.divider)?,
+      &first,
+      divider_start(first.start + first_extent, gap),
+      DIVIDER_SIZE,
+    )?;
+
+    let ratio = if total > 0. { first_extent / total } else { 0. };
+    self.window.emit_to_window(
+      SPLITTER_MOVED_EVENT,
+      SplitterMovedPayload {
+        first: self.first.clone(),
+        second: self.second.clone(),
+        ratio,
+      },
+    )?;
+    Ok(ratio)
+  }
+
+  /// Moves the splitter by the given amount of logical pixels, towards the second webview when positive.
+  ///
+  /// Returns the applied ratio, see [`Self::set_ratio`].
+  pub fn drag(&self, delta: f64) -> crate::Result<f64> {
+    let scale_factor = self.window.scale_factor()?;
+    let first = self.span(&self.webview(&self.first)?, scale_factor)?;
+    let second = self.span(&self.webview(&self.second)?, scale_factor)?;
+    let total = first.extent + second.extent;
+    if total <= 0. {
+      return self.set_ratio(0.);
+    }
+    self.set_ratio((first.extent + delta) / total)
+  }
+
+  /// Moves the splitter by the amount of logical pixels in the request path, sent by the divider webview.
+  fn on_divider_request(&self, request: &http::Request<Vec<u8>>) -> http::Response<Vec<u8>> {
+    let status = match request.uri().path().trim_start_matches('/').parse::<f64>() {
+      Ok(delta) => match self.drag(delta) {
+        Ok(_) => http::StatusCode::NO_CONTENT,
+        Err(e) => {
+          log::error!("failed to move splitter {}: {e}", self.divider);
+          http::StatusCode::INTERNAL_SERVER_ERROR
+        }
+      },
+      Err(_) => http::StatusCode::BAD_REQUEST,
+    };
+    http::Response::builder()
+      .status(status)
+      .header(http::header::ACCESS_CONTROL_ALLOW_ORIGIN, "*")
+      .body(Vec::new())
+      .unwrap()
+  }
+
+  fn webview(&self, label: &str) -> crate::Result<Webview<R>> {
+    self
+      .window
+      .webviews()
+      .into_iter()
+      .find(|w| w.label() == label)
+      .ok_or(crate::Error::WebviewNotFound)
+  }
+
+  fn span(&self, webview: &Webview<R>, scale_factor: f64) -> crate::Result<Span> {
+    let bounds = webview.bounds()?;
+    let position = bounds.position.to_logical::<f64>(scale_factor);
+    let size = bounds.size.to_logical::<f64>(scale_factor);
+    Ok(match self.orientation {
+      SplitterOrientation::Horizontal => Span {
+        start: position.x,
+        extent: size.width,
+        cross_start: position.y,
+        cross_extent: size.height,
+      },
+      SplitterOrientation::Vertical => Span {
+        start: position.y,
+        extent: size.height,
+        cross_start: position.x,
+        cross_extent: size.width,
+      },
+    })
+  }
+
+  fn set_span(
+    &self,
+    webview: &Webview<R>,
+    span: &Span,
+    start: f64,
+    extent: f64,
+  ) -> crate::Result<()> {
+    let (position, size) = self.bounds(span, start, extent);
+    webview.set_bounds(tauri_runtime::Rect {
+      position: position.into(),
+      size: size.into(),
+    })
+  }
+
+  /// The bounds of a webview starting at `start` along the splitter axis, sharing the cross axis of `span`.
+  fn bounds(
+    &self,
+    span: &Span,
+    start: f64,
+    extent: f64,
+  ) -> (LogicalPosition<f64>, LogicalSize<f64>) {
+    match self.orientation {
+      SplitterOrientation::Horizontal => (
+        LogicalPosition::new(start, span.cross_start),
+        LogicalSize::new(extent, span.cross_extent),
+      ),
+      SplitterOrientation::Vertical => (
+        LogicalPosition::new(span.cross_start, start),
+        LogicalSize::new(span.cross_extent, extent),
+      ),
+    }
+  }
+
+  /// The minimum and maximum extent of the webview along the splitter axis.
+  fn limits(&self, webview: &Webview<R>) -> (Option<f64>, Option<f64>) {
+    let (min, max) = webview.size_constraints();
+    let extent = |size: LogicalSize<f64>| match self.orientation {
+      SplitterOrientation::Horizontal => size.width,
+      SplitterOrientation::Vertical => size.height,
+    };
+    (min.map(extent), max.map(extent))
+  }
+}
+
+/// The start of the divider, centered on the space of size `gap` after the first webview ending at `first_end`.
+fn divider_start(first_end: f64, gap: f64) -> f64 {
+  first_end + gap / 2. - DIVIDER_SIZE / 2.
+}
+
+/// The HTML of the divider webview, which reports the pointer movements while it is dragged.
+fn divider_html(orientation: SplitterOrientation) -> String {
+  let (orientation, cursor) = match orientation {
+    SplitterOrientation::Horizontal => ("horizontal", "col-resize"),
+    SplitterOrientation::Vertical => ("vertical", "row-resize"),
+  };
+  format!(
+    r#"<!DOCTYPE html><html data-orientation="{orientation}"><body style="margin: 0; height: 100vh; cursor: {cursor}"><script>{}</script></body></html>"#,
+    include_str!("./scripts/splitter.js")
+  )
+}
+
+/// Computes the extent of the first webview for the given ratio,
+/// clamped so both webviews respect their minimum and maximum extents.
+///
+/// The minimum extents win when the constraints cannot be satisfied.
+fn split(
+  total: f64,
+  ratio: f64,
+  (first_min, first_max): (Option<f64>, Option<f64>),
+  (second_min, second_max): (Option<f64>, Option<f64>),
+) -> f64 {
+  let mut extent = total * ratio.clamp(0., 1.);
+  if let Some(max) = first_max {
+    extent = extent.min(max);
+  }
+  if let Some(min) = second_min {
+    extent = extent.min(total - min);
+  }
+  if let Some(max) = second_max {
+    extent = extent.max(total - max);
+  }
+  if let Some(min) = first_min {
+    extent = extent.max(min);
+  }
+  extent.clamp(0., total.max(0.))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::split;
+
+  #[test]
+  fn split_respects_constraints() {
+    assert_eq!(split(800., 0.5, (None, None), (None, None)), 400.);
+    assert_eq!(split(800., 1.5, (None, None), (None, None)), 800.);
+    assert_eq!(split(800., 0.1, (Some(200.), None), (None, None)), 200.);
+    assert_eq!(split(800., 0.9, (None, Some(500.)), (None, None)), 500.);
+    assert_eq!(split(800., 0.9, (None, None), (Some(300.), None)), 500.);
+    assert_eq!(split(800., 0.1, (None, None), (None, Some(600.))), 200.);
+    // the minimum size of the first webview wins over the one of the second webview
+    assert_eq!(
+      split(800., 0.5, (Some(600.), None), (Some(300.), None)),
+      600.
+    );
+  }
+
+  #[test]
+  fn add_splitter_requires_children() {
+    use crate::{
+      test::mock_app, webview::WebviewBuilder, window::WindowBuilder, LogicalPosition, LogicalSize,
+      WebviewUrl,
+    };
+
+    let app = mock_app();
+    let window = WindowBuilder::new(&app, "main").build().unwrap();
+    for label in ["titlebar", "content"] {
+      window
+        .add_child(
+          WebviewBuilder::new(label, WebviewUrl::App(Default::default())),
+          LogicalPosition::new(0, 0),
+          LogicalSize::new(800, 300),
+        )
+        .unwrap();
+    }
+
+    let splitter = window
+      .add_splitter(
+        ("titlebar", "content"),
+        super::SplitterOrientation::Vertical,
+      )
+      .unwrap();
+    assert_eq!(splitter.first(), "titlebar");
+    assert_eq!(splitter.second(), "content");
+
+    assert!(matches!(
+      window.add_splitter(
+        ("titlebar", "sidebar"),
+        super::SplitterOrientation::Vertical
+      ),
+      Err(crate::Error::WebviewNotFound)
+    ));
+  }
+
+  #[test]
+  fn splitter_moves_webviews() {
+    use std::sync::mpsc::channel;
+
+    use crate::{
+      test::mock_app, webview::WebviewBuilder, window::WindowBuilder, Listener, LogicalPosition,
+      LogicalSize, WebviewUrl,
+    };
+
+    let app = mock_app();
+    let window = WindowBuilder::new(&app, "main").build().unwrap();
+    let add_child = |label: &str, x: f64| {
+      window
+        .add_child(
+          WebviewBuilder::new(label, WebviewUrl::App(Default::default())),
+          LogicalPosition::new(x, 0.),
+          LogicalSize::new(400., 600.),
+        )
+        .unwrap()
+    };
+    let sidebar = add_child("sidebar", 0.);
+    let content = add_child("content", 400.);
+    let bounds = |webview: &crate::Webview<_>| {
+      let bounds = webview.bounds().unwrap();
+      let position = bounds.position.to_logical::<f64>(1.);
+      let size = bounds.size.to_logical::<f64>(1.);
+      (position.x, position.y, size.width, size.height)
+    };
+
+    let (tx, rx) = channel();
+    window.listen(super::SPLITTER_MOVED_EVENT, move |event| {
+      tx.send(event.payload().to_string()).unwrap();
+    });
+
+    let splitter = window
+      .add_splitter(
+        ("sidebar", "content"),
+        super::SplitterOrientation::Horizontal,
+      )
+      .unwrap();
+
+    // the divider is centered on the edge between the webviews
+    let divider = window
+      .webviews()
+      .into_iter()
+      .find(|w| w.label() == splitter.divider())
+      .unwrap();
+    assert_eq!(bounds(&divider), (397., 0., 6., 600.));
+    assert_eq!(splitter.ratio().unwrap(), 0.5);
+
+    assert_eq!(splitter.set_ratio(0.25).unwrap(), 0.25);
+    assert_eq!(bounds(&sidebar), (0., 0., 200., 600.));
+    assert_eq!(bounds(&content), (200., 0., 600., 600.));
+    assert_eq!(bounds(&divider), (197., 0., 6., 600.));
+    assert_eq!(
+      rx.try_recv().unwrap(),
+      r#"{"first":"sidebar","second":"content","ratio":0.25}"#
+    );
+
+    assert_eq!(splitter.drag(200.).unwrap(), 0.5);
+    assert_eq!(bounds(&sidebar), (0., 0., 400., 600.));
+    assert_eq!(bounds(&content), (400., 0., 400., 600.));
+    assert_eq!(
+      rx.try_recv().unwrap(),
+      r#"{"first":"sidebar","second":"content","ratio":0.5}"#
+    );
+
+    // the divider webview reports the pointer movements
+    let request = |path: &str| {
+      http::Request::builder()
+        .uri(format!("tauri-splitter://localhost/{path}"))
+        .body(Vec::new())
+        .unwrap()
+    };
+    assert_eq!(
+      splitter.on_divider_request(&request("-200")).status(),
+      http::StatusCode::NO_CONTENT
+    );
+    assert_eq!(bounds(&sidebar), (0., 0., 200., 600.));
+    assert_eq!(bounds(&divider), (197., 0., 6., 600.));
+    assert_eq!(
+      splitter.on_divider_request(&request("left")).status(),
+      http::StatusCode::BAD_REQUEST
+    );
+  }
+}
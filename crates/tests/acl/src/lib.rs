@@ -12,7 +12,10 @@ mod tests {
   };
 
   use tauri_utils::{
-    acl::{build::parse_capabilities, manifest::Manifest, resolved::Resolved},
+    acl::{
+      build::parse_capabilities, manifest::Manifest, resolved::Resolved,
+      schema::generate_typescript_definitions,
+    },
     platform::Target,
   };
 
@@ -32,7 +35,7 @@ mod tests {
         |_| true,
       )
       .expect("failed to define permissions");
-      let manifest = Manifest::new(permission_files, None);
+      let manifest = Manifest::new(permission_files, None).expect("failed to create manifest");
       manifests.insert(plugin.to_string(), manifest);
     }
 
@@ -79,4 +82,23 @@ mod tests {
       );
     }
   }
+
+  #[test]
+  fn typescript_definitions() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut manifests = load_plugins(&["fs".to_string()]);
+    let manifest = manifests.get_mut("fs").unwrap();
+
+    let scope_schema_str =
+      read_to_string(manifest_dir.join("fixtures/plugins/fs/scope-schema.json"))
+        .expect("failed to read scope-schema.json");
+    manifest.global_scope_schema =
+      Some(serde_json::from_str(&scope_schema_str).expect("scope-schema.json is not a valid JSON"));
+
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path("../fixtures/snapshots");
+    let _guard = settings.bind_to_scope();
+
+    insta::assert_snapshot!(generate_typescript_definitions("fs", manifest));
+  }
 }
@@ -4,7 +4,7 @@
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::{LogicalPosition, LogicalSize, WebviewUrl};
+use tauri::{window::SplitterOrientation, LogicalPosition, LogicalSize, WebviewUrl};
 
 fn main() {
   tauri::Builder::default()
@@ -16,8 +16,11 @@ fn main() {
         .inner_size(width, height)
         .build()?;
 
+      // the top left webview is focused first and Tab moves the focus through the webviews in reading order
       let _webview1 = window.add_child(
         tauri::webview::WebviewBuilder::new("main1", WebviewUrl::App(Default::default()))
+          .tab_index(1)
+          .focused(true)
           .auto_resize(),
         LogicalPosition::new(0., 0.),
         LogicalSize::new(width / 2., height / 2.),
@@ -28,6 +31,8 @@ fn main() {
           "main2",
           WebviewUrl::External("https://github.com/tauri-apps/tauri".parse().unwrap()),
         )
+        .tab_index(2)
+        .focused(false)
         .auto_resize(),
         LogicalPosition::new(width / 2., 0.),
         LogicalSize::new(width / 2., height / 2.),
@@ -38,6 +43,8 @@ fn main() {
           "main3",
           WebviewUrl::External("https://tauri.app".parse().unwrap()),
         )
+        .tab_index(3)
+        .focused(false)
         .auto_resize(),
         LogicalPosition::new(0., height / 2.),
         LogicalSize::new(width / 2., height / 2.),
@@ -48,11 +55,16 @@ fn main() {
           "main4",
           WebviewUrl::External("https://twitter.com/TauriApps".parse().unwrap()),
         )
+        .tab_index(4)
+        .focused(false)
         .auto_resize(),
         LogicalPosition::new(width / 2., height / 2.),
         LogicalSize::new(width / 2., height / 2.),
       )?;
 
+      // the top left and bottom left webviews can be resized by dragging the divider between them
+      window.add_splitter(("main1", "main3"), SplitterOrientation::Vertical)?;
+
       Ok(())
     })
     .run(tauri::generate_context!(
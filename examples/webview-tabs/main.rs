@@ -0,0 +1,65 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+use tauri::{
+  webview::{tabs::WebviewTabs, WebviewBuilder},
+  LogicalPosition, LogicalSize, Manager, State, WebviewUrl, Wry,
+};
+
+const TAB_BAR_HEIGHT: f64 = 40.;
+
+#[tauri::command]
+fn select_tab(tabs: State<'_, WebviewTabs<Wry>>, label: String) -> Result<(), String> {
+  tabs.select(&label).map_err(|e| e.to_string())
+}
+
+fn main() {
+  tauri::Builder::default()
+    .invoke_handler(tauri::generate_handler![select_tab])
+    .setup(|app| {
+      let width = 800.;
+      let height = 600.;
+
+      let window = tauri::window::WindowBuilder::new(app, "main")
+        .inner_size(width, height)
+        .build()?;
+
+      let _tab_bar = window.add_child(
+        WebviewBuilder::new("tab-bar", WebviewUrl::App(Default::default())).auto_resize(),
+        LogicalPosition::new(0., 0.),
+        LogicalSize::new(width, TAB_BAR_HEIGHT),
+      )?;
+
+      let tabs = WebviewTabs::new(
+        &window,
+        LogicalPosition::new(0., TAB_BAR_HEIGHT),
+        LogicalSize::new(width, height - TAB_BAR_HEIGHT),
+      );
+
+      tabs.add(
+        WebviewBuilder::new(
+          "github",
+          WebviewUrl::External("https://github.com/tauri-apps/tauri".parse().unwrap()),
+        )
+        .auto_resize(),
+      )?;
+      tabs.add(
+        WebviewBuilder::new(
+          "tauri",
+          WebviewUrl::External("https://tauri.app".parse().unwrap()),
+        )
+        .auto_resize(),
+      )?;
+
+      app.manage(tabs);
+
+      Ok(())
+    })
+    .run(tauri::generate_context!(
+      "../../examples/webview-tabs/tauri.conf.json"
+    ))
+    .expect("error while running tauri application");
+}
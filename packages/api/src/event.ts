@@ -60,7 +60,8 @@ enum TauriEvent {
   DRAG_ENTER = 'tauri://drag-enter',
   DRAG_OVER = 'tauri://drag-over',
   DRAG_DROP = 'tauri://drag-drop',
-  DRAG_LEAVE = 'tauri://drag-leave'
+  DRAG_LEAVE = 'tauri://drag-leave',
+  FILE_DROP = 'tauri://file-drop'
 }
 
 /**
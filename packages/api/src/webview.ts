@@ -333,6 +333,40 @@ class Webview {
     return emitTo(target, event, payload)
   }
 
+  /**
+   * Emits an event to the webview with the given label in the same window only.
+   *
+   * Unlike {@link Webview.emitTo}, the listeners of other webviews never receive the event.
+   * The target webview receives it with {@link listen} or `getCurrentWebview().listen`.
+   *
+   * @example
+   * ```typescript
+   * import { getCurrentWebview } from '@tauri-apps/api/webview';
+   * // in the title bar webview
+   * await getCurrentWebview().emitToSibling('content', 'toggle-sidebar');
+   * // in the content webview
+   * await getCurrentWebview().listen('toggle-sidebar', () => toggleSidebar());
+   * ```
+   *
+   * @param target Label of the target webview, which must be in the same window.
+   * @param event Event name. Must include only alphanumeric characters, `-`, `/`, `:` and `_`.
+   * @param payload Event payload.
+   *
+   * @since 2.2.0
+   */
+  async emitToSibling(
+    target: string,
+    event: string,
+    payload?: unknown
+  ): Promise<void> {
+    return invoke('plugin:webview|emit_to_sibling', {
+      label: this.label,
+      target,
+      event,
+      payload
+    })
+  }
+
   /** @ignore */
   _handleTauriEvent<T>(event: string, handler: EventCallback<T>): boolean {
     if (localTauriEvents.includes(event)) {
@@ -555,6 +589,32 @@ class Webview {
     return invoke('plugin:webview|set_webview_background_color', { color })
   }
 
+  /**
+   * Sets the user agent of the webview.
+   *
+   * The currently loaded page is not reloaded, so the new user agent only applies starting with the next navigation.
+   *
+   * #### Platform-specific:
+   *
+   * - **Android**: Unsupported.
+   *
+   * @example
+   * ```typescript
+   * import { getCurrentWebview } from '@tauri-apps/api/webview';
+   * await getCurrentWebview().setUserAgent('Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)');
+   * ```
+   *
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 2.2.0
+   */
+  async setUserAgent(userAgent: string): Promise<void> {
+    return invoke('plugin:webview|set_webview_user_agent', {
+      label: this.label,
+      value: userAgent
+    })
+  }
+
   // Listeners
 
   /**
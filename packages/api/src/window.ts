@@ -56,7 +56,7 @@ export interface Monitor {
 }
 
 type Theme = 'light' | 'dark'
-type TitleBarStyle = 'visible' | 'transparent' | 'overlay'
+type TitleBarStyle = 'visible' | 'transparent' | 'overlay' | 'hidden'
 
 type ResizeDirection =
   | 'East'
@@ -822,8 +822,8 @@ class Window {
    * is already focused. How requesting for user attention manifests is platform dependent,
    * see `UserAttentionType` for details.
    *
-   * Providing `null` will unset the request for user attention. Unsetting the request for
-   * user attention might not be done automatically by the WM when the window receives input.
+   * Providing `null` cancels a pending request for user attention.
+   * A pending request is also cancelled when the window gets focused.
    *
    * #### Platform-specific
    *
@@ -1244,6 +1244,10 @@ class Window {
 
   /**
    * Prevents the window contents from being captured by other apps.
+   *
+   * #### Platform-specific
+   *
+   * - **Linux / iOS / Android:** Unsupported, the promise is rejected.
    * @example
    * ```typescript
    * import { getCurrentWindow } from '@tauri-apps/api/window';
@@ -1721,7 +1725,11 @@ class Window {
   }
 
   /**
-   * Sets the title bar style. **macOS only**.
+   * Sets the title bar style.
+   *
+   * #### Platform-specific
+   *
+   * - **Windows / Linux:** Only `'hidden'` is supported, which removes the window decorations.
    *
    * @since 2.0.0
    */
@@ -2267,7 +2275,7 @@ interface WindowOptions {
    */
   theme?: Theme
   /**
-   * The style of the macOS title bar.
+   * The style of the window title bar. `'hidden'` is supported on all desktop platforms, the other styles only on macOS.
    */
   titleBarStyle?: TitleBarStyle
   /**